@@ -0,0 +1,71 @@
+//! Build script for the S3 consistency test tool
+//! 
+//! Captures build metadata that is not otherwise available at compile time,
+//! such as the resolved version of the `rust-s3` dependency, the compiler
+//! version and the build time, and exposes it to the crate through
+//! environment variables read with `env!`.
+
+use std::env;
+use std::fs;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn main() {
+    // Rerun whenever the crate itself is rebuilt, so the build time stays current
+    println!("cargo:rerun-if-changed=Cargo.lock");
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+    
+    let s3_version = fs::read_to_string("Cargo.lock")
+        .ok()
+        .and_then(|lock| locked_version(&lock, "rust-s3"))
+        .unwrap_or_else(|| "unknown".to_string());
+    
+    println!("cargo:rustc-env=S3_CLIENT_VERSION={}", s3_version);
+    println!("cargo:rustc-env=RUSTC_VERSION={}", rustc_version().unwrap_or_else(|| "unknown".to_string()));
+    println!("cargo:rustc-env=BUILD_TIMESTAMP={}", build_timestamp());
+}
+
+/// Finds the resolved version of a package in the contents of `Cargo.lock`
+fn locked_version(lock: &str, package: &str) -> Option<String> {
+    let name_line = format!("name = \"{}\"", package);
+    let mut lines = lock.lines();
+    
+    while let Some(line) = lines.next() {
+        if line.trim() == name_line {
+            return lines
+                .next()
+                .and_then(|version| version.trim().strip_prefix("version = "))
+                .map(|version| version.trim_matches('"').to_string());
+        }
+    }
+    
+    None
+}
+
+/// Runs the compiler Cargo builds with and returns its `--version` output
+fn rustc_version() -> Option<String> {
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let output = Command::new(rustc).arg("--version").output().ok()?;
+    
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8(output.stdout).ok().map(|version| version.trim().to_string())
+}
+
+/// Seconds since the Unix epoch the crate was built at
+/// 
+/// Honours `SOURCE_DATE_EPOCH` so reproducible builds get a fixed timestamp.
+fn build_timestamp() -> u64 {
+    env::var("SOURCE_DATE_EPOCH")
+        .ok()
+        .and_then(|epoch| epoch.trim().parse().ok())
+        .unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|elapsed| elapsed.as_secs())
+                .unwrap_or(0)
+        })
+}
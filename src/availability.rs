@@ -0,0 +1,136 @@
+//! Backend availability probing for S3 consistency testing
+//! 
+//! This module runs a lightweight background probe alongside the consistency
+//! tests. At a fixed cadence it lists a single key from the bucket and records
+//! whether the request succeeded and how long it took, producing an availability
+//! timeline that can be correlated with clusters of slow or failed files.
+
+use chrono::Utc;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
+use tokio::time::{MissedTickBehavior, interval, timeout};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, warn};
+
+use crate::requests::{RequestCounter, RequestKind};
+use crate::storage::ObjectStore;
+use crate::types::AvailabilitySample;
+
+/// Maximum time a single probe request may take before it counts as unavailable
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Background task that periodically probes the backend
+/// 
+/// The probe runs until `stop` is called, at which point the collected
+/// availability timeline is returned.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// let probe = AvailabilityProbe::start(storage.clone(), Duration::from_secs(1), requests.clone());
+/// // ... run tests ...
+/// let timeline = probe.stop().await;
+/// ```
+pub struct AvailabilityProbe {
+    /// Token used to signal the probe task to stop
+    cancel: CancellationToken,
+
+    /// Handle to the probe task, which yields the collected samples
+    handle: JoinHandle<Vec<AvailabilitySample>>,
+}
+
+impl AvailabilityProbe {
+    /// Starts probing the backend in the background
+    /// 
+    /// # Arguments
+    /// 
+    /// * `storage` - Storage to probe
+    /// * `cadence` - Time between the start of consecutive probes
+    /// * `requests` - Counter that every probe request is recorded in
+    /// 
+    /// # Returns
+    /// 
+    /// A running `AvailabilityProbe`
+    pub fn start(storage: Arc<dyn ObjectStore>, cadence: Duration, requests: Arc<RequestCounter>) -> Self {
+        let cancel = CancellationToken::new();
+        let token = cancel.clone();
+        
+        let handle = tokio::spawn(async move {
+            let mut samples = Vec::new();
+            let mut ticker = interval(cadence);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            
+            loop {
+                tokio::select! {
+                    _ = token.cancelled() => break,
+                    _ = ticker.tick() => {
+                        requests.record(RequestKind::List);
+                        samples.push(probe_once(storage.as_ref()).await);
+                    }
+                }
+            }
+            
+            samples
+        });
+        
+        Self { cancel, handle }
+    }
+
+    /// Stops the probe and returns the availability timeline
+    /// 
+    /// # Returns
+    /// 
+    /// All samples collected since the probe was started, in time order
+    pub async fn stop(self) -> Vec<AvailabilitySample> {
+        self.cancel.cancel();
+        
+        match self.handle.await {
+            Ok(samples) => samples,
+            Err(e) => {
+                warn!("Availability probe task failed: {}", e);
+                Vec::new()
+            }
+        }
+    }
+}
+
+/// Performs a single availability probe
+/// 
+/// Lists at most one key from the bucket, which exercises authentication and
+/// the bucket index without transferring object data.
+/// 
+/// # Arguments
+/// 
+/// * `storage` - Storage to probe
+/// 
+/// # Returns
+/// 
+/// An `AvailabilitySample` describing the outcome
+async fn probe_once(storage: &dyn ObjectStore) -> AvailabilitySample {
+    let timestamp = Utc::now();
+    let start = Instant::now();
+    
+    let outcome = timeout(
+        PROBE_TIMEOUT,
+        storage.list("", None, Some(1)),
+    ).await;
+    let latency_ms = start.elapsed().as_millis() as u64;
+    
+    let error = match outcome {
+        Ok(Ok(_)) => None,
+        Ok(Err(e)) => Some(e.to_string()),
+        Err(_) => Some(format!("Probe timed out after {}ms", PROBE_TIMEOUT.as_millis())),
+    };
+    
+    if let Some(error) = &error {
+        debug!("🩺 Availability probe failed after {}ms: {}", latency_ms, error);
+    }
+    
+    AvailabilitySample {
+        timestamp,
+        available: error.is_none(),
+        latency_ms,
+        error,
+    }
+}
@@ -0,0 +1,266 @@
+//! Multi-object batch deletes for test file cleanup
+//! 
+//! S3's multi-object delete (`POST /?delete`) removes up to 1000 keys in a
+//! single request, which makes cleaning up after large runs much faster and
+//! cheaper than one `DELETE` per file. The `s3` crate does not expose this
+//! operation, so the request is built here, signed with AWS Signature
+//! Version 4 (see `sigv4`) and sent with the bucket's credentials.
+//! 
+//! Not every S3-compatible backend implements multi-object delete. A request
+//! that fails as a whole is reported as an error so the caller can fall back
+//! to single deletes.
+
+use anyhow::{Context, Result, anyhow};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use chrono::Utc;
+use reqwest::Url;
+use s3::Bucket;
+use sha2::{Digest, Sha256};
+
+use crate::sigv4::{SigningRequest, authorization_header};
+
+/// Maximum number of keys S3 accepts in one multi-object delete request
+pub const MAX_BATCH_DELETE_KEYS: usize = 1000;
+
+/// A key the backend refused to delete as part of a batch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchDeleteFailure {
+    /// The object key that was not deleted
+    pub key: String,
+    
+    /// S3 error code, e.g. `AccessDenied`
+    pub code: String,
+    
+    /// Human-readable error message from the backend
+    pub message: String,
+}
+
+impl BatchDeleteFailure {
+    /// Determines whether the key was not deleted because access was denied
+    /// 
+    /// # Returns
+    /// 
+    /// `true` if the backend reported `AccessDenied` for this key
+    pub fn is_access_denied(&self) -> bool {
+        self.code == "AccessDenied"
+    }
+}
+
+/// Deletes up to 1000 objects with a single multi-object delete request
+/// 
+/// The request is sent in quiet mode, so the response only lists the keys
+/// that could not be deleted.
+/// 
+/// # Arguments
+/// 
+/// * `client` - HTTP client to send the request with
+/// * `bucket` - The bucket to delete from; its URL, region and credentials are used
+/// * `keys` - The object keys to delete (at most `MAX_BATCH_DELETE_KEYS`)
+/// 
+/// # Returns
+/// 
+/// - `Ok(Vec<BatchDeleteFailure>)` with the keys that were not deleted (empty if all were)
+/// - `Err(anyhow::Error)` if the request as a whole failed, e.g. because the
+///   backend does not support multi-object delete
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// let failures = delete_objects(&client, &bucket, &keys).await?;
+/// for failure in failures {
+///     warn!("Could not delete {}: {}", failure.key, failure.code);
+/// }
+/// ```
+pub async fn delete_objects(
+    client: &reqwest::Client,
+    bucket: &Bucket,
+    keys: &[String],
+) -> Result<Vec<BatchDeleteFailure>> {
+    if keys.is_empty() {
+        return Ok(Vec::new());
+    }
+    if keys.len() > MAX_BATCH_DELETE_KEYS {
+        return Err(anyhow!(
+            "Cannot delete {} objects in one request (maximum is {})",
+            keys.len(), MAX_BATCH_DELETE_KEYS
+        ));
+    }
+    
+    let url = Url::parse(&format!("{}?delete", bucket.url()))
+        .with_context(|| format!("Invalid bucket URL: {}", bucket.url()))?;
+    let host = match (url.host_str(), url.port()) {
+        (Some(host), Some(port)) => format!("{}:{}", host, port),
+        (Some(host), None) => host.to_string(),
+        (None, _) => return Err(anyhow!("Bucket URL has no host: {}", url)),
+    };
+    
+    let credentials = bucket.credentials().await
+        .context("Failed to get bucket credentials")?;
+    let access_key = credentials.access_key
+        .ok_or_else(|| anyhow!("Batch delete requires an access key"))?;
+    let secret_key = credentials.secret_key
+        .ok_or_else(|| anyhow!("Batch delete requires a secret key"))?;
+    let session_token = credentials.security_token.or(credentials.session_token);
+    
+    let body = delete_request_body(keys);
+    let payload_hash = hex::encode(Sha256::digest(body.as_bytes()));
+    let content_md5 = BASE64.encode(md5::compute(body.as_bytes()).0);
+    let now = Utc::now();
+    let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+    
+    // Canonical headers must be sorted by name
+    let mut headers = vec![
+        ("content-md5", content_md5),
+        ("content-type", "application/xml".to_string()),
+        ("host", host),
+        ("x-amz-content-sha256", payload_hash.clone()),
+        ("x-amz-date", amz_date.clone()),
+    ];
+    if let Some(token) = session_token {
+        headers.push(("x-amz-security-token", token));
+    }
+    
+    let authorization = authorization_header(&SigningRequest {
+        method: "POST",
+        service: "s3",
+        path: url.path(),
+        query: "delete=",
+        headers: &headers,
+        payload_hash: &payload_hash,
+        amz_date: &amz_date,
+        region: &bucket.region().to_string(),
+        access_key: &access_key,
+        secret_key: &secret_key,
+    });
+    
+    let mut request = client.post(url.clone()).body(body);
+    for (name, value) in &headers {
+        // reqwest derives the Host header from the URL
+        if *name != "host" {
+            request = request.header(*name, value);
+        }
+    }
+    
+    let response = request.header("authorization", authorization)
+        .send().await
+        .context("Batch delete request failed")?;
+    let status = response.status();
+    let text = response.text().await
+        .context("Failed to read batch delete response")?;
+    
+    if !status.is_success() {
+        return Err(anyhow!("Batch delete returned HTTP {}: {}", status.as_u16(), text.trim()));
+    }
+    
+    Ok(parse_delete_failures(&text))
+}
+
+/// Builds the XML body of a quiet multi-object delete request
+/// 
+/// # Arguments
+/// 
+/// * `keys` - The object keys to delete
+/// 
+/// # Returns
+/// 
+/// The `<Delete>` document listing every key
+fn delete_request_body(keys: &[String]) -> String {
+    let mut body = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><Delete><Quiet>true</Quiet>");
+    for key in keys {
+        body.push_str("<Object><Key>");
+        body.push_str(&xml_escape(key));
+        body.push_str("</Key></Object>");
+    }
+    body.push_str("</Delete>");
+    body
+}
+
+/// Extracts the per-key errors from a multi-object delete response
+/// 
+/// # Arguments
+/// 
+/// * `response` - The `<DeleteResult>` document returned by the backend
+/// 
+/// # Returns
+/// 
+/// One `BatchDeleteFailure` per `<Error>` element, in document order
+fn parse_delete_failures(response: &str) -> Vec<BatchDeleteFailure> {
+    let mut failures = Vec::new();
+    let mut rest = response;
+    
+    while let Some(start) = rest.find("<Error>") {
+        let after = &rest[start + "<Error>".len()..];
+        let end = after.find("</Error>").unwrap_or(after.len());
+        let element = &after[..end];
+        
+        failures.push(BatchDeleteFailure {
+            key: xml_unescape(element_text(element, "Key")),
+            code: xml_unescape(element_text(element, "Code")),
+            message: xml_unescape(element_text(element, "Message")),
+        });
+        rest = &after[end..];
+    }
+    
+    failures
+}
+
+/// Gets the text of a child element, or an empty string if it is missing
+pub fn element_text<'a>(xml: &'a str, tag: &str) -> &'a str {
+    let open = format!("<{}>", tag);
+    let close = format!("</{}>", tag);
+    xml.find(&open)
+        .map(|start| &xml[start + open.len()..])
+        .and_then(|text| text.find(&close).map(|end| &text[..end]))
+        .unwrap_or("")
+}
+
+/// Escapes text for use in XML content
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Reverses `xml_escape`
+pub fn xml_unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_delete_request_body() {
+        let body = delete_request_body(&["a".to_string(), "b&c".to_string()]);
+        
+        assert!(body.contains("<Quiet>true</Quiet>"));
+        assert!(body.contains("<Object><Key>a</Key></Object>"));
+        assert!(body.contains("<Object><Key>b&amp;c</Key></Object>"));
+    }
+    
+    #[test]
+    fn test_parse_delete_failures() {
+        let response = r#"<?xml version="1.0" encoding="UTF-8"?>
+<DeleteResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+  <Error><Key>file-a</Key><Code>AccessDenied</Code><Message>Access Denied</Message></Error>
+  <Error><Key>b&amp;c</Key><Code>InternalError</Code><Message>Try again</Message></Error>
+</DeleteResult>"#;
+
+        let failures = parse_delete_failures(response);
+        assert_eq!(failures.len(), 2);
+        assert_eq!(failures[0].key, "file-a");
+        assert!(failures[0].is_access_denied());
+        assert_eq!(failures[1].key, "b&c");
+        assert!(!failures[1].is_access_denied());
+        
+        assert!(parse_delete_failures("<DeleteResult></DeleteResult>").is_empty());
+    }
+}
@@ -3,14 +3,35 @@
 //! This module handles the cleanup of test files uploaded during consistency testing.
 //! It provides robust cleanup capabilities including retry logic, emergency cleanup
 //! for program interruption, and tracking of active test files.
+//! 
+//! When many files are left to clean up at once, they are removed with
+//! multi-object batch deletes, falling back to single deletes on backends
+//! that do not support them.
+//! 
+//! When the bucket replicates to another bucket, a second manager for the
+//! destination can be attached with `with_replica`, so every file is also
+//! removed from the replication target.
+//! 
+//! When test files are spread across several buckets, a manager for each
+//! additional bucket can be attached with `with_shard`, and each file is
+//! registered with and deleted through the manager of its bucket.
 
 use s3::Bucket;
+use s3::error::S3Error;
+use std::collections::HashSet;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, Notify};
 use tokio::time::sleep;
+use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, warn};
 
+use crate::batch_delete::{MAX_BATCH_DELETE_KEYS, delete_objects};
+use crate::requests::{RequestCounter, RequestKind};
+use crate::storage::ObjectStore;
+use crate::tui::restore_terminal;
+
 /// Manages cleanup operations for S3 test files
 /// 
 /// This structure tracks active test files and provides methods for
@@ -23,14 +44,56 @@ use tracing::{debug, error, info, warn};
 /// The internal file tracking uses `Mutex` for thread-safe access.
 #[derive(Debug)]
 pub struct CleanupManager {
-    /// S3 bucket handle for delete operations
-    bucket: Bucket,
+    /// Object store that single deletes go through
+    storage: Arc<dyn ObjectStore>,
+    
+    /// S3 bucket handle for multi-object delete requests
+    /// 
+    /// `None` unless enabled with `with_batch_delete`, in which case every
+    /// file is deleted individually through `storage`.
+    bucket: Option<Bucket>,
     
     /// List of currently active test files that need cleanup
     /// 
     /// Files are added when uploaded and removed when successfully cleaned up.
     /// This allows for emergency cleanup of all remaining files if needed.
     active_files: Arc<Mutex<Vec<String>>>,
+    
+    /// Test files whose deletion was persistently denied by the backend
+    /// 
+    /// Typically caused by a bucket policy that allows uploads but not
+    /// deletes. These files are left behind in the bucket.
+    denied_files: Arc<Mutex<Vec<String>>>,
+    
+    /// Test files currently being deleted
+    /// 
+    /// A file can be cleaned up from several paths at once, e.g. its regular
+    /// cleanup and an emergency cleanup. Only the first claims the file; the
+    /// others skip it instead of sending redundant deletes.
+    deleting_files: Arc<Mutex<HashSet<String>>>,
+    
+    /// Counter that every delete request is recorded in
+    requests: Arc<RequestCounter>,
+    
+    /// HTTP client for multi-object delete requests
+    http: reqwest::Client,
+    
+    /// Whether the backend is still assumed to support multi-object delete
+    /// 
+    /// Cleared the first time a batch delete request fails as a whole, so
+    /// later cleanups go straight to single deletes.
+    batch_delete_supported: AtomicBool,
+    
+    /// Cleanup manager for the bucket that test files replicate to
+    /// 
+    /// Every registered file is also tracked and deleted there.
+    replica: Option<Box<CleanupManager>>,
+    
+    /// Cleanup managers for additional buckets, by bucket name
+    /// 
+    /// Files are only tracked by the manager of the bucket they were
+    /// uploaded to, see `for_bucket`.
+    shards: Vec<(String, CleanupManager)>,
 }
 
 impl CleanupManager {
@@ -38,7 +101,7 @@ impl CleanupManager {
     /// 
     /// # Arguments
     /// 
-    /// * `bucket` - S3 bucket handle for performing delete operations
+    /// * `storage` - Object store for performing delete operations
     /// 
     /// # Returns
     /// 
@@ -47,15 +110,139 @@ impl CleanupManager {
     /// # Examples
     /// 
     /// ```rust
-    /// let cleanup = CleanupManager::new(bucket);
+    /// let cleanup = CleanupManager::new(Arc::new(bucket.clone())).with_batch_delete(bucket);
     /// ```
-    pub fn new(bucket: Bucket) -> Self {
+    pub fn new(storage: Arc<dyn ObjectStore>) -> Self {
         Self {
-            bucket,
+            storage,
+            bucket: None,
             active_files: Arc::new(Mutex::new(Vec::new())),
+            denied_files: Arc::new(Mutex::new(Vec::new())),
+            deleting_files: Arc::new(Mutex::new(HashSet::new())),
+            requests: Arc::new(RequestCounter::default()),
+            http: reqwest::Client::new(),
+            batch_delete_supported: AtomicBool::new(true),
+            replica: None,
+            shards: Vec::new(),
         }
     }
     
+    /// Records delete requests in a shared request counter
+    /// 
+    /// By default the cleanup manager counts into its own private counter.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `requests` - The counter to record delete requests in
+    /// 
+    /// # Returns
+    /// 
+    /// The cleanup manager using the given counter
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let cleanup = CleanupManager::new(storage).with_request_counter(requests.clone());
+    /// ```
+    pub fn with_request_counter(mut self, requests: Arc<RequestCounter>) -> Self {
+        self.requests = requests;
+        self
+    }
+    
+    /// Removes many files at once with multi-object batch deletes
+    /// 
+    /// Multi-object deletes are signed requests sent straight to the bucket
+    /// endpoint, so they need the S3 bucket handle of the object store.
+    /// Without it every file is deleted individually.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `bucket` - S3 bucket handle for multi-object delete requests
+    /// 
+    /// # Returns
+    /// 
+    /// The cleanup manager using batch deletes where supported
+    pub fn with_batch_delete(mut self, bucket: Bucket) -> Self {
+        self.bucket = Some(bucket);
+        self
+    }
+    
+    /// Also cleans up the copies of test files in a replication target
+    /// 
+    /// Replication copies each test file to the destination bucket, where
+    /// it would otherwise be left behind. Every file registered with this
+    /// manager is registered with `replica` as well, and each cleanup deletes
+    /// the file from both buckets.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `replica` - Cleanup manager for the destination bucket
+    /// 
+    /// # Returns
+    /// 
+    /// The cleanup manager cleaning up both buckets
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let cleanup = CleanupManager::new(source).with_replica(CleanupManager::new(destination));
+    /// ```
+    pub fn with_replica(mut self, replica: CleanupManager) -> Self {
+        self.replica = Some(Box::new(replica));
+        self
+    }
+    
+    /// Cleans up test files uploaded to another bucket as well
+    /// 
+    /// For test files spread across several buckets. Files are registered
+    /// with the manager `for_bucket` returns, while the emergency cleanup,
+    /// the final cleanup check and the denied files cover every bucket.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `bucket` - Name of the additional bucket
+    /// * `shard` - Cleanup manager for that bucket
+    /// 
+    /// # Returns
+    /// 
+    /// The cleanup manager covering the additional bucket
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let cleanup = CleanupManager::new(primary).with_shard("shard-b".to_string(), CleanupManager::new(shard));
+    /// ```
+    pub fn with_shard(mut self, bucket: String, shard: CleanupManager) -> Self {
+        self.shards.push((bucket, shard));
+        self
+    }
+    
+    /// Gets the cleanup manager for files uploaded to a bucket
+    /// 
+    /// # Arguments
+    /// 
+    /// * `bucket` - Name of the bucket the files were uploaded to
+    /// 
+    /// # Returns
+    /// 
+    /// The manager attached for `bucket` with `with_shard`, or this manager
+    /// for any other bucket
+    pub fn for_bucket(&self, bucket: &str) -> &CleanupManager {
+        self.shards.iter()
+            .find(|(name, _)| name == bucket)
+            .map_or(self, |(_, shard)| shard)
+    }
+    
+    /// Gets this manager followed by the replica manager, if any
+    fn managers(&self) -> impl Iterator<Item = &CleanupManager> {
+        std::iter::once(self).chain(self.replica.as_deref())
+    }
+    
+    /// Gets every manager, including the replica and the additional buckets
+    fn all_managers(&self) -> impl Iterator<Item = &CleanupManager> {
+        self.managers().chain(self.shards.iter().map(|(_, shard)| shard))
+    }
+    
     /// Registers a test file as active
     /// 
     /// Adds the file to the internal tracking list so it can be cleaned up
@@ -73,8 +260,10 @@ impl CleanupManager {
     /// ```
     pub async fn register_file(&self, file_key: &str) {
         debug!("Registering test file for cleanup: {}", file_key);
-        let mut active_files = self.active_files.lock().await;
-        active_files.push(file_key.to_string());
+        for manager in self.managers() {
+            let mut active_files = manager.active_files.lock().await;
+            active_files.push(file_key.to_string());
+        }
     }
     
     /// Unregisters a test file from active tracking
@@ -113,30 +302,84 @@ impl CleanupManager {
     /// - Waits 1 second between attempts  
     /// - Logs warnings for failed attempts
     /// - Logs errors if all attempts fail
+    /// - Records the file as denied if every attempt failed with access denied
     /// - Automatically unregisters the file if deletion succeeds
+    /// - Does nothing if another cleanup is already deleting the file
+    /// - Also deletes the file from the replication target, if one is attached
+    /// 
+    /// # Returns
+    /// 
+    /// `true` if the file was deleted, `false` if every attempt failed or
+    /// another cleanup is already deleting it. Only the primary bucket is
+    /// considered.
     /// 
     /// # Examples
     /// 
     /// ```rust
     /// cleanup.cleanup_file("consistency-test-abc123").await;
     /// ```
-    pub async fn cleanup_file(&self, file_key: &str) {
+    pub async fn cleanup_file(&self, file_key: &str) -> bool {
+        let deleted = self.cleanup_file_in_bucket(file_key).await;
+        if let Some(replica) = &self.replica {
+            replica.cleanup_file_in_bucket(file_key).await;
+        }
+        deleted
+    }
+    
+    /// Cleans up a single test file in this manager's bucket only
+    /// 
+    /// # Arguments
+    /// 
+    /// * `file_key` - The S3 object key to delete
+    /// 
+    /// # Returns
+    /// 
+    /// `true` if the file was deleted, `false` if every attempt failed or
+    /// another cleanup is already deleting it
+    async fn cleanup_file_in_bucket(&self, file_key: &str) -> bool {
+        let claimed = self.claim_files(&[file_key.to_string()]).await;
+        if claimed.is_empty() {
+            debug!("Cleanup already in progress for test file: {}", file_key);
+            return false;
+        }
+        
+        let deleted = self.delete_file_with_retries(file_key).await;
+        self.release_files(&claimed).await;
+        deleted
+    }
+    
+    /// Deletes a single test file, retrying failed attempts
+    /// 
+    /// # Arguments
+    /// 
+    /// * `file_key` - The S3 object key to delete, already claimed by the caller
+    /// 
+    /// # Returns
+    /// 
+    /// `true` if the file was deleted, `false` if every attempt failed
+    async fn delete_file_with_retries(&self, file_key: &str) -> bool {
         debug!("Starting cleanup for test file: {}", file_key);
         
         // Try multiple times to ensure cleanup succeeds
+        let mut always_denied = true;
         for attempt in 1..=3 {
-            match self.bucket.delete_object(file_key).await {
-                Ok(_) => {
+            self.requests.record(RequestKind::Delete);
+            match self.storage.delete(file_key).await {
+                Ok(()) => {
                     debug!("Successfully cleaned up test file: {}", file_key);
                     self.unregister_file(file_key).await;
-                    return;
+                    return true;
                 }
                 Err(e) => {
+                    always_denied &= is_access_denied(&e);
                     if attempt == 3 {
                         error!(
                             "Failed to clean up test file {} after {} attempts: {}",
                             file_key, attempt, e
                         );
+                        if always_denied {
+                            self.record_denied_file(file_key).await;
+                        }
                     } else {
                         warn!(
                             "Cleanup attempt {} failed for {}: {}, retrying in 1s...",
@@ -147,6 +390,8 @@ impl CleanupManager {
                 }
             }
         }
+        
+        false
     }
     
     /// Performs emergency cleanup of all active test files
@@ -158,10 +403,11 @@ impl CleanupManager {
     /// # Behavior
     /// 
     /// - Gets a snapshot of all currently active files
-    /// - Attempts to clean up each file individually
+    /// - Deletes them in batches with `cleanup_files`
     /// - Continues even if some cleanups fail
     /// - Clears the active file list when complete
     /// - Logs progress and completion status
+    /// - Covers the replication target and every additional bucket
     /// 
     /// # Examples
     /// 
@@ -170,39 +416,171 @@ impl CleanupManager {
     /// cleanup.emergency_cleanup().await;
     /// ```
     pub async fn emergency_cleanup(&self) {
-        // Get a snapshot of active files to avoid holding the lock during cleanup
-        let active_files = {
-            let active_files_guard = self.active_files.lock().await;
-            active_files_guard.clone()
-        };
-        
-        if active_files.is_empty() {
-            debug!("No active test files to clean up");
-            return;
+        for manager in self.all_managers() {
+            // Get a snapshot of active files to avoid holding the lock during cleanup
+            let active_files = manager.get_active_files().await;
+            
+            if active_files.is_empty() {
+                debug!("No active test files to clean up");
+                continue;
+            }
+            
+            info!("Starting emergency cleanup of {} test files...", active_files.len());
+            
+            // Batch deletes without retries to speed up shutdown
+            manager.cleanup_files_in_bucket(&active_files).await;
+            
+            // Clear the active file list
+            {
+                let mut active_files_guard = manager.active_files.lock().await;
+                active_files_guard.clear();
+            }
+            
+            info!("Emergency cleanup completed for {} files", active_files.len());
+        }
+    }
+    
+    /// Cleans up many test files with multi-object batch deletes
+    /// 
+    /// Deletes the files in batches of up to 1000 keys per request. If the
+    /// backend rejects a batch delete request as a whole (typically because it
+    /// does not implement multi-object delete), that batch and all later ones
+    /// are deleted one file at a time instead. Files are not retried.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `file_keys` - The S3 object keys to delete
+    /// 
+    /// # Behavior
+    /// 
+    /// - Counts each batch request as one delete request
+    /// - Records files whose deletion was denied
+    /// - Unregisters every file that was deleted
+    /// - Skips files another cleanup is already deleting
+    /// - Also deletes the files from the replication target, if one is attached
+    /// 
+    /// # Returns
+    /// 
+    /// The number of files that were deleted from the primary bucket by this call
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let files = cleanup.get_active_files().await;
+    /// let deleted = cleanup.cleanup_files(&files).await;
+    /// ```
+    pub async fn cleanup_files(&self, file_keys: &[String]) -> usize {
+        let deleted = self.cleanup_files_in_bucket(file_keys).await;
+        if let Some(replica) = &self.replica {
+            replica.cleanup_files_in_bucket(file_keys).await;
+        }
+        deleted
+    }
+    
+    /// Cleans up many test files in this manager's bucket only
+    /// 
+    /// # Arguments
+    /// 
+    /// * `file_keys` - The S3 object keys to delete
+    /// 
+    /// # Returns
+    /// 
+    /// The number of files that were deleted by this call
+    async fn cleanup_files_in_bucket(&self, file_keys: &[String]) -> usize {
+        let claimed = self.claim_files(file_keys).await;
+        if claimed.len() < file_keys.len() {
+            debug!(
+                "Skipping {} test files whose cleanup is already in progress",
+                file_keys.len() - claimed.len()
+            );
         }
         
-        info!("Starting emergency cleanup of {} test files...", active_files.len());
+        let mut deleted = HashSet::new();
         
-        // Clean up each file individually
-        for file_key in &active_files {
-            // Use a simpler cleanup for emergency scenarios (no retries to speed up shutdown)
-            match self.bucket.delete_object(file_key).await {
-                Ok(_) => {
-                    debug!("Emergency cleanup successful for: {}", file_key);
+        for batch in claimed.chunks(MAX_BATCH_DELETE_KEYS) {
+            if let Some(bucket) = self.bucket.as_ref().filter(|_| self.batch_delete_supported.load(Ordering::Relaxed)) {
+                self.requests.record(RequestKind::Delete);
+                match delete_objects(&self.http, bucket, batch).await {
+                    Ok(failures) => {
+                        debug!(
+                            "Batch deleted {} of {} test files",
+                            batch.len() - failures.len(), batch.len()
+                        );
+                        for failure in &failures {
+                            warn!(
+                                "Batch delete failed for {}: {} ({})",
+                                failure.key, failure.code, failure.message
+                            );
+                            if failure.is_access_denied() {
+                                self.record_denied_file(&failure.key).await;
+                            }
+                        }
+                        deleted.extend(batch.iter()
+                            .filter(|key| !failures.iter().any(|f| &f.key == *key))
+                            .cloned());
+                        continue;
+                    }
+                    Err(e) => {
+                        warn!("Batch delete unavailable, falling back to single deletes: {:#}", e);
+                        self.batch_delete_supported.store(false, Ordering::Relaxed);
+                    }
                 }
-                Err(e) => {
-                    warn!("Emergency cleanup failed for {}: {}", file_key, e);
+            }
+            
+            for file_key in batch {
+                self.requests.record(RequestKind::Delete);
+                match self.storage.delete(file_key).await {
+                    Ok(()) => {
+                        debug!("Cleanup successful for: {}", file_key);
+                        deleted.insert(file_key.clone());
+                    }
+                    Err(e) => {
+                        warn!("Cleanup failed for {}: {}", file_key, e);
+                        if is_access_denied(&e) {
+                            self.record_denied_file(file_key).await;
+                        }
+                    }
                 }
             }
         }
         
-        // Clear the active file list
         {
-            let mut active_files_guard = self.active_files.lock().await;
-            active_files_guard.clear();
+            let mut active_files = self.active_files.lock().await;
+            active_files.retain(|f| !deleted.contains(f));
         }
         
-        info!("Emergency cleanup completed for {} files", active_files.len());
+        self.release_files(&claimed).await;
+        deleted.len()
+    }
+    
+    /// Marks test files as being deleted
+    /// 
+    /// # Arguments
+    /// 
+    /// * `file_keys` - The S3 object keys about to be deleted
+    /// 
+    /// # Returns
+    /// 
+    /// The keys that were not already being deleted, which the caller now
+    /// owns and must pass to `release_files` when done
+    async fn claim_files(&self, file_keys: &[String]) -> Vec<String> {
+        let mut deleting_files = self.deleting_files.lock().await;
+        file_keys.iter()
+            .filter(|key| deleting_files.insert((*key).clone()))
+            .cloned()
+            .collect()
+    }
+    
+    /// Marks claimed test files as no longer being deleted
+    /// 
+    /// # Arguments
+    /// 
+    /// * `file_keys` - Keys returned by `claim_files`
+    async fn release_files(&self, file_keys: &[String]) {
+        let mut deleting_files = self.deleting_files.lock().await;
+        for file_key in file_keys {
+            deleting_files.remove(file_key);
+        }
     }
     
     /// Gets the number of currently active test files
@@ -247,11 +625,55 @@ impl CleanupManager {
         active_files.clone()
     }
     
+    /// Gets the test files whose deletion was denied by the backend
+    /// 
+    /// A file is included when every delete attempt for it failed with
+    /// access denied, which usually means a bucket policy forbids deletes.
+    /// Files denied in the replication target and in additional buckets are
+    /// included as well.
+    /// 
+    /// # Returns
+    /// 
+    /// A vector containing the denied file keys, in the order they were denied
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let denied = cleanup.get_denied_files().await;
+    /// if !denied.is_empty() {
+    ///     print_cleanup_denied_warning(&denied);
+    /// }
+    /// ```
+    pub async fn get_denied_files(&self) -> Vec<String> {
+        let mut denied = Vec::new();
+        for manager in self.all_managers() {
+            for file_key in manager.denied_files.lock().await.iter() {
+                if !denied.contains(file_key) {
+                    denied.push(file_key.clone());
+                }
+            }
+        }
+        denied
+    }
+    
+    /// Records a test file whose deletion was denied
+    /// 
+    /// # Arguments
+    /// 
+    /// * `file_key` - The S3 object key that could not be deleted
+    async fn record_denied_file(&self, file_key: &str) {
+        let mut denied_files = self.denied_files.lock().await;
+        if !denied_files.iter().any(|f| f == file_key) {
+            denied_files.push(file_key.to_string());
+        }
+    }
+    
     /// Performs a final cleanup check
     /// 
     /// This method should be called at the end of testing to ensure
-    /// all test files have been properly cleaned up. It will attempt
-    /// to clean up any remaining files and log warnings if any are found.
+    /// all test files have been properly cleaned up. It will batch delete
+    /// any remaining files, in the replication target and additional buckets
+    /// as well, and log warnings if any are found or left behind.
     /// 
     /// # Examples
     /// 
@@ -260,77 +682,185 @@ impl CleanupManager {
     /// cleanup.final_cleanup_check().await;
     /// ```
     pub async fn final_cleanup_check(&self) {
-        let active_count = self.active_file_count().await;
-        
-        if active_count > 0 {
-            warn!(
-                "Found {} test files still active at end of testing, cleaning up...",
-                active_count
-            );
-            self.emergency_cleanup().await;
-        } else {
-            debug!("All test files cleaned up successfully");
+        for manager in self.all_managers() {
+            let active_files = manager.get_active_files().await;
+            
+            if !active_files.is_empty() {
+                warn!(
+                    "Found {} test files still active at end of testing, cleaning up...",
+                    active_files.len()
+                );
+                let deleted = manager.cleanup_files_in_bucket(&active_files).await;
+                if deleted < active_files.len() {
+                    warn!("{} test files could not be cleaned up", active_files.len() - deleted);
+                }
+            } else {
+                debug!("All test files cleaned up successfully");
+            }
         }
     }
 }
 
+/// Determines whether a delete error means the request was not authorized
+/// 
+/// # Arguments
+/// 
+/// * `error` - The error returned by a delete attempt
+/// 
+/// # Returns
+/// 
+/// `true` for HTTP 401/403 responses, `false` for any other error
+fn is_access_denied(error: &S3Error) -> bool {
+    matches!(error, S3Error::HttpFailWithBody(401 | 403, _))
+}
+
+/// Prints a prominent warning that cleanup was denied by the backend
+/// 
+/// Lists every affected key so the files can be removed manually. This is
+/// printed separately from the regular log output because uploads succeeding
+/// while deletes are denied otherwise only shows up as easily missed errors.
+/// 
+/// # Arguments
+/// 
+/// * `denied_files` - Keys of the files whose deletion was denied
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// print_cleanup_denied_warning(&cleanup.get_denied_files().await);
+/// ```
+pub fn print_cleanup_denied_warning(denied_files: &[String]) {
+    println!("\n{}", "!".repeat(50));
+    println!("   ⛔ CLEANUP DENIED - TEST FILES LEFT BEHIND");
+    println!("{}", "!".repeat(50));
+    println!("The backend returned access denied for every delete of {} test file(s).", denied_files.len());
+    println!("Uploads are allowed but deletes are not; check the bucket policy");
+    println!("and the permissions of the configured credentials.");
+    println!("\nFiles remaining in the bucket:");
+    for file_key in denied_files {
+        println!("   {}", file_key);
+    }
+    println!("{}", "!".repeat(50));
+}
+
 /// Sets up a cleanup signal handler for graceful shutdown
 /// 
-/// This function sets up a Ctrl+C signal handler that will trigger
-/// emergency cleanup when the program is interrupted. This ensures
-/// test files are cleaned up even if the program is terminated unexpectedly.
+/// This function sets up a Ctrl+C signal handler. The first interrupt stops
+/// the run gracefully: files in flight are finished, the rest are skipped,
+/// and the report is produced for what completed. A second interrupt
+/// triggers emergency cleanup and exits immediately. This ensures test
+/// files are cleaned up even if the program is terminated unexpectedly.
 /// 
 /// # Arguments
 /// 
 /// * `cleanup_manager` - The cleanup manager to use for emergency cleanup
+/// * `stop` - Token cancelled on the first interrupt to stop the run gracefully
 /// 
 /// # Behavior
 /// 
 /// - Spawns a background task to listen for Ctrl+C
-/// - Triggers emergency cleanup when signal is received
-/// - Exits the program after cleanup is complete
+/// - Also reacts to the returned interrupt trigger, for callers such as the
+///   terminal dashboard that receive Ctrl+C as a key press instead of a signal
+/// - Cancels `stop` on the first interrupt
+/// - On the second interrupt, restores the terminal if the dashboard is
+///   active, triggers emergency cleanup and exits the program after cleanup
+///   is complete
 /// - Logs the cleanup process
 /// 
+/// # Returns
+/// 
+/// A trigger that counts as an interrupt when notified
+/// 
 /// # Examples
 /// 
 /// ```rust
-/// let cleanup = Arc::new(CleanupManager::new(bucket));
-/// setup_cleanup_handler(cleanup.clone());
+/// let cleanup = Arc::new(CleanupManager::new(storage));
+/// let interrupt = setup_cleanup_handler(cleanup.clone(), tester.stop_token());
 /// ```
-pub fn setup_cleanup_handler(cleanup_manager: Arc<CleanupManager>) {
+pub fn setup_cleanup_handler(cleanup_manager: Arc<CleanupManager>, stop: CancellationToken) -> Arc<Notify> {
+    setup_scoped_cleanup_handler(cleanup_manager, stop, CancellationToken::new())
+}
+
+/// Sets up a cleanup signal handler that is removed once a run is done
+/// 
+/// Behaves like [`setup_cleanup_handler`], but the handler stops listening
+/// when `done` is cancelled. Used when several testers run one after another,
+/// so that the handler of a finished run cannot exit the program while a
+/// later run still has test files to clean up.
+/// 
+/// # Arguments
+/// 
+/// * `cleanup_manager` - The cleanup manager to use for emergency cleanup
+/// * `stop` - Token cancelled on the first interrupt to stop the run gracefully
+/// * `done` - Token cancelled when the run has finished and cleaned up
+/// 
+/// # Returns
+/// 
+/// A trigger that counts as an interrupt when notified
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// let done = CancellationToken::new();
+/// setup_scoped_cleanup_handler(tester.cleanup_manager(), tester.stop_token(), done.clone());
+/// let report = tester.run_consistency_test(&args).await;
+/// done.cancel();
+/// ```
+pub fn setup_scoped_cleanup_handler(
+    cleanup_manager: Arc<CleanupManager>,
+    stop: CancellationToken,
+    done: CancellationToken,
+) -> Arc<Notify> {
+    let interrupt = Arc::new(Notify::new());
+    let trigger = interrupt.clone();
+    
     tokio::spawn(async move {
-        match tokio::signal::ctrl_c().await {
-            Ok(()) => {
-                warn!("Received interrupt signal (Ctrl+C), initiating cleanup...");
-                cleanup_manager.emergency_cleanup().await;
-                info!("Cleanup completed, exiting");
-                std::process::exit(1);
+        loop {
+            tokio::select! {
+                result = tokio::signal::ctrl_c() => match result {
+                    Ok(()) => warn!("Received interrupt signal (Ctrl+C)"),
+                    Err(err) => {
+                        error!("Failed to listen for shutdown signal: {}", err);
+                        tokio::select! {
+                            _ = interrupt.notified() => warn!("Run interrupted"),
+                            _ = done.cancelled() => return,
+                        }
+                    }
+                },
+                _ = interrupt.notified() => warn!("Run interrupted"),
+                _ = done.cancelled() => return,
             }
-            Err(err) => {
-                error!("Failed to listen for shutdown signal: {}", err);
+            
+            if stop.is_cancelled() {
+                warn!("Interrupted again, initiating cleanup...");
+                break;
             }
+            
+            warn!("Finishing files in flight and reporting; press Ctrl+C again to stop immediately");
+            stop.cancel();
         }
+        
+        restore_terminal();
+        cleanup_manager.emergency_cleanup().await;
+        info!("Cleanup completed, exiting");
+        std::process::exit(1);
     });
+    
+    trigger
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use s3::{Bucket, Region, creds::Credentials};
+    use crate::mock::MockStorage;
 
-    fn create_test_bucket() -> Bucket {
-        let credentials = Credentials::default().unwrap();
-        let region = Region::Custom {
-            region: "test".to_string(),
-            endpoint: "http://localhost:9000".to_string(),
-        };
-        Bucket::new("test-bucket", region, credentials).unwrap()
+    fn create_test_storage() -> Arc<MockStorage> {
+        Arc::new(MockStorage::new(Duration::ZERO, 0.0))
     }
 
     #[tokio::test]
     async fn test_register_and_unregister_file() {
-        let bucket = create_test_bucket();
-        let cleanup = CleanupManager::new(bucket);
+        let cleanup = CleanupManager::new(create_test_storage());
         
         assert_eq!(cleanup.active_file_count().await, 0);
         
@@ -347,10 +877,45 @@ mod tests {
         assert_eq!(active_files, vec!["test-file-2"]);
     }
     
+    #[tokio::test]
+    async fn test_record_denied_file() {
+        let cleanup = CleanupManager::new(create_test_storage());
+        
+        assert!(cleanup.get_denied_files().await.is_empty());
+        
+        cleanup.record_denied_file("file-a").await;
+        cleanup.record_denied_file("file-b").await;
+        cleanup.record_denied_file("file-a").await;
+        
+        assert_eq!(cleanup.get_denied_files().await, vec!["file-a", "file-b"]);
+    }
+    
+    #[tokio::test]
+    async fn test_claim_files_deduplicates() {
+        let cleanup = CleanupManager::new(create_test_storage());
+        let keys = vec!["file-a".to_string(), "file-b".to_string()];
+        
+        assert_eq!(cleanup.claim_files(&keys[..1]).await, vec!["file-a"]);
+        
+        // A second cleanup only gets the file that is not already being deleted
+        assert_eq!(cleanup.claim_files(&keys).await, vec!["file-b"]);
+        assert!(cleanup.claim_files(&keys).await.is_empty());
+        
+        cleanup.release_files(&keys[..1]).await;
+        assert_eq!(cleanup.claim_files(&keys).await, vec!["file-a"]);
+    }
+    
+    #[test]
+    fn test_is_access_denied() {
+        assert!(is_access_denied(&S3Error::HttpFailWithBody(403, String::new())));
+        assert!(is_access_denied(&S3Error::HttpFailWithBody(401, String::new())));
+        assert!(!is_access_denied(&S3Error::HttpFailWithBody(404, String::new())));
+        assert!(!is_access_denied(&S3Error::HttpFailWithBody(503, String::new())));
+    }
+    
     #[tokio::test]
     async fn test_get_active_files() {
-        let bucket = create_test_bucket();
-        let cleanup = CleanupManager::new(bucket);
+        let cleanup = CleanupManager::new(create_test_storage());
         
         cleanup.register_file("file-a").await;
         cleanup.register_file("file-b").await;
@@ -362,4 +927,64 @@ mod tests {
         assert!(active_files.contains(&"file-b".to_string()));
         assert!(active_files.contains(&"file-c".to_string()));
     }
+    
+    #[tokio::test]
+    async fn test_cleanup_deletes_from_storage() {
+        let storage = create_test_storage();
+        let cleanup = CleanupManager::new(storage.clone());
+        let keys = vec!["file-a".to_string(), "file-b".to_string()];
+        
+        for key in &keys {
+            storage.put(key, b"data", None).await.unwrap();
+            cleanup.register_file(key).await;
+        }
+        
+        assert_eq!(cleanup.cleanup_files(&keys).await, 2);
+        assert_eq!(storage.object_count(), 0);
+        assert_eq!(cleanup.active_file_count().await, 0);
+    }
+    
+    #[tokio::test]
+    async fn test_cleanup_deletes_from_replica() {
+        let source = create_test_storage();
+        let destination = create_test_storage();
+        let cleanup = CleanupManager::new(source.clone())
+            .with_replica(CleanupManager::new(destination.clone()));
+        
+        for key in ["file-a", "file-b"] {
+            source.put(key, b"data", None).await.unwrap();
+            destination.put(key, b"data", None).await.unwrap();
+            cleanup.register_file(key).await;
+        }
+        
+        assert!(cleanup.cleanup_file("file-a").await);
+        assert_eq!(destination.object_count(), 1);
+        
+        cleanup.final_cleanup_check().await;
+        assert_eq!(source.object_count(), 0);
+        assert_eq!(destination.object_count(), 0);
+    }
+    
+    #[tokio::test]
+    async fn test_cleanup_deletes_from_shard() {
+        let primary = create_test_storage();
+        let shard = create_test_storage();
+        let cleanup = CleanupManager::new(primary.clone())
+            .with_shard("shard-b".to_string(), CleanupManager::new(shard.clone()));
+        
+        primary.put("file-a", b"data", None).await.unwrap();
+        cleanup.for_bucket("shard-a").register_file("file-a").await;
+        for key in ["file-b", "file-c"] {
+            shard.put(key, b"data", None).await.unwrap();
+            cleanup.for_bucket("shard-b").register_file(key).await;
+        }
+        
+        assert_eq!(cleanup.active_file_count().await, 1);
+        assert!(cleanup.for_bucket("shard-b").cleanup_file("file-b").await);
+        assert_eq!(shard.object_count(), 1);
+        
+        cleanup.final_cleanup_check().await;
+        assert_eq!(primary.object_count(), 0);
+        assert_eq!(shard.object_count(), 0);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,125 @@
+//! Importing propagation durations for offline analysis
+//! 
+//! The `stats` subcommand runs the statistics engine on propagation times
+//! measured elsewhere, without touching S3. Durations are read from a plain
+//! text file with one value in milliseconds per line.
+
+use anyhow::{Context, Result, anyhow};
+use chrono::{Duration, Utc};
+use std::io::Read;
+use std::path::Path;
+
+use crate::types::TestResult;
+
+/// Loads propagation durations from a file, or from stdin if the path is `-`
+/// 
+/// # Arguments
+/// 
+/// * `path` - Path to the durations file, or `-` for stdin
+/// 
+/// # Returns
+/// 
+/// - `Ok(Vec<u64>)` with the durations in milliseconds, in file order
+/// - `Err(anyhow::Error)` if the file cannot be read or contains an invalid value
+pub fn load_durations(path: &Path) -> Result<Vec<u64>> {
+    if path == Path::new("-") {
+        let mut content = String::new();
+        std::io::stdin().read_to_string(&mut content)
+            .context("Failed to read durations from stdin")?;
+        return parse_durations(&content, "stdin");
+    }
+    
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read durations file: {}", path.display()))?;
+    parse_durations(&content, &path.display().to_string())
+}
+
+/// Parses propagation durations from text
+/// 
+/// Each non-empty line holds one duration in milliseconds. Fractional values
+/// are rounded to the nearest millisecond. Anything after a `#` is a comment.
+/// 
+/// # Arguments
+/// 
+/// * `content` - The text to parse
+/// * `source` - Description of the source used in error messages
+/// 
+/// # Returns
+/// 
+/// - `Ok(Vec<u64>)` with the durations in milliseconds
+/// - `Err(anyhow::Error)` naming the first line that is not a non-negative number
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// let durations = parse_durations("120\n# slow one\n2400.6\n", "example")?;
+/// assert_eq!(durations, vec![120, 2401]);
+/// ```
+pub fn parse_durations(content: &str, source: &str) -> Result<Vec<u64>> {
+    let mut durations = Vec::new();
+    
+    for (index, line) in content.lines().enumerate() {
+        let value = line.split('#').next().unwrap_or("").trim();
+        if value.is_empty() {
+            continue;
+        }
+        
+        let duration = value.parse::<f64>().ok()
+            .filter(|duration| duration.is_finite() && *duration >= 0.0)
+            .ok_or_else(|| anyhow!(
+                "Invalid duration '{}' on line {} of {} (expected milliseconds)",
+                value, index + 1, source
+            ))?;
+        durations.push(duration.round() as u64);
+    }
+    
+    Ok(durations)
+}
+
+/// Converts imported durations into successful test results
+/// 
+/// Lets imported durations go through the same statistics code as measured
+/// ones. Each result gets a synthetic key and upload time.
+/// 
+/// # Arguments
+/// 
+/// * `durations` - Propagation durations in milliseconds
+/// 
+/// # Returns
+/// 
+/// One successful `TestResult` per duration
+pub fn results_from_durations(durations: &[u64]) -> Vec<TestResult> {
+    let upload_time = Utc::now();
+    durations.iter()
+        .enumerate()
+        .map(|(i, &duration_ms)| TestResult::success(
+            format!("imported-{}", i + 1),
+            upload_time,
+            upload_time + Duration::milliseconds(duration_ms as i64),
+            1,
+        ))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_parse_durations() {
+        let durations = parse_durations("120\n\n  340 \n# comment\n2400.6 # slow\n", "test").unwrap();
+        assert_eq!(durations, vec![120, 340, 2401]);
+        
+        assert!(parse_durations("120\nfast\n", "test").is_err());
+        assert!(parse_durations("-5\n", "test").is_err());
+    }
+    
+    #[test]
+    fn test_results_from_durations() {
+        let results = results_from_durations(&[120, 340]);
+        
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(|r| r.success));
+        assert_eq!(results[1].propagation_duration_ms, Some(340));
+    }
+}
@@ -0,0 +1,89 @@
+//! Error types for S3 consistency testing
+//! 
+//! The tester's public API returns `ConsistencyTestError`, so callers can
+//! tell failed uploads, reads, cleanup, configuration, connection and
+//! results file problems apart by matching on the variant instead of parsing messages. The binary
+//! converts these into `anyhow` errors at the `main` boundary.
+
+use s3::error::S3Error;
+use std::path::PathBuf;
+use thiserror::Error;
+
+/// Error raised while setting up or running a consistency test
+#[derive(Debug, Error)]
+pub enum ConsistencyTestError {
+    /// Uploading a test file failed with the given backend error
+    #[error("Upload failed: {0}")]
+    Upload(#[source] S3Error),
+    
+    /// A test file never produced a consistent read
+    #[error("{message}")]
+    Read {
+        /// Number of read attempts made
+        attempts: u32,
+        
+        /// Why polling was abandoned before `max_wait`, if it was
+        /// 
+        /// `None` if polling ran until it timed out.
+        early_abort_reason: Option<String>,
+        
+        /// Description of the failure
+        message: String,
+    },
+    
+    /// Test files were left behind in the bucket after cleanup
+    #[error("{} test file(s) remain in bucket {bucket} after cleanup: {}", .remaining.len(), .remaining.join(", "))]
+    Cleanup {
+        /// The bucket that was checked
+        bucket: String,
+        
+        /// Keys of the test files that remain
+        remaining: Vec<String>,
+    },
+    
+    /// The configuration cannot be used to build an S3 client
+    #[error("Invalid configuration: {0}")]
+    Config(String),
+    
+    /// A client for the endpoint could not be created or a request to it failed
+    #[error("{message} ({endpoint})")]
+    Connection {
+        /// The endpoint the client was for
+        endpoint: String,
+        
+        /// Description of the failure
+        message: String,
+    },
+    
+    /// The `--results-jsonl` file could not be created
+    #[error("Failed to create results file {}: {source}", .path.display())]
+    ResultsFile {
+        /// The file that was being created
+        path: PathBuf,
+        
+        /// The underlying I/O error
+        #[source]
+        source: std::io::Error,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_error_messages() {
+        let upload = ConsistencyTestError::Upload(S3Error::HttpFailWithBody(503, "SlowDown".to_string()));
+        assert!(upload.to_string().starts_with("Upload failed: "));
+        assert!(std::error::Error::source(&upload).is_some());
+        
+        let cleanup = ConsistencyTestError::Cleanup {
+            bucket: "test-bucket".to_string(),
+            remaining: vec!["a".to_string(), "b".to_string()],
+        };
+        assert_eq!(cleanup.to_string(), "2 test file(s) remain in bucket test-bucket after cleanup: a, b");
+        
+        let config = ConsistencyTestError::Config("Invalid AWS region: mars-1".to_string());
+        assert_eq!(config.to_string(), "Invalid configuration: Invalid AWS region: mars-1");
+    }
+}
@@ -0,0 +1,214 @@
+//! Buffered per-file debug logging for `--verbose-failures`
+//! 
+//! Debug output for every file drowns a large run, but it is exactly what is
+//! needed to understand a file that failed. In this mode each file is uploaded
+//! and polled inside a `test_file` span, and `FailureLogLayer` holds the debug
+//! events logged inside that span in memory. When the span closes, the
+//! buffered lines are written out if the file was marked as failed and
+//! discarded otherwise, so only failures are shown in full.
+
+use chrono::Utc;
+use std::fmt::{self, Write as _};
+use std::io::Write as _;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::layer::{Context, Layer};
+use tracing_subscriber::registry::LookupSpan;
+
+/// Name of the span that scopes one test file's logs
+pub const FILE_SPAN: &str = "test_file";
+
+/// Field of `FILE_SPAN` recording the file's key
+pub const KEY_FIELD: &str = "key";
+
+/// Field of `FILE_SPAN` set to `true` when the file failed
+pub const FAILED_FIELD: &str = "failed";
+
+/// Log lines buffered for one test file
+#[derive(Debug, Default)]
+struct FileLog {
+    /// The file's key, once it has been recorded
+    key: Option<String>,
+    
+    /// Formatted events logged while the file was tested
+    lines: Vec<String>,
+    
+    /// Whether the file was marked as failed
+    failed: bool,
+}
+
+impl Visit for FileLog {
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        if field.name() == FAILED_FIELD {
+            self.failed = value;
+        }
+    }
+    
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == KEY_FIELD {
+            self.key = Some(value.to_string());
+        }
+    }
+    
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == KEY_FIELD {
+            self.key = Some(format!("{:?}", value));
+        }
+    }
+}
+
+/// Formats an event's message followed by its other fields
+#[derive(Default)]
+struct EventText(String);
+
+impl Visit for EventText {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            let _ = write!(self.0, "{:?}", value);
+        } else {
+            let _ = write!(self.0, " {}={:?}", field.name(), value);
+        }
+    }
+}
+
+/// Layer that shows a file's debug logs only if the file failed
+/// 
+/// Events outside a `FILE_SPAN` are ignored; they are left to the regular
+/// log output.
+pub struct FailureLogLayer<W> {
+    make_writer: W,
+}
+
+impl<W> FailureLogLayer<W>
+where
+    W: for<'w> MakeWriter<'w> + 'static,
+{
+    /// Creates a layer that writes failed files' logs to `make_writer`
+    /// 
+    /// # Arguments
+    /// 
+    /// * `make_writer` - Destination for flushed logs, usually stderr
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let layer = FailureLogLayer::new(std::io::stderr);
+    /// ```
+    pub fn new(make_writer: W) -> Self {
+        Self { make_writer }
+    }
+}
+
+impl<S, W> Layer<S> for FailureLogLayer<W>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    W: for<'w> MakeWriter<'w> + 'static,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        if attrs.metadata().name() != FILE_SPAN {
+            return;
+        }
+        let Some(span) = ctx.span(id) else { return };
+        
+        let mut log = FileLog::default();
+        attrs.record(&mut log);
+        span.extensions_mut().insert(log);
+    }
+    
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else { return };
+        let mut extensions = span.extensions_mut();
+        if let Some(log) = extensions.get_mut::<FileLog>() {
+            values.record(log);
+        }
+    }
+    
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let Some(scope) = ctx.event_scope(event) else { return };
+        for span in scope {
+            let mut extensions = span.extensions_mut();
+            if let Some(log) = extensions.get_mut::<FileLog>() {
+                let mut text = EventText::default();
+                event.record(&mut text);
+                log.lines.push(format!(
+                    "{} {:>5} {}",
+                    Utc::now().format("%H:%M:%S%.3f"),
+                    event.metadata().level().as_str(),
+                    text.0,
+                ));
+                return;
+            }
+        }
+    }
+    
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(&id) else { return };
+        let Some(log) = span.extensions_mut().remove::<FileLog>() else { return };
+        if !log.failed {
+            return;
+        }
+        
+        let mut writer = self.make_writer.make_writer();
+        let _ = writeln!(
+            writer,
+            "───── Debug log for failed file {} ─────",
+            log.key.as_deref().unwrap_or("<unknown>")
+        );
+        for line in &log.lines {
+            let _ = writeln!(writer, "  {}", line);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+    use std::sync::{Arc, Mutex};
+    use tracing::{debug, debug_span, field};
+    use tracing_subscriber::layer::SubscriberExt;
+    
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+    
+    impl io::Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+        
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+    
+    #[test]
+    fn test_only_failed_files_are_flushed() {
+        let buffer = SharedBuffer::default();
+        let writer = buffer.clone();
+        let subscriber = tracing_subscriber::registry()
+            .with(FailureLogLayer::new(move || writer.clone()));
+        
+        tracing::subscriber::with_default(subscriber, || {
+            let passed = debug_span!(FILE_SPAN, key = "passed-key", failed = field::Empty);
+            passed.in_scope(|| debug!("read attempt 1 succeeded"));
+            drop(passed);
+            
+            let failed = debug_span!(FILE_SPAN, key = field::Empty, failed = field::Empty);
+            failed.record(KEY_FIELD, "failed-key");
+            failed.in_scope(|| debug!(attempt = 3, "read returned 404"));
+            failed.record(FAILED_FIELD, true);
+            drop(failed);
+            
+            debug!("outside any file");
+        });
+        
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert!(output.contains("Debug log for failed file failed-key"));
+        assert!(output.contains("DEBUG read returned 404 attempt=3"));
+        assert!(!output.contains("passed-key"));
+        assert!(!output.contains("succeeded"));
+        assert!(!output.contains("outside any file"));
+    }
+}
@@ -0,0 +1,190 @@
+//! InfluxDB line protocol export of consistency test results
+//! 
+//! Each run is summarized as a single line-protocol point in the
+//! `s3_consistency` measurement, tagged with the endpoint, bucket and any run
+//! labels, with the run's statistics as fields and timestamped at the end of
+//! the run. The point is either posted to an InfluxDB write URL or appended
+//! to a file for another agent to ship.
+//! 
+//! # Example Point
+//! 
+//! ```text
+//! s3_consistency,bucket=my-bucket,endpoint=https://s3.amazonaws.com success_rate=95,successful_tests=19i,failed_tests=1i,p95_ms=1987i 1737642622000000000
+//! ```
+
+use anyhow::{Context, Result, anyhow};
+use std::io::Write;
+use std::path::Path;
+use tracing::info;
+
+use crate::types::ConsistencyReport;
+
+/// Measurement name used for every exported point
+pub const MEASUREMENT: &str = "s3_consistency";
+
+/// Formats a report as one InfluxDB line-protocol point
+/// 
+/// Timing fields are omitted when the statistic is unavailable, e.g. when
+/// no test succeeded or the sample was too small for a percentile.
+/// 
+/// # Arguments
+/// 
+/// * `report` - The completed consistency report
+/// 
+/// # Returns
+/// 
+/// The point, without a trailing newline
+pub fn format_line_protocol(report: &ConsistencyReport) -> String {
+    let stats = &report.statistics;
+    
+    // Tags must be sorted by key for best write performance
+    let mut tags = vec![
+        ("bucket".to_string(), report.config.bucket.clone()),
+        ("endpoint".to_string(), report.config.endpoint.clone()),
+    ];
+    tags.extend(report.labels.iter()
+        .filter(|(key, _)| key.as_str() != "bucket" && key.as_str() != "endpoint")
+        .map(|(key, value)| (key.clone(), value.clone())));
+    tags.sort();
+    
+    let mut fields = vec![
+        format!("success_rate={}", stats.success_rate),
+        format!("successful_tests={}i", stats.successful_tests),
+        format!("failed_tests={}i", stats.failed_tests),
+        format!("total_duration_ms={}i", report.total_duration_ms),
+        format!("total_requests={}i", report.total_requests),
+    ];
+    let timings = [
+        ("min_ms", stats.min_propagation_time_ms),
+        ("max_ms", stats.max_propagation_time_ms),
+        ("median_ms", stats.median_propagation_time_ms),
+        ("p95_ms", stats.percentile_95_ms),
+        ("p99_ms", stats.percentile_99_ms),
+    ];
+    for (name, value) in timings {
+        if let Some(value) = value {
+            fields.push(format!("{}={}i", name, value));
+        }
+    }
+    if let Some(avg) = stats.avg_propagation_time_ms {
+        fields.push(format!("avg_ms={}", avg));
+    }
+    
+    let tag_set: String = tags.iter()
+        .filter(|(_, value)| !value.is_empty())
+        .map(|(key, value)| format!(",{}={}", escape_tag(key), escape_tag(value)))
+        .collect();
+    let timestamp_ns = report.test_end_time.timestamp_nanos_opt().unwrap_or_default();
+    
+    format!("{}{} {} {}", MEASUREMENT, tag_set, fields.join(","), timestamp_ns)
+}
+
+/// Exports a report's point to InfluxDB or a file
+/// 
+/// # Arguments
+/// 
+/// * `report` - The completed consistency report
+/// * `target` - An `http(s)://` write URL (including database or bucket
+///   parameters), or a file path the point is appended to
+/// * `token` - API token sent as `Authorization: Token <token>` when posting
+/// 
+/// # Returns
+/// 
+/// - `Ok(())` if the point was written
+/// - `Err(anyhow::Error)` if the request failed or was rejected, or the file
+///   could not be written
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// export_influx(&report, "http://localhost:8086/api/v2/write?org=ops&bucket=s3", Some(&token)).await?;
+/// export_influx(&report, "metrics.lp", None).await?;
+/// ```
+pub async fn export_influx(report: &ConsistencyReport, target: &str, token: Option<&str>) -> Result<()> {
+    let line = format_line_protocol(report);
+    
+    if target.starts_with("http://") || target.starts_with("https://") {
+        let mut request = reqwest::Client::new().post(target).body(line);
+        if let Some(token) = token {
+            request = request.header("authorization", format!("Token {}", token));
+        }
+        
+        let response = request.send().await
+            .with_context(|| format!("Failed to send results to InfluxDB at {}", target))?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(anyhow!("InfluxDB rejected the results with HTTP {}: {}", status.as_u16(), body.trim()));
+        }
+        
+        info!("📤 Results sent to InfluxDB: {}", target);
+    } else {
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(Path::new(target))
+            .with_context(|| format!("Failed to open InfluxDB output file: {}", target))?;
+        writeln!(file, "{}", line)
+            .with_context(|| format!("Failed to write InfluxDB output file: {}", target))?;
+        
+        info!("📤 Line protocol appended to: {}", target);
+    }
+    
+    Ok(())
+}
+
+/// Escapes a tag key or value for line protocol
+fn escape_tag(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(',', "\\,")
+        .replace('=', "\\=")
+        .replace(' ', "\\ ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::S3Config;
+    use crate::statistics::calculate_statistics;
+    use crate::types::{Args, TestParameters, TestResult};
+    use chrono::{Duration, TimeZone, Utc};
+    use clap::Parser;
+    
+    fn create_test_report() -> ConsistencyReport {
+        let start = Utc.with_ymd_and_hms(2025, 1, 23, 14, 30, 0).unwrap();
+        let results = vec![
+            TestResult::success("a".to_string(), start, start + Duration::milliseconds(120), 2),
+            TestResult::failure("b".to_string(), start, "Upload failed".to_string()),
+        ];
+        let statistics = calculate_statistics(&results);
+        let config = S3Config {
+            endpoint: "http://localhost:9000".to_string(),
+            bucket: "test-bucket".to_string(),
+            ..Default::default()
+        };
+        let parameters = TestParameters::from(&Args::parse_from(["s3-consistency-test", "--config", "config.toml"]));
+        
+        ConsistencyReport::new(start, start + Duration::seconds(1), config, parameters, results, statistics)
+    }
+    
+    #[test]
+    fn test_format_line_protocol() {
+        let mut report = create_test_report();
+        report.labels.insert("env".to_string(), "staging eu".to_string());
+        
+        let line = format_line_protocol(&report);
+        assert!(line.starts_with(
+            "s3_consistency,bucket=test-bucket,endpoint=http://localhost:9000,env=staging\\ eu "
+        ));
+        assert!(line.contains("success_rate=50,"));
+        assert!(line.contains("successful_tests=1i"));
+        assert!(line.contains("min_ms=120i"));
+        assert!(!line.contains("p95_ms"));
+        assert!(line.ends_with(" 1737642601000000000"));
+    }
+    
+    #[test]
+    fn test_escape_tag() {
+        assert_eq!(escape_tag("a b,c=d"), "a\\ b\\,c\\=d");
+    }
+}
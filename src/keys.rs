@@ -0,0 +1,290 @@
+//! Test object key generation for consistency testing
+//! 
+//! By default every test file gets a random UUID key under a shared prefix.
+//! Shorter keys, a random 8-digit hex suffix or a per-run counter, can be
+//! chosen with a key style to keep listings, logs and tables readable.
+//! Random keys can be distributed across partitions differently from the keys
+//! a real application writes, so a key pattern such as `users/{seq}/avatar.jpg`
+//! or `{date}/{uuid}` can be given to reproduce an application's key layout.
+//! 
+//! Patterned keys are still placed under the shared test prefix (followed by a
+//! per-run identifier), so cleanup verification and listing sweeps find them
+//! and sequence numbers never collide with keys left over from earlier runs.
+//! The configuration's `prefix` setting is placed in front of the shared
+//! prefix to keep test files in one area of a bucket.
+
+use anyhow::{Result, anyhow};
+use chrono::Utc;
+use clap::ValueEnum;
+use serde::Serialize;
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use uuid::Uuid;
+
+/// Key prefix shared by every uploaded test file
+pub const TEST_KEY_PREFIX: &str = "consistency-test-";
+
+/// Placeholders that may appear in a key pattern
+const PLACEHOLDERS: [&str; 3] = ["seq", "uuid", "date"];
+
+/// Number of hex digits in the random suffix of short keys
+const SHORT_KEY_LEN: usize = 8;
+
+/// Form of generated keys when no key pattern is given
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum KeyStyle {
+    /// `consistency-test-<uuid>`
+    #[default]
+    Uuid,
+    
+    /// `consistency-test-<8 hex digits>`, unique within the run
+    Short,
+    
+    /// `consistency-test-<run id>-<n>`, counting up from 1
+    Counter,
+}
+
+/// Template for test object keys
+/// 
+/// Supported placeholders:
+/// - `{seq}` - A sequence number, starting at 1 and unique within the run
+/// - `{uuid}` - A random UUID
+/// - `{date}` - The current UTC date as `YYYY-MM-DD`
+/// 
+/// A pattern must contain `{seq}` or `{uuid}` so every key is unique.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// let pattern = KeyPattern::parse("users/{seq}/avatar.jpg")?;
+/// assert_eq!(pattern.render(7), "users/7/avatar.jpg");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyPattern {
+    /// The template as given on the command line
+    template: String,
+}
+
+impl KeyPattern {
+    /// Parses and validates a key pattern
+    /// 
+    /// # Arguments
+    /// 
+    /// * `template` - The pattern, e.g. `{date}/{uuid}`
+    /// 
+    /// # Returns
+    /// 
+    /// - `Ok(KeyPattern)` if the pattern is valid
+    /// - `Err(anyhow::Error)` if it is empty, starts with `/`, has an unknown or
+    ///   unterminated placeholder, or contains neither `{seq}` nor `{uuid}`
+    pub fn parse(template: &str) -> Result<Self> {
+        if template.is_empty() {
+            return Err(anyhow!("Key pattern cannot be empty"));
+        }
+        
+        if template.starts_with('/') {
+            return Err(anyhow!("Key pattern '{}' cannot start with '/'", template));
+        }
+        
+        let mut rest = template;
+        while let Some(start) = rest.find('{') {
+            let end = rest[start..].find('}')
+                .ok_or_else(|| anyhow!("Key pattern '{}' has an unterminated placeholder", template))?;
+            let name = &rest[start + 1..start + end];
+            if !PLACEHOLDERS.contains(&name) {
+                return Err(anyhow!(
+                    "Key pattern '{}' has unknown placeholder '{{{}}}' (supported: {{seq}}, {{uuid}}, {{date}})",
+                    template, name
+                ));
+            }
+            rest = &rest[start + end + 1..];
+        }
+        
+        if !template.contains("{seq}") && !template.contains("{uuid}") {
+            return Err(anyhow!(
+                "Key pattern '{}' must contain {{seq}} or {{uuid}} so every key is unique",
+                template
+            ));
+        }
+        
+        Ok(Self { template: template.to_string() })
+    }
+    
+    /// Renders the pattern into a key
+    /// 
+    /// # Arguments
+    /// 
+    /// * `seq` - The sequence number substituted for `{seq}`
+    /// 
+    /// # Returns
+    /// 
+    /// The key with every placeholder replaced
+    pub fn render(&self, seq: u64) -> String {
+        let mut key = self.template.replace("{seq}", &seq.to_string());
+        if key.contains("{date}") {
+            key = key.replace("{date}", &Utc::now().format("%Y-%m-%d").to_string());
+        }
+        while key.contains("{uuid}") {
+            key = key.replacen("{uuid}", &Uuid::new_v4().to_string(), 1);
+        }
+        key
+    }
+}
+
+impl fmt::Display for KeyPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.template)
+    }
+}
+
+/// Generates the keys for a run's test files
+/// 
+/// Designed to be shared across async tasks; sequence numbers are
+/// allocated atomically.
+#[derive(Debug)]
+pub struct KeyGenerator {
+    /// Prefix every generated key starts with
+    prefix: String,
+    
+    /// Identifier for this run, separating patterned keys from other runs
+    run_id: String,
+    
+    /// Last sequence number handed out
+    seq: AtomicU64,
+    
+    /// Suffixes already handed out as short keys
+    /// 
+    /// Eight hex digits are too few to rule out collisions by chance alone,
+    /// so a suffix is never reused within the run.
+    short_suffixes: Mutex<HashSet<String>>,
+}
+
+impl Default for KeyGenerator {
+    /// Creates a key generator for keys under `TEST_KEY_PREFIX`
+    fn default() -> Self {
+        Self::new(TEST_KEY_PREFIX)
+    }
+}
+
+impl KeyGenerator {
+    /// Creates a key generator with a fresh run identifier
+    /// 
+    /// # Arguments
+    /// 
+    /// * `prefix` - Prefix every key starts with, ending in `TEST_KEY_PREFIX`
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let keys = KeyGenerator::new(&config.key_prefix());
+    /// ```
+    pub fn new(prefix: &str) -> Self {
+        let run_id = Uuid::new_v4().simple().to_string()[..8].to_string();
+        Self {
+            prefix: prefix.to_string(),
+            run_id,
+            seq: AtomicU64::new(0),
+            short_suffixes: Mutex::new(HashSet::new()),
+        }
+    }
+    
+    /// Generates the next test file key
+    /// 
+    /// With a pattern the rendered pattern is placed under
+    /// `<prefix><run id>/`. Without one, `style` decides the form of the key.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `pattern` - The key pattern, if one was given
+    /// * `style` - The form of the key when no pattern was given
+    /// 
+    /// # Returns
+    /// 
+    /// A key under the generator's prefix
+    pub fn next_key(&self, pattern: Option<&KeyPattern>, style: KeyStyle) -> String {
+        match (pattern, style) {
+            (Some(pattern), _) => {
+                format!("{}{}/{}", self.prefix, self.run_id, pattern.render(self.next_seq()))
+            }
+            (None, KeyStyle::Uuid) => format!("{}{}", self.prefix, Uuid::new_v4()),
+            (None, KeyStyle::Short) => format!("{}{}", self.prefix, self.next_short_suffix()),
+            (None, KeyStyle::Counter) => format!("{}{}-{}", self.prefix, self.run_id, self.next_seq()),
+        }
+    }
+    
+    /// Allocates the next sequence number, starting at 1
+    fn next_seq(&self) -> u64 {
+        self.seq.fetch_add(1, Ordering::Relaxed) + 1
+    }
+    
+    /// Picks a random hex suffix that has not been used in this run
+    fn next_short_suffix(&self) -> String {
+        let mut issued = self.short_suffixes.lock().unwrap();
+        loop {
+            let suffix = Uuid::new_v4().simple().to_string()[..SHORT_KEY_LEN].to_string();
+            if issued.insert(suffix.clone()) {
+                return suffix;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_key_pattern_validation() {
+        assert!(KeyPattern::parse("users/{seq}/avatar.jpg").is_ok());
+        assert!(KeyPattern::parse("{date}/{uuid}").is_ok());
+        
+        assert!(KeyPattern::parse("").is_err());
+        assert!(KeyPattern::parse("/users/{seq}").is_err());
+        assert!(KeyPattern::parse("users/{id}").is_err());
+        assert!(KeyPattern::parse("users/{seq").is_err());
+        assert!(KeyPattern::parse("{date}/avatar.jpg").is_err());
+    }
+    
+    #[test]
+    fn test_key_generator() {
+        let keys = KeyGenerator::default();
+        let pattern = KeyPattern::parse("users/{seq}/avatar.jpg").unwrap();
+        
+        let first = keys.next_key(Some(&pattern), KeyStyle::Uuid);
+        let second = keys.next_key(Some(&pattern), KeyStyle::Uuid);
+        assert!(first.starts_with(TEST_KEY_PREFIX));
+        assert!(first.ends_with("/users/1/avatar.jpg"));
+        assert!(second.ends_with("/users/2/avatar.jpg"));
+        
+        let random = keys.next_key(None, KeyStyle::Uuid);
+        assert!(random.starts_with(TEST_KEY_PREFIX));
+        assert!(!random.contains('/'));
+    }
+    
+    #[test]
+    fn test_short_and_counter_keys() {
+        let keys = KeyGenerator::default();
+        
+        let short: HashSet<String> = (0..1000).map(|_| keys.next_key(None, KeyStyle::Short)).collect();
+        assert_eq!(short.len(), 1000);
+        assert!(short.iter().all(|key| key.len() == TEST_KEY_PREFIX.len() + SHORT_KEY_LEN));
+        
+        let first = keys.next_key(None, KeyStyle::Counter);
+        let second = keys.next_key(None, KeyStyle::Counter);
+        assert_eq!(first, format!("{}{}-1", TEST_KEY_PREFIX, keys.run_id));
+        assert_eq!(second, format!("{}{}-2", TEST_KEY_PREFIX, keys.run_id));
+    }
+    
+    #[test]
+    fn test_keys_under_configured_prefix() {
+        let prefix = format!("scratch/{}", TEST_KEY_PREFIX);
+        let keys = KeyGenerator::new(&prefix);
+        let pattern = KeyPattern::parse("users/{seq}").unwrap();
+        
+        assert!(keys.next_key(None, KeyStyle::Uuid).starts_with(&prefix));
+        assert_eq!(keys.next_key(Some(&pattern), KeyStyle::Uuid), format!("{}{}/users/1", prefix, keys.run_id));
+    }
+}
@@ -12,6 +12,7 @@
 //! - Robust cleanup with retry logic and emergency cleanup on interruption
 //! - Detailed JSON reporting for further analysis
 //! - Real-time progress monitoring with structured logging
+//! - Optional live terminal dashboard (`--tui`)
 //! 
 //! # Usage
 //! 
@@ -41,21 +42,65 @@
 //! path_style = false
 //! ```
 
+mod availability;
+mod batch_delete;
+mod checksum;
 mod cleanup;
 mod config;
+mod diagnostics;
+mod durations;
+mod error;
+mod failure_log;
+mod influx;
+mod keys;
+#[cfg(any(test, feature = "self-test"))]
+mod mock;
+mod requests;
+mod results;
+mod safeguard;
+mod sizes;
+mod sigv4;
+mod sqlite;
 mod statistics;
+mod status_pattern;
+mod storage;
+mod sts;
 mod tester;
+mod throttle;
+mod tui;
 mod types;
+mod version;
+mod webhook;
+mod workload;
 
 use anyhow::Result;
-use clap::Parser;
-use tracing::{error, info};
+use clap::{ArgMatches, CommandFactory, FromArgMatches};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info, warn};
+use tracing_subscriber::Layer;
+use tracing_subscriber::filter::{LevelFilter, Targets};
+#[cfg(not(feature = "tokio-console"))]
+use tracing_subscriber::layer::Identity;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
 
-use crate::cleanup::setup_cleanup_handler;
-use crate::config::load_config;
-use crate::statistics::print_summary;
+use crate::cleanup::{print_cleanup_denied_warning, setup_cleanup_handler, setup_scoped_cleanup_handler};
+use crate::config::{S3Config, load_config, load_configs, parse_config};
+use crate::durations::{load_durations, results_from_durations};
+use crate::failure_log::FailureLogLayer;
+use crate::influx::export_influx;
+use crate::safeguard::{confirm_bucket, confirmation_reason};
+use crate::sqlite::{load_durations_by_hour, save_sqlite_report};
+use crate::statistics::{calculate_filtered_statistics, calculate_hourly_statistics, print_benchmark_matrix, print_breakpoint_summary, print_statistics_summary, print_summary, print_time_of_day_report, rank_benchmark_entries};
 use crate::tester::S3ConsistencyTester;
-use crate::types::Args;
+use crate::tui::{Dashboard, DashboardHandle};
+use crate::types::{Args, BenchmarkEntry, BenchmarkReport, Command, DisplayOptions, RunStatus, TestParameters};
+use crate::version::verbose_version;
+use crate::webhook::send_webhook;
+use crate::workload::WorkloadProfile;
 
 /// Main application entry point
 /// 
@@ -65,7 +110,9 @@ use crate::types::Args;
 /// 3. Load S3 configuration
 /// 4. Set up cleanup handlers for graceful shutdown
 /// 5. Run consistency tests
-/// 6. Generate and save detailed report
+/// 6. Generate and save detailed report (and optionally append it to SQLite)
+/// 7. Warn about denied deletes and optionally verify that no test files were
+///    left in the bucket
 /// 
 /// # Error Handling
 /// 
@@ -75,67 +122,655 @@ use crate::types::Args;
 /// 
 /// # Signal Handling
 /// 
-/// The application sets up a Ctrl+C handler to ensure cleanup of test files
-/// even if the program is interrupted. This prevents leaving orphaned files
-/// in the S3 bucket.
+/// The application sets up a Ctrl+C handler. The first Ctrl+C finishes the
+/// files in flight, skips the rest and reports what completed; a second one
+/// runs emergency cleanup and exits. Either way test files are cleaned up,
+/// which prevents leaving orphaned files in the S3 bucket.
 /// 
 /// # Returns
 /// 
 /// - `Ok(())` if all tests complete successfully and report is saved
 /// - `Err(anyhow::Error)` if any critical error occurs during execution
-
-#[tokio::main]
-async fn main() -> Result<()> {
+fn main() -> Result<()> {
     // Parse command-line arguments
-    let args = Args::parse();
+    let matches = Args::command().get_matches();
+    let args = Args::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    
+    // Print build details without starting a run
+    if args.version_verbose {
+        print!("{}", verbose_version());
+        return Ok(());
+    }
     
+    // Build the runtime by hand so its worker thread count can be pinned
+    let runtime = build_runtime(args.worker_threads)?;
+    runtime.block_on(run(matches, args))
+}
+
+/// Builds the multi-threaded tokio runtime the tool runs on
+/// 
+/// # Arguments
+/// 
+/// * `worker_threads` - Number of worker threads, or `None` for one per CPU core
+/// 
+/// # Returns
+/// 
+/// - `Ok(Runtime)` with I/O and timers enabled
+/// - `Err(anyhow::Error)` if the runtime cannot be created
+fn build_runtime(worker_threads: Option<u64>) -> Result<tokio::runtime::Runtime> {
+    let mut builder = tokio::runtime::Builder::new_multi_thread();
+    if let Some(threads) = worker_threads {
+        builder.worker_threads(threads as usize);
+    }
+    builder.enable_all()
+        .build()
+        .map_err(|e| anyhow::anyhow!("Failed to start the async runtime: {}", e))
+}
+
+/// Runs the tool for the parsed command line
+/// 
+/// # Arguments
+/// 
+/// * `matches` - The raw matches, used to tell flags given on the command line from defaults
+/// * `args` - The parsed command-line arguments
+/// 
+/// # Returns
+/// 
+/// - `Ok(())` if all tests complete successfully and report is saved
+/// - `Err(anyhow::Error)` if any critical error occurs during execution
+async fn run(matches: ArgMatches, mut args: Args) -> Result<()> {
     // Initialize structured logging
-    initialize_logging(args.verbose);
+    initialize_logging(&args);
+    
+    // Fill in settings from the workload profile that were not given as flags
+    if let Some(path) = &args.workload {
+        WorkloadProfile::load(path)?.apply(&mut args, &matches)?;
+        info!("📋 Loaded workload profile: {}", path.display());
+    }
+    
+    if let (Some(min), Some(max)) = (args.stats_min_ms, args.stats_max_ms)
+        && min > max
+    {
+        return Err(anyhow::anyhow!("--stats-min-ms ({}) cannot be greater than --stats-max-ms ({})", min, max));
+    }
+    
+    // Analyze imported durations without touching S3
+    if let Some(Command::Stats { durations }) = &args.command {
+        return run_stats(durations, &args);
+    }
+    
+    // Compare several configurations instead of testing one
+    if let Some(Command::Benchmark { configs }) = &args.command {
+        return run_benchmark(configs, &args).await;
+    }
+    
+    // Validate the tool's own timing logic against the in-memory mock
+    #[cfg(feature = "self-test")]
+    if args.self_test {
+        return run_self_test(&args).await;
+    }
     
     info!("🚀 S3 Consistency Test Tool starting...");
+    if let Some(threads) = args.worker_threads {
+        info!("🧵 Worker threads: {}", threads);
+    }
     
     // Load and validate S3 configuration
-    let config = load_config(&args.config)
+    let config = match &args.config_inline {
+        Some(inline) => parse_config(inline, "--config-inline"),
+        None if !args.config.is_empty() => load_configs(&args.config),
+        None => Err(anyhow::anyhow!("Either --config or --config-inline is required")),
+    };
+    let config = config
         .map_err(|e| {
             error!("Failed to load configuration: {}", e);
             e
         })?;
     
+    let mut tester = prepare_tester(config, &args).await?;
+    
+    // Set up cleanup handler for graceful shutdown on interruption
+    let interrupt = setup_cleanup_handler(tester.cleanup_manager(), tester.stop_token());
+    
+    info!("🛡️  Cleanup handler configured for graceful shutdown");
+    
+    // Ramp concurrency instead of running a single test suite if requested
+    if args.find_breakpoint {
+        let report = tester.find_breakpoint(&args).await
+            .map_err(|e| {
+                error!("Breakpoint search failed: {}", e);
+                e
+            })?;
+        
+        print_breakpoint_summary(&report);
+        
+        let report_file = format!(
+            "breakpoint-report-{}.json",
+            report.test_start_time.format("%Y%m%d-%H%M%S")
+        );
+        save_json_report(&report, &report_file)?;
+        
+        check_cleanup(&tester, &args).await?;
+        
+        info!("📊 Breakpoint search completed successfully!");
+        return Ok(());
+    }
+    
+    // Show the live dashboard while the test runs if requested
+    let dashboard = if args.tui {
+        let dashboard = Arc::new(Dashboard::new(args.test_count));
+        tester = tester.with_dashboard(dashboard.clone());
+        Some(DashboardHandle::start(dashboard, interrupt)?)
+    } else {
+        None
+    };
+    
+    // Run the consistency test suite
+    let report = tester.run_consistency_test(&args).await;
+    
+    // Give the terminal back before printing the summary
+    if let Some(dashboard) = dashboard {
+        dashboard.stop();
+    }
+    
+    let report = report
+        .map_err(|e| {
+            error!("Consistency test failed: {}", e);
+            e
+        })?;
+    
+    // Display comprehensive summary to the user
+    print_summary(&report, &DisplayOptions::from(&args));
+    
+    // Save detailed JSON report for further analysis, limiting the per-file
+    // results to failures if requested; the exports below get every result
+    let report_file = generate_report_filename(&report);
+    if args.failures_only {
+        let mut failures = report.clone();
+        failures.retain_failures();
+        save_json_report(&failures, &report_file)?;
+    } else {
+        save_json_report(&report, &report_file)?;
+    }
+    
+    // Append to the historical SQLite database if requested
+    if let Some(sqlite_path) = &args.sqlite {
+        save_sqlite_report(&report, sqlite_path)?;
+        
+        // Summarize the whole history by hour of day if requested
+        if args.time_of_day_report {
+            let hourly = calculate_hourly_statistics(&load_durations_by_hour(sqlite_path)?);
+            print_time_of_day_report(&hourly, &DisplayOptions::from(&args));
+        }
+    }
+    
+    // Export the statistics to the metrics pipeline if requested
+    if let Some(target) = &args.influx {
+        export_influx(&report, target, args.influx_token.as_deref()).await?;
+    }
+    
+    // Notify chat or automation that the run finished
+    if let Some(url) = &args.webhook {
+        send_webhook(&report, url, args.webhook_retries).await?;
+    }
+    
+    // Fail the run if cleanup left test files behind
+    let cleanup_check = check_cleanup(&tester, &args).await;
+    
+    // Write the machine-readable status, including the exit code, before exiting
+    if let Some(status_path) = &args.status_file {
+        let exit_code = if cleanup_check.is_ok() { 0 } else { 1 };
+        save_json_report(&RunStatus::new(&report.statistics, exit_code), status_path)?;
+    }
+    
+    cleanup_check?;
+    
+    info!("📊 Test completed successfully!");
+    
+    Ok(())
+}
+
+/// Creates a tester for a configuration and prepares it for a run
+/// 
+/// Applies command-line overrides to the configuration, rejects options the
+/// configuration cannot support, asks for confirmation before testing
+/// protected buckets, and enables the run-level features selected by `args`.
+/// 
+/// # Arguments
+/// 
+/// * `config` - The loaded S3 configuration
+/// * `args` - Command-line arguments
+/// 
+/// # Returns
+/// 
+/// - `Ok(S3ConsistencyTester)` ready to run
+/// - `Err(anyhow::Error)` if the configuration is unusable or the endpoint unreachable
+async fn prepare_tester(mut config: S3Config, args: &Args) -> Result<S3ConsistencyTester> {
+    // Command-line overrides take precedence over the configuration file
+    if args.acl.is_some() || args.bucket.is_some() || args.prefix.is_some() {
+        if let Some(acl) = &args.acl {
+            config.acl = Some(acl.clone());
+        }
+        if let Some(bucket) = &args.bucket {
+            if config.buckets.is_some() {
+                return Err(anyhow::anyhow!("--bucket cannot override a configuration that spreads files across several buckets"));
+            }
+            config.bucket = bucket.clone();
+        }
+        if let Some(prefix) = &args.prefix {
+            config.prefix = Some(prefix.clone());
+        }
+        config.validate()
+            .map_err(|e| anyhow::anyhow!("Invalid configuration after applying command-line overrides: {}", e))?;
+    }
+    
     info!("📋 Configuration loaded successfully");
     info!("🔗 Endpoint: {}", config.endpoint);
     info!("🪣 Bucket: {}", config.bucket);
+    if let Some(prefix) = &config.prefix {
+        info!("📁 Key prefix: {}", prefix);
+    }
+    let buckets = config.bucket_pool();
+    if buckets.len() > 1 {
+        info!("🧩 Sharded across buckets: {}", buckets.join(", "));
+        if args.list_completeness || args.test_delete_list_consistency {
+            return Err(anyhow::anyhow!(
+                "--list-completeness and --test-delete-list-consistency cannot be combined with several buckets"
+            ));
+        }
+    }
+    match &config.replication_target {
+        Some(target) => info!("🪞 Replication target: {} ({})", target.bucket, target.endpoint),
+        None if args.test_replication => {
+            return Err(anyhow::anyhow!("--test-replication requires a [replication_target] table in the configuration"));
+        }
+        None => {}
+    }
+    if let Some(sse) = &config.sse {
+        info!("🔐 Server-side encryption: {}", sse);
+        if args.multipart || args.max_file_size() > args.stream_threshold {
+            return Err(anyhow::anyhow!("sse cannot be combined with streamed or multipart uploads"));
+        }
+    }
+    
+    // Make sure every protected bucket is really meant to be tested
+    for bucket in &buckets {
+        let reason = confirmation_reason(
+            bucket,
+            &args.allow_bucket,
+            &args.deny_pattern,
+            config.require_confirmation.unwrap_or(false),
+        );
+        if let Some(reason) = reason {
+            if args.yes {
+                warn!("⚠️  Testing protected bucket because --yes was given: {}", reason);
+            } else {
+                confirm_bucket(bucket, &reason)?;
+            }
+        }
+    }
     
     // Create the S3 consistency tester
-    let tester = S3ConsistencyTester::new(config).await
+    let mut tester = S3ConsistencyTester::new(config).await
         .map_err(|e| {
             error!("Failed to initialize S3 tester: {}", e);
             e
         })?;
     
-    // Set up cleanup handler for graceful shutdown on interruption
-    setup_cleanup_handler(tester.cleanup_manager());
+    // Wait for a server that may still be starting up, e.g. in CI
+    if let Some(connect_timeout) = args.connect_timeout {
+        info!("🔌 Checking that the endpoints are reachable...");
+        tester.connect_with_retry(Duration::from_secs(connect_timeout), args.connect_retries).await
+            .map_err(|e| {
+                error!("Endpoint did not become reachable: {}", e);
+                e
+            })?;
+    }
     
-    info!("🛡️  Cleanup handler configured for graceful shutdown");
+    // Fall back to unencrypted uploads if the backend rejects the configured encryption
+    tester.verify_sse_support().await
+        .map_err(|e| {
+            error!("Server-side encryption check failed: {}", e);
+            e
+        })?;
     
-    // Run the consistency test suite
-    let report = tester.run_consistency_test(&args).await
+    // Ask for stored checksums with every read so they can be verified
+    if args.checksum.is_some() {
+        tester.enable_checksum_mode()?;
+    }
+    
+    // Pace all uploads against the bandwidth limit
+    if let Some(bytes_per_second) = args.max_upload_bps {
+        tester.limit_upload_bandwidth(bytes_per_second);
+    }
+    
+    Ok(tester)
+}
+
+/// Calculates and reports statistics for imported durations
+/// 
+/// Implements the `stats` subcommand: prints the summary and saves the full
+/// statistics as JSON, without loading a configuration or connecting to S3.
+/// 
+/// # Arguments
+/// 
+/// * `path` - File of durations in milliseconds, or `-` for stdin
+/// * `args` - Command-line arguments with the display settings and the
+///   `--stats-min-ms`/`--stats-max-ms` range
+/// 
+/// # Returns
+/// 
+/// - `Ok(())` if the statistics were printed and saved
+/// - `Err(anyhow::Error)` if the durations could not be read or the report saved
+fn run_stats(path: &Path, args: &Args) -> Result<()> {
+    let durations = load_durations(path)
         .map_err(|e| {
-            error!("Consistency test failed: {}", e);
+            error!("Failed to load durations: {}", e);
             e
         })?;
     
-    // Display comprehensive summary to the user
-    print_summary(&report);
+    info!("📥 Loaded {} durations from {}", durations.len(), path.display());
     
-    // Save detailed JSON report for further analysis
-    let report_file = generate_report_filename(&report);
+    let statistics = calculate_filtered_statistics(
+        &results_from_durations(&durations),
+        args.stats_min_ms,
+        args.stats_max_ms,
+    );
+    print_statistics_summary(&statistics, &path.display().to_string(), &DisplayOptions::from(args));
+    
+    let report_file = format!("statistics-report-{}.json", chrono::Utc::now().format("%Y%m%d-%H%M%S"));
+    save_json_report(&statistics, &report_file)?;
+    
+    Ok(())
+}
+
+/// Runs the same workload against several configurations and ranks them
+/// 
+/// Implements the `benchmark` subcommand: each configuration is tested in
+/// turn with the test settings given before the subcommand. A configuration
+/// that cannot be tested is recorded as failed and the benchmark moves on to
+/// the next one. The results are printed as one matrix ranked by success
+/// rate and p95 propagation time, and saved as JSON.
+/// 
+/// # Arguments
+/// 
+/// * `paths` - Configuration files, or directories of `.toml` files, to compare
+/// * `args` - Command-line arguments with the workload settings
+/// 
+/// # Returns
+/// 
+/// - `Ok(())` if the benchmark completed and its report was saved
+/// - `Err(anyhow::Error)` if an option cannot be used with a benchmark, a
+///   configuration is invalid, every run failed or cleanup failed
+async fn run_benchmark(paths: &[PathBuf], args: &Args) -> Result<()> {
+    if !args.config.is_empty() || args.config_inline.is_some() {
+        anyhow::bail!("Pass the configurations to compare with `benchmark --config`, not before the subcommand");
+    }
+    if args.find_breakpoint || args.tui {
+        anyhow::bail!("benchmark cannot be combined with --find-breakpoint or --tui");
+    }
+    if args.bucket.is_some() {
+        anyhow::bail!("benchmark cannot be combined with --bucket; each configuration names its own bucket");
+    }
+    if args.sqlite.is_some() || args.influx.is_some() || args.webhook.is_some() || args.status_file.is_some() {
+        anyhow::bail!("benchmark does not support --sqlite, --influx, --webhook or --status-file");
+    }
+    
+    // Load every configuration up front so a typo fails before anything is uploaded
+    let config_paths = expand_config_paths(paths)?;
+    if config_paths.len() < 2 {
+        anyhow::bail!("benchmark needs at least two configurations, found {}", config_paths.len());
+    }
+    let configs = config_paths.iter()
+        .map(|path| load_config(path))
+        .collect::<Result<Vec<_>>>()
+        .map_err(|e| {
+            error!("Failed to load configuration: {}", e);
+            e
+        })?;
+    
+    let total = configs.len();
+    info!("🏁 Benchmarking {} configurations", total);
+    let test_start_time = chrono::Utc::now();
+    let mut entries = Vec::with_capacity(total);
+    let mut cleanup_check = Ok(());
+    
+    for (i, (config_path, config)) in config_paths.into_iter().zip(configs).enumerate() {
+        info!("➡️  Configuration {}/{}: {}", i + 1, total, config_path.display());
+        let mut entry = BenchmarkEntry {
+            config_path,
+            endpoint: config.endpoint.clone(),
+            bucket: config.bucket.clone(),
+            rank: None,
+            statistics: None,
+            error_details: None,
+        };
+        
+        let tester = match prepare_tester(config, args).await {
+            Ok(tester) => tester,
+            Err(e) => {
+                error!("Skipping {}: {}", entry.config_path.display(), e);
+                entry.error_details = Some(e.to_string());
+                entries.push(entry);
+                continue;
+            }
+        };
+        
+        // Only this run's handler may clean up and exit on a second Ctrl+C
+        let done = CancellationToken::new();
+        setup_scoped_cleanup_handler(tester.cleanup_manager(), tester.stop_token(), done.clone());
+        
+        match tester.run_consistency_test(args).await {
+            Ok(report) => {
+                info!("📊 Finished {}:\n{}", entry.config_path.display(), report.get_summary());
+                entry.statistics = Some(report.statistics);
+            }
+            Err(e) => {
+                error!("Consistency test against {} failed: {}", entry.config_path.display(), e);
+                entry.error_details = Some(e.to_string());
+            }
+        }
+        entries.push(entry);
+        
+        let check = check_cleanup(&tester, args).await;
+        if cleanup_check.is_ok() {
+            cleanup_check = check;
+        }
+        done.cancel();
+        
+        if tester.stop_token().is_cancelled() {
+            warn!("Benchmark interrupted, skipping the remaining configurations");
+            break;
+        }
+    }
+    
+    rank_benchmark_entries(&mut entries);
+    let report = BenchmarkReport {
+        test_start_time,
+        test_end_time: chrono::Utc::now(),
+        test_parameters: TestParameters::from(args),
+        entries,
+    };
+    
+    print_benchmark_matrix(&report, &DisplayOptions::from(args));
+    
+    let report_file = format!(
+        "benchmark-report-{}.json",
+        report.test_start_time.format("%Y%m%d-%H%M%S")
+    );
     save_json_report(&report, &report_file)?;
     
-    info!("📊 Test completed successfully!");
+    cleanup_check?;
+    
+    if report.entries.iter().all(|entry| entry.statistics.is_none()) {
+        anyhow::bail!("Every benchmark run failed");
+    }
+    
+    info!("📊 Benchmark completed successfully!");
+    Ok(())
+}
+
+/// Expands the configurations given to `benchmark` into configuration files
+/// 
+/// Directories are replaced by the `.toml` files they contain, sorted by name.
+/// 
+/// # Arguments
+/// 
+/// * `paths` - Configuration files and directories, in the order given
+/// 
+/// # Returns
+/// 
+/// - `Ok(Vec<PathBuf>)` with one configuration file per provider
+/// - `Err(anyhow::Error)` if a directory cannot be read
+fn expand_config_paths(paths: &[PathBuf]) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for path in paths {
+        if !path.is_dir() {
+            files.push(path.clone());
+            continue;
+        }
+        
+        let mut entries = std::fs::read_dir(path)
+            .and_then(|entries| entries.map(|entry| entry.map(|entry| entry.path())).collect::<std::io::Result<Vec<_>>>())
+            .map_err(|e| anyhow::anyhow!("Failed to read config directory {}: {}", path.display(), e))?;
+        entries.retain(|file| file.is_file() && file.extension().is_some_and(|ext| ext == "toml"));
+        entries.sort();
+        files.extend(entries);
+    }
+    Ok(files)
+}
+
+/// Runs the test pipeline against the in-memory mock
+/// 
+/// Implements `--self-test`: no configuration or credentials are loaded and
+/// nothing is sent over the network. The run fails if the measured median
+/// propagation time does not match the simulated delay, or if cleanup left
+/// objects in the mock.
+/// 
+/// # Arguments
+/// 
+/// * `args` - Command-line arguments, including the simulated delay and failure rate
+/// 
+/// # Returns
+/// 
+/// - `Ok(())` if the pipeline measured the simulated delay
+/// - `Err(anyhow::Error)` if an option needs real S3 or the check failed
+#[cfg(feature = "self-test")]
+async fn run_self_test(args: &Args) -> Result<()> {
+    use crate::config::S3Config;
+    use crate::mock::{MockStorage, check_propagation};
+    
+    if args.multipart || args.max_file_size() > args.stream_threshold {
+        anyhow::bail!("--self-test does not support streamed or multipart uploads");
+    }
+    if args.fresh_connection || args.diagnose_timing {
+        anyhow::bail!("--self-test does not support --fresh-connection or --diagnose-timing");
+    }
+    if args.auto_interval {
+        anyhow::bail!("--self-test does not support --auto-interval");
+    }
+    
+    let delay = Duration::from_millis(args.self_test_delay_ms);
+    info!("🧪 Self-test against in-memory mock ({}ms delay, {:.0}% failure rate)",
+          args.self_test_delay_ms, args.self_test_failure_rate * 100.0);
+    
+    // The bucket handle is never used for requests, but the tester needs one
+    let config = S3Config {
+        endpoint: "http://127.0.0.1:9".to_string(),
+        region: "us-east-1".to_string(),
+        bucket: "self-test".to_string(),
+        access_key: "self-test".to_string(),
+        secret_key: "self-test".to_string(),
+        path_style: Some(true),
+        ..Default::default()
+    };
+    let storage = Arc::new(MockStorage::new(delay, args.self_test_failure_rate));
+    let tester = S3ConsistencyTester::new(config).await?.with_storage(storage.clone());
+    
+    let report = tester.run_consistency_test(args).await?;
+    print_summary(&report, &DisplayOptions::from(args));
+    
+    check_propagation(&report.statistics, delay, Duration::from_millis(args.interval))?;
+    if storage.object_count() > 0 {
+        anyhow::bail!("Self-test cleanup left {} objects in the mock", storage.object_count());
+    }
+    
+    info!("✅ Self-test passed");
+    Ok(())
+}
+
+/// Runs the post-run cleanup checks that can fail the run
+/// 
+/// # Arguments
+/// 
+/// * `tester` - The tester whose cleanup should be checked
+/// * `args` - Command-line arguments selecting which checks fail the run
+/// 
+/// # Returns
+/// 
+/// - `Ok(())` if every enabled check passed
+/// - `Err(anyhow::Error)` from the first check that failed
+async fn check_cleanup(tester: &S3ConsistencyTester, args: &Args) -> Result<()> {
+    check_cleanup_denied(tester, args).await?;
+    
+    if args.verify_cleanup {
+        verify_cleanup(tester).await?;
+    }
+    
+    Ok(())
+}
+
+/// Warns loudly if the backend denied deleting any test files
+/// 
+/// # Arguments
+/// 
+/// * `tester` - The tester whose cleanup should be checked
+/// * `args` - Command-line arguments controlling whether this fails the run
+/// 
+/// # Returns
+/// 
+/// - `Ok(())` if no deletes were denied, or denied deletes should not fail the run
+/// - `Err(anyhow::Error)` if deletes were denied and `--fail-on-cleanup-denied` is set
+async fn check_cleanup_denied(tester: &S3ConsistencyTester, args: &Args) -> Result<()> {
+    let denied_files = tester.cleanup_manager().get_denied_files().await;
+    if denied_files.is_empty() {
+        return Ok(());
+    }
+    
+    print_cleanup_denied_warning(&denied_files);
+    
+    if args.fail_on_cleanup_denied {
+        error!("Cleanup was denied for {} test file(s)", denied_files.len());
+        return Err(anyhow::anyhow!(
+            "Deleting {} test file(s) was denied by the backend",
+            denied_files.len()
+        ));
+    }
     
     Ok(())
 }
 
+/// Checks that no test files remain in the bucket
+/// 
+/// Runs after the report has been saved so the results are kept even when
+/// the check fails.
+/// 
+/// # Arguments
+/// 
+/// * `tester` - The tester whose bucket should be checked
+/// 
+/// # Returns
+/// 
+/// - `Ok(())` if the bucket contains no test files
+/// - `Err(anyhow::Error)` listing the leftover keys, causing a non-zero exit
+async fn verify_cleanup(tester: &S3ConsistencyTester) -> Result<()> {
+    tester.verify_cleanup().await
+        .map_err(|e| {
+            error!("Cleanup verification failed: {}", e);
+            e.into()
+        })
+}
+
 /// Initializes the logging system with appropriate verbosity
 /// 
 /// Sets up structured logging using the `tracing` crate with different
@@ -144,18 +779,46 @@ async fn main() -> Result<()> {
 /// 
 /// # Arguments
 /// 
-/// * `verbose` - Whether to enable debug-level logging
-fn initialize_logging(verbose: bool) {
-    let level = if verbose {
+/// * `args` - Command-line arguments; `verbose` enables debug-level logging
+/// 
+/// Log output is discarded while the terminal dashboard is shown. When built
+/// with the `tokio-console` feature, `--tokio-console` additionally serves
+/// task states to `tokio-console`.
+fn initialize_logging(args: &Args) {
+    let level = if args.verbose {
         tracing::Level::DEBUG
     } else {
         tracing::Level::INFO
     };
     
-    tracing_subscriber::fmt()
-        .with_max_level(level)
+    let fmt_layer = tracing_subscriber::fmt::layer()
         .with_target(false)
         .with_ansi(true)
+        .with_writer(|| -> Box<dyn std::io::Write> {
+            if tui::is_active() {
+                Box::new(std::io::sink())
+            } else {
+                Box::new(std::io::stdout())
+            }
+        })
+        .with_filter(LevelFilter::from_level(level));
+    
+    // The console layer sees every task event, independent of the log level
+    #[cfg(feature = "tokio-console")]
+    let console_layer = args.tokio_console.then(console_subscriber::spawn);
+    #[cfg(not(feature = "tokio-console"))]
+    let console_layer: Option<Identity> = None;
+    
+    // Only this crate's debug events are buffered, not those of the HTTP stack
+    let failure_layer = args.verbose_failures.then(|| {
+        FailureLogLayer::new(std::io::stderr)
+            .with_filter(Targets::new().with_target(env!("CARGO_CRATE_NAME"), LevelFilter::DEBUG))
+    });
+    
+    tracing_subscriber::registry()
+        .with(console_layer)
+        .with(fmt_layer)
+        .with(failure_layer)
         .init();
 }
 
@@ -178,7 +841,7 @@ fn generate_report_filename(report: &crate::types::ConsistencyReport) -> String
     )
 }
 
-/// Saves the consistency report as a formatted JSON file
+/// Saves a report (or any other serializable value) as a formatted JSON file
 /// 
 /// Serializes the complete report to pretty-formatted JSON and writes
 /// it to disk. The JSON format allows for easy analysis with external
@@ -186,21 +849,22 @@ fn generate_report_filename(report: &crate::types::ConsistencyReport) -> String
 /// 
 /// # Arguments
 /// 
-/// * `report` - The consistency report to save
+/// * `report` - The report to save
 /// * `filename` - The filename to write to
 /// 
 /// # Returns
 /// 
 /// - `Ok(())` if the file was saved successfully
 /// - `Err(anyhow::Error)` if serialization or file writing fails
-fn save_json_report(report: &crate::types::ConsistencyReport, filename: &str) -> Result<()> {
+fn save_json_report<T: serde::Serialize>(report: &T, filename: impl AsRef<Path>) -> Result<()> {
+    let filename = filename.as_ref();
     let report_json = serde_json::to_string_pretty(report)
         .map_err(|e| anyhow::anyhow!("Failed to serialize report to JSON: {}", e))?;
     
     std::fs::write(filename, report_json)
-        .map_err(|e| anyhow::anyhow!("Failed to write report file {}: {}", filename, e))?;
+        .map_err(|e| anyhow::anyhow!("Failed to write report file {}: {}", filename.display(), e))?;
     
-    info!("💾 Detailed report saved to: {}", filename);
+    info!("💾 Detailed report saved to: {}", filename.display());
     
     Ok(())
 }
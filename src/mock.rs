@@ -0,0 +1,328 @@
+//! In-memory storage backend for self-testing
+//! 
+//! `MockStorage` implements `ObjectStore` without any network access. Uploaded
+//! objects only become visible to reads, existence checks and listings after
+//! a configurable propagation delay, and every request can fail with a
+//! simulated `503 SlowDown` at a configurable rate. `--self-test` runs the
+//! full pipeline against it and checks that the measured propagation time
+//! matches the simulated delay, which validates the tool's own timing logic
+//! without a real S3 endpoint.
+//! 
+//! Only compiled for tests and with the `self-test` feature.
+
+use anyhow::Result;
+use futures::future::{self, BoxFuture};
+use rand::Rng;
+use reqwest::header::HeaderMap;
+use s3::error::S3Error;
+use s3::serde_types::{ListBucketResult, Object};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::storage::{ObjectResponse, ObjectStore, UPLOAD_CONTENT_TYPE};
+use crate::types::ConsistencyStatistics;
+
+/// Maximum number of keys returned per listing page, like S3
+const MAX_LIST_KEYS: usize = 1000;
+
+/// Timing slack allowed when comparing measured and simulated propagation
+const SELF_TEST_TOLERANCE: Duration = Duration::from_millis(50);
+
+/// An object stored in the mock
+#[derive(Debug)]
+struct MockObject {
+    /// The object data
+    data: Vec<u8>,
+    
+    /// Headers sent with the upload, returned with every read
+    headers: HashMap<String, String>,
+    
+    /// When the object becomes visible to reads
+    visible_at: Instant,
+}
+
+/// Storage backend simulating eventual consistency in memory
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// let storage = Arc::new(MockStorage::new(Duration::from_millis(200), 0.05));
+/// let tester = S3ConsistencyTester::new(config).await?.with_storage(storage);
+/// ```
+#[derive(Debug)]
+pub struct MockStorage {
+    /// Stored objects by key
+    objects: Mutex<HashMap<String, MockObject>>,
+    
+    /// Time from an upload until the object becomes visible
+    propagation_delay: Duration,
+    
+    /// Probability (0.0 to 1.0) that any request fails
+    failure_rate: f64,
+}
+
+impl MockStorage {
+    /// Creates an empty mock backend
+    /// 
+    /// # Arguments
+    /// 
+    /// * `propagation_delay` - Time from an upload until the object is visible
+    /// * `failure_rate` - Probability (0.0 to 1.0) that any request fails
+    /// 
+    /// # Returns
+    /// 
+    /// A new `MockStorage` with no objects
+    pub fn new(propagation_delay: Duration, failure_rate: f64) -> Self {
+        Self {
+            objects: Mutex::new(HashMap::new()),
+            propagation_delay,
+            failure_rate: failure_rate.clamp(0.0, 1.0),
+        }
+    }
+    
+    /// Gets the number of stored objects, whether visible or not
+    pub fn object_count(&self) -> usize {
+        self.objects.lock().unwrap().len()
+    }
+    
+    /// Fails a request at the configured failure rate
+    fn simulate_failure(&self) -> Result<(), S3Error> {
+        if self.failure_rate > 0.0 && rand::thread_rng().gen_bool(self.failure_rate) {
+            return Err(S3Error::HttpFailWithBody(503, "<Error><Code>SlowDown</Code><Message>Simulated failure</Message></Error>".to_string()));
+        }
+        Ok(())
+    }
+    
+    /// Stores an object that becomes visible after the propagation delay
+    fn store_object(&self, key: &str, data: &[u8], headers: Option<HeaderMap>) -> Result<(), S3Error> {
+        self.simulate_failure()?;
+        
+        let headers = headers.iter()
+            .flatten()
+            .filter_map(|(name, value)| Some((name.as_str().to_string(), value.to_str().ok()?.to_string())))
+            .collect();
+        self.objects.lock().unwrap().insert(key.to_string(), MockObject {
+            data: data.to_vec(),
+            headers,
+            visible_at: Instant::now() + self.propagation_delay,
+        });
+        Ok(())
+    }
+    
+    /// Reads a visible object, or a byte range of it
+    fn read_object(&self, key: &str, range: Option<(u64, u64)>) -> Result<ObjectResponse, S3Error> {
+        self.simulate_failure()?;
+        
+        let objects = self.objects.lock().unwrap();
+        let object = objects.get(key)
+            .filter(|object| object.visible_at <= Instant::now())
+            .ok_or_else(|| S3Error::HttpFailWithBody(404, "<Error><Code>NoSuchKey</Code><Message>The specified key does not exist.</Message></Error>".to_string()))?;
+        
+        let body = match range {
+            Some((start, end)) => {
+                let end = (end as usize).min(object.data.len().saturating_sub(1));
+                object.data.get(start as usize..=end).unwrap_or_default().to_vec()
+            }
+            None => object.data.clone(),
+        };
+        let mut headers = object.headers.clone();
+        headers.insert("content-length".to_string(), body.len().to_string());
+        
+        Ok(ObjectResponse {
+            status_code: if range.is_some() { 206 } else { 200 },
+            body,
+            headers,
+        })
+    }
+    
+    /// Checks whether a visible object exists
+    fn object_exists(&self, key: &str) -> Result<bool, S3Error> {
+        self.simulate_failure()?;
+        
+        Ok(self.objects.lock().unwrap()
+            .get(key)
+            .is_some_and(|object| object.visible_at <= Instant::now()))
+    }
+    
+    /// Reads the metadata of a visible object
+    fn object_metadata(&self, key: &str) -> Result<Option<HashMap<String, String>>, S3Error> {
+        self.simulate_failure()?;
+        
+        let objects = self.objects.lock().unwrap();
+        Ok(objects.get(key)
+            .filter(|object| object.visible_at <= Instant::now())
+            .map(|object| {
+                let mut headers = object.headers.clone();
+                headers.insert("content-length".to_string(), object.data.len().to_string());
+                headers.insert("content-type".to_string(), UPLOAD_CONTENT_TYPE.to_string());
+                headers
+            }))
+    }
+    
+    /// Deletes an object immediately, visible or not
+    fn remove_object(&self, key: &str) -> Result<(), S3Error> {
+        self.simulate_failure()?;
+        
+        self.objects.lock().unwrap().remove(key);
+        Ok(())
+    }
+    
+    /// Lists visible keys under a prefix in key order, starting after `marker`
+    fn list_visible(&self, prefix: &str, marker: Option<String>, max_keys: Option<usize>) -> Result<ListBucketResult, S3Error> {
+        self.simulate_failure()?;
+        
+        let now = Instant::now();
+        let mut keys: Vec<(String, u64)> = self.objects.lock().unwrap()
+            .iter()
+            .filter(|(key, object)| key.starts_with(prefix) && object.visible_at <= now)
+            .filter(|(key, _)| marker.as_ref().is_none_or(|marker| key.as_str() > marker.as_str()))
+            .map(|(key, object)| (key.clone(), object.data.len() as u64))
+            .collect();
+        keys.sort();
+        
+        let max_keys = max_keys.unwrap_or(MAX_LIST_KEYS).min(MAX_LIST_KEYS);
+        let is_truncated = keys.len() > max_keys;
+        keys.truncate(max_keys);
+        
+        Ok(ListBucketResult {
+            name: "self-test".to_string(),
+            delimiter: None,
+            max_keys: None,
+            prefix: Some(prefix.to_string()),
+            continuation_token: None,
+            encoding_type: None,
+            is_truncated,
+            next_continuation_token: None,
+            contents: keys.into_iter().map(|(key, size)| Object {
+                last_modified: String::new(),
+                e_tag: None,
+                storage_class: None,
+                key,
+                owner: None,
+                size,
+            }).collect(),
+            common_prefixes: None,
+        })
+    }
+}
+
+/// Checks that a self-test measured the simulated propagation delay
+/// 
+/// Polling only sees an object on the first read at or after the delay, so
+/// the measured median may exceed the delay by up to one polling interval
+/// plus scheduling slack, but it can never be meaningfully shorter.
+/// 
+/// # Arguments
+/// 
+/// * `statistics` - Statistics of the self-test run
+/// * `delay` - The simulated propagation delay
+/// * `interval` - The polling interval used for the run
+/// 
+/// # Returns
+/// 
+/// - `Ok(())` if the median propagation time matches the delay
+/// - `Err(anyhow::Error)` describing the mismatch otherwise
+pub fn check_propagation(statistics: &ConsistencyStatistics, delay: Duration, interval: Duration) -> Result<()> {
+    let median = statistics.median_propagation_time_ms
+        .ok_or_else(|| anyhow::anyhow!("Self-test had no successful files to measure"))?;
+    let median = Duration::from_millis(median);
+    
+    let lower = delay.saturating_sub(SELF_TEST_TOLERANCE);
+    let upper = delay + interval * 2 + SELF_TEST_TOLERANCE;
+    if median < lower || median > upper {
+        anyhow::bail!(
+            "Self-test measured a median propagation time of {:?}, expected between {:?} and {:?}",
+            median, lower, upper
+        );
+    }
+    Ok(())
+}
+
+impl ObjectStore for MockStorage {
+    fn put<'a>(
+        &'a self,
+        key: &'a str,
+        data: &'a [u8],
+        headers: Option<HeaderMap>,
+    ) -> BoxFuture<'a, Result<(), S3Error>> {
+        Box::pin(future::ready(self.store_object(key, data, headers)))
+    }
+    
+    fn get<'a>(
+        &'a self,
+        key: &'a str,
+        range: Option<(u64, u64)>,
+    ) -> BoxFuture<'a, Result<ObjectResponse, S3Error>> {
+        Box::pin(future::ready(self.read_object(key, range)))
+    }
+    
+    fn head<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<bool, S3Error>> {
+        Box::pin(future::ready(self.object_exists(key)))
+    }
+    
+    fn metadata<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<Option<HashMap<String, String>>, S3Error>> {
+        Box::pin(future::ready(self.object_metadata(key)))
+    }
+    
+    fn delete<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<(), S3Error>> {
+        Box::pin(future::ready(self.remove_object(key)))
+    }
+    
+    fn list<'a>(
+        &'a self,
+        prefix: &'a str,
+        marker: Option<String>,
+        max_keys: Option<usize>,
+    ) -> BoxFuture<'a, Result<ListBucketResult, S3Error>> {
+        Box::pin(future::ready(self.list_visible(prefix, marker, max_keys)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[tokio::test]
+    async fn test_objects_become_visible_after_delay() {
+        let storage = MockStorage::new(Duration::from_millis(50), 0.0);
+        storage.put("a", b"hello", None).await.unwrap();
+        
+        assert!(matches!(storage.get("a", None).await, Err(S3Error::HttpFailWithBody(404, _))));
+        assert!(!storage.head("a").await.unwrap());
+        assert!(storage.metadata("a").await.unwrap().is_none());
+        assert!(storage.list("", None, None).await.unwrap().contents.is_empty());
+        
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        
+        assert_eq!(storage.get("a", None).await.unwrap().body, b"hello");
+        assert_eq!(storage.get("a", Some((0, 0))).await.unwrap().body, b"h");
+        assert!(storage.head("a").await.unwrap());
+        assert_eq!(storage.metadata("a").await.unwrap().unwrap()["content-length"], "5");
+        assert_eq!(storage.list("", None, None).await.unwrap().contents.len(), 1);
+        
+        storage.delete("a").await.unwrap();
+        assert_eq!(storage.object_count(), 0);
+    }
+    
+    #[tokio::test]
+    async fn test_failure_rate() {
+        let storage = MockStorage::new(Duration::ZERO, 1.0);
+        assert!(matches!(storage.put("a", b"hello", None).await, Err(S3Error::HttpFailWithBody(503, _))));
+    }
+    
+    #[test]
+    fn test_check_propagation() {
+        let statistics = |median: Option<u64>| ConsistencyStatistics {
+            median_propagation_time_ms: median,
+            ..crate::statistics::calculate_statistics(&[])
+        };
+        let delay = Duration::from_millis(200);
+        let interval = Duration::from_millis(100);
+        
+        assert!(check_propagation(&statistics(Some(250)), delay, interval).is_ok());
+        assert!(check_propagation(&statistics(Some(100)), delay, interval).is_err());
+        assert!(check_propagation(&statistics(Some(500)), delay, interval).is_err());
+        assert!(check_propagation(&statistics(None), delay, interval).is_err());
+    }
+}
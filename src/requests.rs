@@ -0,0 +1,108 @@
+//! S3 request accounting for consistency testing
+//! 
+//! S3-compatible services typically charge per request, so this module keeps
+//! a running count of every request the tool makes: uploads, each poll
+//! attempt, listings, and every delete attempt including retries. The counter
+//! is shared between the tester, the cleanup manager and the availability probe.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::types::RequestCounts;
+
+/// Kind of S3 request being counted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequestKind {
+    /// `PUT` object (upload; a streamed upload counts once even if sent in parts,
+    /// while each request of a `--multipart` upload counts separately)
+    Upload,
+    
+    /// `GET` object (consistency read), or `HEAD` object for `--check-collision`
+    Read,
+    
+    /// List objects (one request per listing page)
+    List,
+    
+    /// `DELETE` object (cleanup, including retries; a batch delete counts once)
+    Delete,
+}
+
+/// Thread-safe counter of S3 requests made during a run
+/// 
+/// Designed to be shared across async tasks using `Arc`. Requests are
+/// counted when they are sent, whether or not they succeed.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// let requests = Arc::new(RequestCounter::default());
+/// requests.record(RequestKind::Upload);
+/// assert_eq!(requests.snapshot().total(), 1);
+/// ```
+#[derive(Debug, Default)]
+pub struct RequestCounter {
+    /// Number of upload requests
+    uploads: AtomicU64,
+    
+    /// Number of read requests
+    reads: AtomicU64,
+    
+    /// Number of listing requests
+    lists: AtomicU64,
+    
+    /// Number of delete requests
+    deletes: AtomicU64,
+}
+
+impl RequestCounter {
+    /// Records that a request of the given kind was sent
+    /// 
+    /// # Arguments
+    /// 
+    /// * `kind` - The kind of request
+    pub fn record(&self, kind: RequestKind) {
+        let counter = match kind {
+            RequestKind::Upload => &self.uploads,
+            RequestKind::Read => &self.reads,
+            RequestKind::List => &self.lists,
+            RequestKind::Delete => &self.deletes,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+    
+    /// Gets the number of requests made so far
+    /// 
+    /// # Returns
+    /// 
+    /// The request counts broken down by kind
+    pub fn snapshot(&self) -> RequestCounts {
+        RequestCounts {
+            uploads: self.uploads.load(Ordering::Relaxed),
+            reads: self.reads.load(Ordering::Relaxed),
+            lists: self.lists.load(Ordering::Relaxed),
+            deletes: self.deletes.load(Ordering::Relaxed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_request_counter() {
+        let requests = RequestCounter::default();
+        
+        requests.record(RequestKind::Upload);
+        requests.record(RequestKind::Read);
+        requests.record(RequestKind::Read);
+        requests.record(RequestKind::List);
+        requests.record(RequestKind::Delete);
+        
+        let counts = requests.snapshot();
+        assert_eq!(counts.uploads, 1);
+        assert_eq!(counts.reads, 2);
+        assert_eq!(counts.lists, 1);
+        assert_eq!(counts.deletes, 1);
+        assert_eq!(counts.total(), 5);
+    }
+}
@@ -0,0 +1,346 @@
+//! Bounded-memory result collection for very large runs
+//! 
+//! Holding every `TestResult` of a million-file run, and serializing all of
+//! them into the report, takes more memory and disk than the run is worth.
+//! `ResultCollector` keeps results in memory only up to
+//! `--max-results-in-memory`. Beyond that, results are folded into running
+//! statistics a chunk at a time with `merge_statistics`, and the report keeps
+//! a uniform random sample of them (reservoir sampling). Counts, rates,
+//! minimum, maximum and average stay exact; order statistics such as the
+//! median and percentiles are estimated from the sample.
+//! 
+//! With `--results-jsonl`, every result is also appended to a file as one
+//! JSON line as soon as it finishes, so no per-file data is lost. With
+//! `--failures-only`, only failed results are written to the file.
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use tracing::{error, info};
+
+use crate::error::ConsistencyTestError;
+use crate::statistics;
+use crate::types::{ConsistencyStatistics, TestResult};
+
+/// Results collected over a run
+#[derive(Debug)]
+pub struct CollectedResults {
+    /// Every result, or a random sample of them, in the order the tests were started
+    pub results: Vec<TestResult>,
+    
+    /// Statistics covering every result
+    pub statistics: ConsistencyStatistics,
+    
+    /// Number of results collected, which may exceed `results.len()`
+    pub total: usize,
+}
+
+impl CollectedResults {
+    /// Determines whether `results` holds only a sample of the results
+    pub fn is_sampled(&self) -> bool {
+        self.total > self.results.len()
+    }
+}
+
+/// Collects test results within a memory limit
+pub struct ResultCollector {
+    /// File every result is appended to as one JSON line, if any
+    writer: Option<(PathBuf, BufWriter<File>)>,
+    
+    /// Whether only failed results are written to the JSONL file
+    failures_only: bool,
+    
+    /// Maximum number of results held in memory, and the size of the sample
+    limit: usize,
+    
+    /// Shortest propagation time included in the statistics, if limited
+    stats_min_ms: Option<u64>,
+    
+    /// Longest propagation time included in the statistics, if limited
+    stats_max_ms: Option<u64>,
+    
+    /// Results not yet folded into `totals`
+    pending: Vec<TestResult>,
+    
+    /// Statistics of the results folded so far
+    /// 
+    /// `None` until more than `limit` results have been collected.
+    totals: Option<ConsistencyStatistics>,
+    
+    /// Uniform random sample of the results folded so far
+    sample: Vec<TestResult>,
+    
+    /// Number of results folded into `totals`
+    folded: usize,
+    
+    /// Random source for the sample
+    rng: StdRng,
+}
+
+impl ResultCollector {
+    /// Creates a collector
+    /// 
+    /// # Arguments
+    /// 
+    /// * `limit` - Maximum number of results held in memory
+    /// * `jsonl_path` - File to append every result to, if any
+    /// * `failures_only` - Write only failed results to the JSONL file
+    /// * `stats_min_ms` - Shortest propagation time to include in the statistics
+    /// * `stats_max_ms` - Longest propagation time to include in the statistics
+    /// 
+    /// # Returns
+    /// 
+    /// - `Ok(ResultCollector)` ready to collect results
+    /// - `Err(ConsistencyTestError::ResultsFile)` if the file cannot be created
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let collector = ResultCollector::new(100_000, Some(Path::new("results.jsonl")), false, None, None)?;
+    /// ```
+    pub fn new(
+        limit: usize,
+        jsonl_path: Option<&Path>,
+        failures_only: bool,
+        stats_min_ms: Option<u64>,
+        stats_max_ms: Option<u64>,
+    ) -> Result<Self, ConsistencyTestError> {
+        let writer = jsonl_path
+            .map(|path| {
+                let file = File::create(path).map_err(|source| ConsistencyTestError::ResultsFile {
+                    path: path.to_path_buf(),
+                    source,
+                })?;
+                info!("📝 Writing every result to {}", path.display());
+                Ok::<_, ConsistencyTestError>((path.to_path_buf(), BufWriter::new(file)))
+            })
+            .transpose()?;
+        
+        Ok(Self {
+            writer,
+            failures_only,
+            limit: limit.max(1),
+            stats_min_ms,
+            stats_max_ms,
+            pending: Vec::new(),
+            totals: None,
+            sample: Vec::new(),
+            folded: 0,
+            rng: StdRng::from_entropy(),
+        })
+    }
+    
+    /// Gets the number of results collected so far
+    pub fn count(&self) -> usize {
+        self.folded + self.pending.len()
+    }
+    
+    /// Adds a finished result
+    /// 
+    /// The result is written to the JSONL file right away, unless it
+    /// succeeded and only failures are written. It always counts towards the
+    /// statistics. A write error is logged and stops further writes, without
+    /// failing the run.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `result` - The finished test result
+    pub fn push(&mut self, result: TestResult) {
+        if let Some((path, writer)) = &mut self.writer
+            && !(self.failures_only && result.success)
+        {
+            let written = serde_json::to_writer(&mut *writer, &result)
+                .map_err(std::io::Error::from)
+                .and_then(|_| writer.write_all(b"\n"));
+            if let Err(e) = written {
+                error!("❌ Failed to write results to {}, no further results are written: {}", path.display(), e);
+                self.writer = None;
+            }
+        }
+        
+        self.pending.push(result);
+        if self.pending.len() >= self.limit && self.count() > self.limit {
+            self.fold();
+        }
+    }
+    
+    /// Folds the pending results into the running statistics and the sample
+    fn fold(&mut self) {
+        let chunk = std::mem::take(&mut self.pending);
+        let chunk_statistics = statistics::calculate_filtered_statistics(&chunk, self.stats_min_ms, self.stats_max_ms);
+        self.totals = Some(match &self.totals {
+            Some(totals) => statistics::merge_statistics(totals, &chunk_statistics),
+            None => chunk_statistics,
+        });
+        
+        // Reservoir sampling: the i-th result replaces a random slot with probability limit/(i+1)
+        for result in chunk {
+            if self.sample.len() < self.limit {
+                self.sample.push(result);
+            } else {
+                let slot = self.rng.gen_range(0..=self.folded);
+                if slot < self.limit {
+                    self.sample[slot] = result;
+                }
+            }
+            self.folded += 1;
+        }
+    }
+    
+    /// Calculates statistics for the results collected so far
+    /// 
+    /// # Returns
+    /// 
+    /// The statistics, with order statistics estimated from the sample once
+    /// more than `limit` results have been collected
+    pub fn statistics(&self) -> ConsistencyStatistics {
+        let pending = statistics::calculate_filtered_statistics(&self.pending, self.stats_min_ms, self.stats_max_ms);
+        let Some(totals) = &self.totals else { return pending };
+        
+        let totals = if self.pending.is_empty() {
+            totals.clone()
+        } else {
+            statistics::merge_statistics(totals, &pending)
+        };
+        let sample = statistics::calculate_filtered_statistics(&self.sample, self.stats_min_ms, self.stats_max_ms);
+        statistics::combine_sampled_statistics(&totals, &sample)
+    }
+    
+    /// Finishes collecting
+    /// 
+    /// Flushes the JSONL file and calculates the final statistics.
+    /// 
+    /// # Returns
+    /// 
+    /// Every result if no more than `limit` were collected, otherwise a
+    /// random sample of `limit` results, together with statistics for all
+    pub fn finish(mut self) -> CollectedResults {
+        if let Some((path, writer)) = &mut self.writer
+            && let Err(e) = writer.flush()
+        {
+            error!("❌ Failed to write results to {}: {}", path.display(), e);
+        }
+        
+        let total = self.count();
+        if self.totals.is_some() && !self.pending.is_empty() {
+            self.fold();
+        }
+        let statistics = self.statistics();
+        
+        let mut results = match self.totals {
+            Some(_) => {
+                info!("🎲 Keeping a random sample of {} of {} results in the report", self.sample.len(), total);
+                self.sample
+            }
+            None => self.pending,
+        };
+        // Concurrent files finish in any order; sort by start order so reports can be diffed
+        results.sort_by_key(|result| result.test_index);
+        
+        CollectedResults {
+            results,
+            statistics,
+            total,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Utc};
+    use tempfile::NamedTempFile;
+    
+    fn create_results(count: usize) -> Vec<TestResult> {
+        let start = Utc::now();
+        (0..count)
+            .map(|i| {
+                let upload_time = start + Duration::seconds(i as i64);
+                let mut result = if i % 5 == 4 {
+                    TestResult::failure(format!("key-{}", i), upload_time, "Timed out".to_string())
+                } else {
+                    TestResult::success(format!("key-{}", i), upload_time, upload_time + Duration::milliseconds(10 * (i as i64 + 1)), 1)
+                };
+                result.test_index = i;
+                result
+            })
+            .collect()
+    }
+    
+    #[test]
+    fn test_results_within_limit_are_kept() {
+        let mut collector = ResultCollector::new(10, None, false, None, None).unwrap();
+        for result in create_results(10) {
+            collector.push(result);
+        }
+        
+        let collected = collector.finish();
+        assert!(!collected.is_sampled());
+        assert_eq!(collected.results.len(), 10);
+        assert_eq!(collected.results[3].file_key, "key-3");
+        assert_eq!(collected.statistics.percentile_95_ms, statistics::calculate_statistics(&create_results(10)).percentile_95_ms);
+    }
+    
+    #[test]
+    fn test_results_are_kept_in_start_order() {
+        let mut collector = ResultCollector::new(10, None, false, None, None).unwrap();
+        let mut results = create_results(6);
+        results.swap(0, 4);
+        results.swap(1, 5);
+        for result in results {
+            collector.push(result);
+        }
+        
+        let keys: Vec<_> = collector.finish().results.into_iter().map(|result| result.file_key).collect();
+        assert_eq!(keys, ["key-0", "key-1", "key-2", "key-3", "key-4", "key-5"]);
+    }
+    
+    #[test]
+    fn test_results_beyond_limit_are_sampled_and_written() {
+        let file = NamedTempFile::new().unwrap();
+        let mut collector = ResultCollector::new(10, Some(file.path()), false, None, None).unwrap();
+        for result in create_results(45) {
+            collector.push(result);
+        }
+        
+        let collected = collector.finish();
+        assert!(collected.is_sampled());
+        assert_eq!(collected.total, 45);
+        assert_eq!(collected.results.len(), 10);
+        assert!(collected.results.windows(2).all(|pair| pair[0].test_index < pair[1].test_index));
+        
+        // Counts and extremes stay exact
+        let statistics = &collected.statistics;
+        assert_eq!(statistics.successful_tests, 36);
+        assert_eq!(statistics.failed_tests, 9);
+        assert_eq!(statistics.min_propagation_time_ms, Some(10));
+        assert_eq!(statistics.max_propagation_time_ms, Some(440));
+        assert!(statistics.median_propagation_time_ms.is_some());
+        
+        let lines = std::fs::read_to_string(file.path()).unwrap();
+        assert_eq!(lines.lines().count(), 45);
+        assert!(lines.lines().all(|line| serde_json::from_str::<serde_json::Value>(line).is_ok()));
+    }
+    
+    #[test]
+    fn test_failures_only_writes_failed_results() {
+        let file = NamedTempFile::new().unwrap();
+        let mut collector = ResultCollector::new(10, Some(file.path()), true, None, None).unwrap();
+        for result in create_results(20) {
+            collector.push(result);
+        }
+        
+        let collected = collector.finish();
+        assert_eq!(collected.statistics.successful_tests, 16);
+        assert_eq!(collected.statistics.failed_tests, 4);
+        
+        let lines = std::fs::read_to_string(file.path()).unwrap();
+        let written: Vec<serde_json::Value> = lines.lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(written.len(), 4);
+        assert!(written.iter().all(|result| result["success"] == false));
+    }
+}
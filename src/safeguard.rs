@@ -0,0 +1,158 @@
+//! Safeguard against running the tester against the wrong bucket
+//! 
+//! The tester creates and deletes objects, so pointing it at a production
+//! bucket by mistake is dangerous. Buckets can be explicitly allowed with
+//! `--allow-bucket`, flagged with `--deny-pattern`, or every bucket not
+//! explicitly allowed can be protected with `require_confirmation = true` in
+//! the configuration. A protected bucket requires typing its name at an
+//! interactive prompt, or passing `--yes`, before any object is written.
+
+use anyhow::{Result, anyhow};
+use std::io::{BufRead, IsTerminal, Write};
+
+/// Decides whether a bucket needs confirmation before testing
+/// 
+/// Buckets in `allow` never need confirmation. Otherwise a bucket needs
+/// confirmation if it matches a deny pattern, if an allowlist was given and
+/// the bucket is not on it, or if `require_confirmation` is set.
+/// 
+/// # Arguments
+/// 
+/// * `bucket` - The target bucket name
+/// * `allow` - Explicitly allowed bucket names
+/// * `deny_patterns` - Bucket name patterns where `*` matches any characters
+/// * `require_confirmation` - Whether every bucket not allowed needs confirmation
+/// 
+/// # Returns
+/// 
+/// The reason confirmation is needed, or `None` if the bucket may be used
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// let reason = confirmation_reason("prod-assets", &[], &["prod-*".to_string()], false);
+/// assert!(reason.is_some());
+/// ```
+pub fn confirmation_reason(
+    bucket: &str,
+    allow: &[String],
+    deny_patterns: &[String],
+    require_confirmation: bool,
+) -> Option<String> {
+    if allow.iter().any(|allowed| allowed == bucket) {
+        return None;
+    }
+    
+    if let Some(pattern) = deny_patterns.iter().find(|pattern| matches_pattern(pattern, bucket)) {
+        return Some(format!("bucket '{}' matches deny pattern '{}'", bucket, pattern));
+    }
+    
+    if !allow.is_empty() {
+        return Some(format!("bucket '{}' is not in the --allow-bucket list", bucket));
+    }
+    
+    if require_confirmation {
+        return Some(format!("require_confirmation is set and bucket '{}' is not explicitly allowed", bucket));
+    }
+    
+    None
+}
+
+/// Asks the user to confirm testing against a protected bucket
+/// 
+/// The user must type the bucket name to continue. Without an interactive
+/// terminal the run is refused, since nobody can answer the prompt.
+/// 
+/// # Arguments
+/// 
+/// * `bucket` - The target bucket name
+/// * `reason` - Why confirmation is needed, from `confirmation_reason`
+/// 
+/// # Returns
+/// 
+/// - `Ok(())` if the user confirmed
+/// - `Err(anyhow::Error)` if the user declined or no terminal is available
+pub fn confirm_bucket(bucket: &str, reason: &str) -> Result<()> {
+    let stdin = std::io::stdin();
+    if !stdin.is_terminal() {
+        return Err(anyhow!(
+            "Refusing to test without confirmation: {}. Pass --yes to run non-interactively",
+            reason
+        ));
+    }
+    
+    eprintln!("⚠️  This test creates and deletes objects, and {}.", reason);
+    eprint!("Type the bucket name to continue: ");
+    std::io::stderr().flush()?;
+    
+    let mut answer = String::new();
+    stdin.lock().read_line(&mut answer)?;
+    
+    if answer.trim() != bucket {
+        return Err(anyhow!("Confirmation did not match bucket '{}', aborting", bucket));
+    }
+    
+    Ok(())
+}
+
+/// Matches a bucket name against a pattern where `*` matches any characters
+fn matches_pattern(pattern: &str, name: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = name.strip_prefix(first) else {
+        return false;
+    };
+    
+    let remaining: Vec<&str> = parts.collect();
+    let Some((last, middle)) = remaining.split_last() else {
+        // No wildcard, so the pattern must match exactly
+        return rest.is_empty();
+    };
+    
+    for part in middle {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    
+    rest.ends_with(last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_matches_pattern() {
+        assert!(matches_pattern("prod-*", "prod-assets"));
+        assert!(matches_pattern("*-prod", "assets-prod"));
+        assert!(matches_pattern("*prod*", "my-prod-bucket"));
+        assert!(matches_pattern("a*b*c", "a-b-c"));
+        assert!(matches_pattern("exact", "exact"));
+        assert!(matches_pattern("*", "anything"));
+        
+        assert!(!matches_pattern("exact", "exact-not"));
+        assert!(!matches_pattern("prod-*", "staging-prod"));
+        assert!(!matches_pattern("a*b*c", "a-c-b"));
+        assert!(!matches_pattern("ab*ba", "aba"));
+    }
+    
+    #[test]
+    fn test_confirmation_reason() {
+        let deny = vec!["prod-*".to_string()];
+        
+        // Nothing configured means no confirmation
+        assert!(confirmation_reason("prod-assets", &[], &[], false).is_none());
+        
+        // Deny patterns and require_confirmation protect buckets
+        assert!(confirmation_reason("prod-assets", &[], &deny, false).is_some());
+        assert!(confirmation_reason("test-bucket", &[], &deny, false).is_none());
+        assert!(confirmation_reason("test-bucket", &[], &[], true).is_some());
+        
+        // An allowlist protects every other bucket, and wins over deny patterns
+        let allow = vec!["prod-scratch".to_string()];
+        assert!(confirmation_reason("prod-scratch", &allow, &deny, true).is_none());
+        assert!(confirmation_reason("test-bucket", &allow, &[], false).is_some());
+    }
+}
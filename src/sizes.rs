@@ -0,0 +1,176 @@
+//! Test file size distributions for consistency testing
+//! 
+//! By default every test file has the same `--file-size`. Real workloads
+//! store a mix of sizes, and larger objects can propagate differently, so a
+//! weighted distribution such as `80%:10KB,15%:1MB,5%:100MB` can be given to
+//! sample each file's size independently. Propagation is then also reported
+//! per size, answering "what is our p95 for the sizes we actually store".
+
+use anyhow::{Result, anyhow};
+use rand::Rng;
+use rand::distributions::{Distribution, WeightedIndex};
+use std::fmt;
+
+/// Size units accepted in a distribution, largest first
+const UNITS: [(&str, usize); 4] = [
+    ("GB", 1024 * 1024 * 1024),
+    ("MB", 1024 * 1024),
+    ("KB", 1024),
+    ("B", 1),
+];
+
+/// Weighted distribution of test file sizes
+/// 
+/// Written as comma-separated `WEIGHT:SIZE` entries. Weights are relative
+/// and may carry a `%` sign; they do not need to add up to 100. Sizes are
+/// bytes, optionally with a `B`, `KB`, `MB` or `GB` suffix (powers of 1024).
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// let distribution = SizeDistribution::parse("80%:10KB,15%:1MB,5%:100MB")?;
+/// let size = distribution.sample(&mut rand::thread_rng());
+/// ```
+#[derive(Debug, Clone)]
+pub struct SizeDistribution {
+    /// The distribution as given on the command line
+    spec: String,
+    
+    /// File sizes in bytes, in the order given
+    sizes: Vec<usize>,
+    
+    /// Sampler picking an index into `sizes` by weight
+    weights: WeightedIndex<f64>,
+}
+
+impl SizeDistribution {
+    /// Parses and validates a size distribution
+    /// 
+    /// # Arguments
+    /// 
+    /// * `spec` - The distribution, e.g. `80%:10KB,15%:1MB,5%:100MB`
+    /// 
+    /// # Returns
+    /// 
+    /// - `Ok(SizeDistribution)` if the distribution is valid
+    /// - `Err(anyhow::Error)` if an entry is malformed, a weight is negative,
+    ///   a size is repeated, or every weight is zero
+    pub fn parse(spec: &str) -> Result<Self> {
+        let mut sizes = Vec::new();
+        let mut weights = Vec::new();
+        
+        for entry in spec.split(',').map(str::trim) {
+            let (weight, size) = entry.split_once(':')
+                .ok_or_else(|| anyhow!("Size distribution entry '{}' must be WEIGHT:SIZE", entry))?;
+            
+            let weight: f64 = weight.trim().trim_end_matches('%').parse()
+                .map_err(|_| anyhow!("Invalid weight '{}' in size distribution", weight))?;
+            if !weight.is_finite() || weight < 0.0 {
+                return Err(anyhow!("Weight '{}' in size distribution must not be negative", weight));
+            }
+            
+            let size = parse_size(size.trim())?;
+            if sizes.contains(&size) {
+                return Err(anyhow!("Size {} bytes appears more than once in size distribution", size));
+            }
+            
+            sizes.push(size);
+            weights.push(weight);
+        }
+        
+        let weights = WeightedIndex::new(&weights)
+            .map_err(|_| anyhow!("Size distribution needs at least one positive weight"))?;
+        
+        Ok(Self {
+            spec: spec.to_string(),
+            sizes,
+            weights,
+        })
+    }
+    
+    /// Picks the size of the next test file
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rng` - Random number generator to sample with
+    /// 
+    /// # Returns
+    /// 
+    /// A file size in bytes, chosen with probability proportional to its weight
+    pub fn sample<R: Rng>(&self, rng: &mut R) -> usize {
+        self.sizes[self.weights.sample(rng)]
+    }
+    
+    /// Gets the largest size the distribution can produce
+    pub fn max_size(&self) -> usize {
+        self.sizes.iter().copied().max().unwrap_or(0)
+    }
+}
+
+impl fmt::Display for SizeDistribution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.spec)
+    }
+}
+
+/// Parses a file size with an optional unit suffix
+/// 
+/// # Arguments
+/// 
+/// * `size` - The size, e.g. `512`, `10KB` or `1 MB`
+/// 
+/// # Returns
+/// 
+/// - `Ok(usize)` - The size in bytes
+/// - `Err(anyhow::Error)` if the number or unit is invalid
+fn parse_size(size: &str) -> Result<usize> {
+    let upper = size.to_ascii_uppercase();
+    let (number, multiplier) = UNITS.iter()
+        .find_map(|(unit, multiplier)| upper.strip_suffix(unit).map(|number| (number, *multiplier)))
+        .unwrap_or((upper.as_str(), 1));
+    
+    number.trim().parse::<usize>().ok()
+        .and_then(|number| number.checked_mul(multiplier))
+        .ok_or_else(|| anyhow!("Invalid size '{}' in size distribution", size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    
+    #[test]
+    fn test_parse_size() {
+        assert_eq!(parse_size("512").unwrap(), 512);
+        assert_eq!(parse_size("512B").unwrap(), 512);
+        assert_eq!(parse_size("10KB").unwrap(), 10 * 1024);
+        assert_eq!(parse_size("1 mb").unwrap(), 1024 * 1024);
+        assert_eq!(parse_size("2GB").unwrap(), 2 * 1024 * 1024 * 1024);
+        assert!(parse_size("10XB").is_err());
+        assert!(parse_size("KB").is_err());
+    }
+    
+    #[test]
+    fn test_parse_distribution() {
+        let distribution = SizeDistribution::parse("80%:10KB, 15%:1MB, 5%:100MB").unwrap();
+        assert_eq!(distribution.max_size(), 100 * 1024 * 1024);
+        assert_eq!(distribution.to_string(), "80%:10KB, 15%:1MB, 5%:100MB");
+        
+        assert!(SizeDistribution::parse("10KB").is_err());
+        assert!(SizeDistribution::parse("-1:10KB").is_err());
+        assert!(SizeDistribution::parse("0:10KB,0:1MB").is_err());
+        assert!(SizeDistribution::parse("50:1KB,50:1024").is_err());
+    }
+    
+    #[test]
+    fn test_sample_follows_weights() {
+        let distribution = SizeDistribution::parse("0:1KB,1:2KB").unwrap();
+        let mut rng = StdRng::seed_from_u64(7);
+        assert!((0..100).all(|_| distribution.sample(&mut rng) == 2048));
+        
+        let distribution = SizeDistribution::parse("90:1KB,10:2KB").unwrap();
+        let small = (0..1000).filter(|_| distribution.sample(&mut rng) == 1024).count();
+        assert!((800..=980).contains(&small));
+    }
+}
@@ -0,0 +1,304 @@
+//! SQLite export for historical querying of consistency test results
+//! 
+//! This module appends completed consistency reports to an SQLite database so
+//! that results from many runs can be queried together with plain SQL. The
+//! schema is created on first use, and each run adds one row to `runs`, one
+//! row per tested file to `results` and one row per label to `run_labels`.
+//! 
+//! # Example Query
+//! 
+//! ```sql
+//! SELECT date(test_start_time) AS day, bucket, AVG(avg_propagation_time_ms)
+//! FROM runs
+//! GROUP BY day, bucket
+//! ORDER BY day;
+//! ```
+
+use anyhow::{Context, Result};
+use rusqlite::{Connection, params};
+use std::path::Path;
+use tracing::info;
+
+use crate::types::ConsistencyReport;
+
+/// Schema for the results database
+/// 
+/// Each result row also stores the full serialized `TestResult` in
+/// `result_json` so that fields added in newer versions of the tool are
+/// preserved even when they don't have a dedicated column.
+const SCHEMA: &str = r#"
+CREATE TABLE IF NOT EXISTS runs (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    test_start_time TEXT NOT NULL,
+    test_end_time TEXT NOT NULL,
+    total_duration_ms INTEGER NOT NULL,
+    tool_version TEXT NOT NULL,
+    s3_client_version TEXT NOT NULL,
+    endpoint TEXT NOT NULL,
+    region TEXT NOT NULL,
+    bucket TEXT NOT NULL,
+    test_count INTEGER NOT NULL,
+    file_size INTEGER NOT NULL,
+    max_wait_seconds INTEGER NOT NULL,
+    check_interval_ms INTEGER NOT NULL,
+    successful_tests INTEGER NOT NULL,
+    failed_tests INTEGER NOT NULL,
+    success_rate REAL NOT NULL,
+    min_propagation_time_ms INTEGER,
+    max_propagation_time_ms INTEGER,
+    avg_propagation_time_ms REAL,
+    median_propagation_time_ms INTEGER,
+    percentile_95_ms INTEGER,
+    percentile_99_ms INTEGER
+);
+
+CREATE TABLE IF NOT EXISTS results (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    run_id INTEGER NOT NULL REFERENCES runs(id),
+    file_key TEXT NOT NULL,
+    upload_time TEXT NOT NULL,
+    first_read_success_time TEXT,
+    propagation_duration_ms INTEGER,
+    check_interval_ms INTEGER,
+    total_attempts INTEGER NOT NULL,
+    success INTEGER NOT NULL,
+    error_details TEXT,
+    is_outlier INTEGER NOT NULL,
+    result_json TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_results_run_id ON results(run_id);
+
+CREATE TABLE IF NOT EXISTS run_labels (
+    run_id INTEGER NOT NULL REFERENCES runs(id),
+    key TEXT NOT NULL,
+    value TEXT NOT NULL,
+    PRIMARY KEY (run_id, key)
+);
+"#;
+
+/// Appends a consistency report to an SQLite database
+/// 
+/// Opens (or creates) the database at `path`, creates the schema if needed
+/// and inserts the run metadata and all per-file results in a single transaction.
+/// 
+/// # Arguments
+/// 
+/// * `report` - The consistency report to store
+/// * `path` - Path to the SQLite database file
+/// 
+/// # Returns
+/// 
+/// - `Ok(i64)` with the id of the inserted run
+/// - `Err(anyhow::Error)` if the database cannot be opened or written
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// let run_id = save_sqlite_report(&report, Path::new("results.db"))?;
+/// ```
+pub fn save_sqlite_report(report: &ConsistencyReport, path: &Path) -> Result<i64> {
+    let mut conn = Connection::open(path)
+        .with_context(|| format!("Failed to open SQLite database: {}", path.display()))?;
+    
+    conn.execute_batch(SCHEMA)
+        .context("Failed to create SQLite schema")?;
+    
+    let tx = conn.transaction()
+        .context("Failed to start SQLite transaction")?;
+    
+    let stats = &report.statistics;
+    let params = &report.test_parameters;
+    
+    tx.execute(
+        "INSERT INTO runs (
+            test_start_time, test_end_time, total_duration_ms, tool_version, s3_client_version,
+            endpoint, region, bucket, test_count, file_size, max_wait_seconds, check_interval_ms,
+            successful_tests, failed_tests, success_rate, min_propagation_time_ms,
+            max_propagation_time_ms, avg_propagation_time_ms, median_propagation_time_ms,
+            percentile_95_ms, percentile_99_ms
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21)",
+        params![
+            report.test_start_time.to_rfc3339(),
+            report.test_end_time.to_rfc3339(),
+            report.total_duration_ms as i64,
+            report.tool_version,
+            report.s3_client_version,
+            report.config.endpoint,
+            report.config.region,
+            report.config.bucket,
+            params.test_count as i64,
+            params.file_size as i64,
+            params.max_wait_seconds as i64,
+            params.check_interval_ms as i64,
+            stats.successful_tests as i64,
+            stats.failed_tests as i64,
+            stats.success_rate,
+            stats.min_propagation_time_ms.map(|v| v as i64),
+            stats.max_propagation_time_ms.map(|v| v as i64),
+            stats.avg_propagation_time_ms,
+            stats.median_propagation_time_ms.map(|v| v as i64),
+            stats.percentile_95_ms.map(|v| v as i64),
+            stats.percentile_99_ms.map(|v| v as i64),
+        ],
+    ).context("Failed to insert run into SQLite database")?;
+    
+    let run_id = tx.last_insert_rowid();
+    
+    {
+        let mut stmt = tx.prepare(
+            "INSERT INTO results (
+                run_id, file_key, upload_time, first_read_success_time, propagation_duration_ms,
+                check_interval_ms, total_attempts, success, error_details, is_outlier, result_json
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+        ).context("Failed to prepare SQLite result insert")?;
+        
+        for result in &report.results {
+            let result_json = serde_json::to_string(result)
+                .context("Failed to serialize test result")?;
+            
+            stmt.execute(params![
+                run_id,
+                result.file_key,
+                result.upload_time.to_rfc3339(),
+                result.first_read_success_time.map(|t| t.to_rfc3339()),
+                result.propagation_duration_ms.map(|v| v as i64),
+                result.check_interval_ms.map(|v| v as i64),
+                result.total_attempts,
+                result.success,
+                result.error_details,
+                result.is_outlier,
+                result_json,
+            ]).with_context(|| format!("Failed to insert result for {}", result.file_key))?;
+        }
+        
+        let mut stmt = tx.prepare(
+            "INSERT INTO run_labels (run_id, key, value) VALUES (?1, ?2, ?3)",
+        ).context("Failed to prepare SQLite label insert")?;
+        
+        for (key, value) in &report.labels {
+            stmt.execute(params![run_id, key, value])
+                .with_context(|| format!("Failed to insert label {}", key))?;
+        }
+    }
+    
+    tx.commit()
+        .context("Failed to commit SQLite transaction")?;
+    
+    info!("🗄️  Results stored in SQLite database {} (run id {})", path.display(), run_id);
+    
+    Ok(run_id)
+}
+
+/// Loads every successful propagation time in the database by hour of upload
+/// 
+/// # Arguments
+/// 
+/// * `path` - Path to the SQLite database file
+/// 
+/// # Returns
+/// 
+/// - `Ok(Vec<(u32, u64)>)` of (UTC hour of day, propagation time in milliseconds)
+/// - `Err(anyhow::Error)` if the database cannot be opened or queried
+pub fn load_durations_by_hour(path: &Path) -> Result<Vec<(u32, u64)>> {
+    let conn = Connection::open(path)
+        .with_context(|| format!("Failed to open SQLite database: {}", path.display()))?;
+    
+    let mut stmt = conn.prepare(
+        "SELECT CAST(strftime('%H', upload_time) AS INTEGER), propagation_duration_ms
+         FROM results
+         WHERE success = 1 AND propagation_duration_ms IS NOT NULL",
+    ).context("Failed to prepare SQLite time-of-day query")?;
+    
+    let rows = stmt.query_map([], |row| {
+        Ok((row.get::<_, i64>(0)? as u32, row.get::<_, i64>(1)? as u64))
+    }).context("Failed to query SQLite time-of-day data")?;
+    
+    rows.collect::<rusqlite::Result<Vec<_>>>()
+        .context("Failed to read SQLite time-of-day data")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::S3Config;
+    use crate::statistics::calculate_statistics;
+    use crate::types::{Args, TestParameters, TestResult};
+    use chrono::{Timelike, Utc};
+    use clap::Parser;
+    use tempfile::NamedTempFile;
+
+    fn create_test_report() -> ConsistencyReport {
+        let now = Utc::now();
+        let results = vec![
+            TestResult::success("key-1".to_string(), now, now, 1),
+            TestResult::failure("key-2".to_string(), now, "Test error".to_string()),
+        ];
+        let statistics = calculate_statistics(&results);
+        
+        ConsistencyReport::new(
+            now,
+            now,
+            S3Config {
+                endpoint: "http://localhost:9000".to_string(),
+                region: "us-east-1".to_string(),
+                bucket: "test-bucket".to_string(),
+                access_key: "test-key".to_string(),
+                secret_key: "test-secret".to_string(),
+                path_style: Some(true),
+                ..Default::default()
+            },
+            TestParameters::from(&Args::parse_from(["s3-consistency-test", "--config", "config.toml"])),
+            results,
+            statistics,
+        )
+    }
+
+    #[test]
+    fn test_save_sqlite_report_appends_runs() {
+        let db = NamedTempFile::new().unwrap();
+        let report = create_test_report();
+        
+        let first = save_sqlite_report(&report, db.path()).unwrap();
+        let second = save_sqlite_report(&report, db.path()).unwrap();
+        assert_ne!(first, second);
+        
+        let conn = Connection::open(db.path()).unwrap();
+        let runs: i64 = conn.query_row("SELECT COUNT(*) FROM runs", [], |row| row.get(0)).unwrap();
+        let results: i64 = conn.query_row("SELECT COUNT(*) FROM results", [], |row| row.get(0)).unwrap();
+        let failures: i64 = conn
+            .query_row("SELECT COUNT(*) FROM results WHERE success = 0", [], |row| row.get(0))
+            .unwrap();
+        
+        assert_eq!(runs, 2);
+        assert_eq!(results, 4);
+        assert_eq!(failures, 2);
+    }
+    
+    #[test]
+    fn test_save_sqlite_report_labels() {
+        let db = NamedTempFile::new().unwrap();
+        let mut report = create_test_report();
+        report.labels.insert("env".to_string(), "staging".to_string());
+        
+        let run_id = save_sqlite_report(&report, db.path()).unwrap();
+        
+        let conn = Connection::open(db.path()).unwrap();
+        let env: String = conn
+            .query_row("SELECT value FROM run_labels WHERE run_id = ?1 AND key = 'env'", [run_id], |row| row.get(0))
+            .unwrap();
+        assert_eq!(env, "staging");
+    }
+    
+    #[test]
+    fn test_load_durations_by_hour() {
+        let db = NamedTempFile::new().unwrap();
+        let report = create_test_report();
+        save_sqlite_report(&report, db.path()).unwrap();
+        
+        // Only the successful result is returned, under the hour it was uploaded
+        let durations = load_durations_by_hour(db.path()).unwrap();
+        let hour = report.results[0].upload_time.hour();
+        assert_eq!(durations, vec![(hour, 0)]);
+    }
+}
@@ -4,7 +4,39 @@
 //! results and formatting them for display. It handles percentile calculations,
 //! success rate analysis, and comprehensive reporting.
 
-use crate::types::{ConsistencyReport, ConsistencyStatistics, TestResult};
+use std::collections::BTreeMap;
+
+use crate::keys::TEST_KEY_PREFIX;
+use crate::types::{AttemptCohortStatistics, AttemptsBucket, AvailabilitySample, BenchmarkEntry, BenchmarkReport, BreakpointReport, BucketStatistics, ConnectionTiming, ConsistencyReport, ConsistencyStatistics, DeleteListStatistics, DisplayOptions, ErrorCodeCount, ErrorRetryStatistics, HourlyStatistics, ListCompletenessResult, MetadataSkewStatistics, OrganicReadStatistics, OverwriteRaceResult, PollingTimeStatistics, ReadYourWritesStatistics, RecreateStatistics, ReplicationStatistics, ResultOrder, SizeStatistics, TestParameters, TestResult};
+
+/// Minimum number of successful tests before a 95th percentile is reported
+/// 
+/// With fewer samples the "95th percentile" is simply one of the slowest
+/// few values and says little about the tail of the distribution.
+pub const MIN_SAMPLES_P95: usize = 20;
+
+/// Minimum number of successful tests before a 99th percentile is reported
+pub const MIN_SAMPLES_P99: usize = 100;
+
+/// Width of the key column of `--table`
+const TABLE_KEY_WIDTH: usize = 24;
+
+/// Width the error column of `--table` is truncated to
+const TABLE_ERROR_WIDTH: usize = 48;
+
+/// Width of the provider column of the benchmark matrix
+const BENCHMARK_PROVIDER_WIDTH: usize = 24;
+
+/// Largest attempt number of each attempts-to-consistency range
+/// 
+/// Files needing more attempts than the last bound fall into an open-ended
+/// final range.
+const ATTEMPTS_BUCKET_BOUNDS: [u32; 4] = [1, 5, 20, 100];
+
+/// Largest attempt count of each attempt cohort
+/// 
+/// Files needing more attempts than the last bound form the final cohort.
+const ATTEMPT_COHORT_BOUNDS: [u32; 2] = [1, 5];
 
 /// Calculates comprehensive statistics from test results
 /// 
@@ -27,6 +59,23 @@ use crate::types::{ConsistencyReport, ConsistencyStatistics, TestResult};
 /// println!("Success rate: {:.1}%", statistics.success_rate);
 /// ```
 pub fn calculate_statistics(results: &[TestResult]) -> ConsistencyStatistics {
+    // Delete-list times are measured for every deleted file, including failed reads
+    let delete_list = calculate_delete_list_statistics(results);
+    let recreate_after_delete = calculate_recreate_statistics(results);
+    let replication = calculate_replication_statistics(results);
+    let metadata_skew = calculate_metadata_skew_statistics(results);
+    let read_your_writes = calculate_read_your_writes_statistics(results);
+    let organic_reads = calculate_organic_read_statistics(results);
+    let polling_time = calculate_polling_time_statistics(results);
+    let size_mismatch_events = results.iter().map(|r| r.size_mismatch_events as u64).sum();
+    let flapping_tests = results.iter().filter(|r| r.flapping_detected).count();
+    let key_collisions = results.iter().map(|r| r.key_collisions as u64).sum();
+    let attempts_distribution = calculate_attempts_distribution(results);
+    let attempt_cohorts = calculate_attempt_cohorts(results);
+    let error_retries = calculate_error_retries(results);
+    let error_codes = calculate_error_codes(results);
+    let (sla_breaches, sla_compliance_rate) = calculate_sla_compliance(results);
+    
     if results.is_empty() {
         return ConsistencyStatistics {
             successful_tests: 0,
@@ -38,6 +87,32 @@ pub fn calculate_statistics(results: &[TestResult]) -> ConsistencyStatistics {
             median_propagation_time_ms: None,
             percentile_95_ms: None,
             percentile_99_ms: None,
+            min_propagation_time_us: None,
+            max_propagation_time_us: None,
+            avg_propagation_time_us: None,
+            median_propagation_time_us: None,
+            percentile_95_us: None,
+            percentile_99_us: None,
+            mean_ci_width_ms: None,
+            outlier_threshold_ms: None,
+            outlier_count: 0,
+            size_mismatch_events,
+            flapping_tests,
+            excluded_results: 0,
+            key_collisions,
+            delete_list,
+            recreate_after_delete,
+            replication,
+            metadata_skew,
+            read_your_writes,
+            organic_reads,
+            polling_time,
+            sla_breaches,
+            sla_compliance_rate,
+            attempts_distribution,
+            attempt_cohorts,
+            error_retries,
+            error_codes,
         };
     }
     
@@ -63,6 +138,32 @@ pub fn calculate_statistics(results: &[TestResult]) -> ConsistencyStatistics {
             median_propagation_time_ms: None,
             percentile_95_ms: None,
             percentile_99_ms: None,
+            min_propagation_time_us: None,
+            max_propagation_time_us: None,
+            avg_propagation_time_us: None,
+            median_propagation_time_us: None,
+            percentile_95_us: None,
+            percentile_99_us: None,
+            mean_ci_width_ms: None,
+            outlier_threshold_ms: None,
+            outlier_count: 0,
+            size_mismatch_events,
+            flapping_tests,
+            excluded_results: 0,
+            key_collisions,
+            delete_list,
+            recreate_after_delete,
+            replication,
+            metadata_skew,
+            read_your_writes,
+            organic_reads,
+            polling_time,
+            sla_breaches,
+            sla_compliance_rate,
+            attempts_distribution,
+            attempt_cohorts,
+            error_retries,
+            error_codes,
         };
     }
     
@@ -83,9 +184,35 @@ pub fn calculate_statistics(results: &[TestResult]) -> ConsistencyStatistics {
     // Calculate median
     let median_propagation_time_ms = calculate_median(&durations);
     
-    // Calculate percentiles
-    let percentile_95_ms = calculate_percentile(&durations, 95.0);
-    let percentile_99_ms = calculate_percentile(&durations, 99.0);
+    // Calculate percentiles, suppressing those the sample is too small for
+    let percentile_95_ms = calculate_sampled_percentile(&durations, 95.0, MIN_SAMPLES_P95);
+    let percentile_99_ms = calculate_sampled_percentile(&durations, 99.0, MIN_SAMPLES_P99);
+    
+    // Repeat the timing statistics at microsecond resolution, so sub-millisecond
+    // propagation does not collapse to zero
+    let mut durations_us: Vec<u64> = successful_results
+        .iter()
+        .map(|r| r.propagation_duration_us.unwrap_or_else(|| r.propagation_duration_ms.unwrap() * 1000))
+        .collect();
+    durations_us.sort_unstable();
+    
+    let min_propagation_time_us = durations_us.first().copied();
+    let max_propagation_time_us = durations_us.last().copied();
+    let avg_propagation_time_us = Some(
+        durations_us.iter().sum::<u64>() as f64 / durations_us.len() as f64
+    );
+    let median_propagation_time_us = calculate_median(&durations_us);
+    let percentile_95_us = calculate_sampled_percentile(&durations_us, 95.0, MIN_SAMPLES_P95);
+    let percentile_99_us = calculate_sampled_percentile(&durations_us, 99.0, MIN_SAMPLES_P99);
+    
+    // Calculate the precision of the average
+    let mean_ci_width_ms = calculate_mean_ci_width(&durations);
+    
+    // Detect outliers using the upper Tukey fence
+    let outlier_threshold_ms = calculate_outlier_threshold(&durations);
+    let outlier_count = outlier_threshold_ms
+        .map(|threshold| durations.iter().filter(|&&d| d > threshold).count())
+        .unwrap_or(0);
     
     ConsistencyStatistics {
         successful_tests,
@@ -97,240 +224,2305 @@ pub fn calculate_statistics(results: &[TestResult]) -> ConsistencyStatistics {
         median_propagation_time_ms,
         percentile_95_ms,
         percentile_99_ms,
+        min_propagation_time_us,
+        max_propagation_time_us,
+        avg_propagation_time_us,
+        median_propagation_time_us,
+        percentile_95_us,
+        percentile_99_us,
+        mean_ci_width_ms,
+        outlier_threshold_ms,
+        outlier_count,
+        size_mismatch_events,
+        flapping_tests,
+        excluded_results: 0,
+        key_collisions,
+        delete_list,
+        recreate_after_delete,
+        replication,
+        metadata_skew,
+        read_your_writes,
+        organic_reads,
+        polling_time,
+        sla_breaches,
+        sla_compliance_rate,
+        attempts_distribution,
+        attempt_cohorts,
+        error_retries,
+        error_codes,
     }
 }
 
-/// Calculates the median value from a sorted vector of durations
+/// Calculates statistics from the results within a propagation time range
 /// 
-/// For even-length vectors, returns the average of the two middle values.
-/// For odd-length vectors, returns the exact middle value.
+/// Implements `--stats-min-ms` and `--stats-max-ms`. Successful results
+/// whose propagation time falls outside the range are left out before
+/// calculating the statistics, and counted in `excluded_results`. Failed
+/// results have no propagation time and are always included.
+/// 
+/// Unlike outlier detection, which derives its threshold from the data and
+/// only flags results, this applies a fixed range chosen by the user and
+/// changes which results the statistics describe.
 /// 
 /// # Arguments
 /// 
-/// * `sorted_durations` - A sorted vector of duration values
+/// * `results` - Test results to analyze
+/// * `min_ms` - Shortest propagation time to include, if any
+/// * `max_ms` - Longest propagation time to include, if any
 /// 
 /// # Returns
 /// 
-/// The median value, or `None` if the vector is empty
+/// Statistics for the results within the range
 /// 
 /// # Examples
 /// 
 /// ```rust
-/// let durations = vec![100, 200, 300, 400, 500];
-/// let median = calculate_median(&durations); // Some(300)
+/// let statistics = calculate_filtered_statistics(&results, None, Some(60_000));
+/// println!("Excluded {} results", statistics.excluded_results);
 /// ```
-fn calculate_median(sorted_durations: &[u64]) -> Option<u64> {
-    if sorted_durations.is_empty() {
-        return None;
+pub fn calculate_filtered_statistics(results: &[TestResult], min_ms: Option<u64>, max_ms: Option<u64>) -> ConsistencyStatistics {
+    if min_ms.is_none() && max_ms.is_none() {
+        return calculate_statistics(results);
     }
     
-    let len = sorted_durations.len();
-    if len % 2 == 0 {
-        // Even number of elements - average the two middle values
-        let mid1 = sorted_durations[len / 2 - 1];
-        let mid2 = sorted_durations[len / 2];
-        Some((mid1 + mid2) / 2)
-    } else {
-        // Odd number of elements - take the middle value
-        Some(sorted_durations[len / 2])
+    let (included, excluded): (Vec<TestResult>, Vec<TestResult>) = results.iter()
+        .cloned()
+        .partition(|r| r.propagation_duration_ms.is_none_or(|duration| {
+            min_ms.is_none_or(|min| duration >= min) && max_ms.is_none_or(|max| duration <= max)
+        }));
+    
+    let mut statistics = calculate_statistics(&included);
+    statistics.excluded_results = excluded.len();
+    statistics
+}
+
+/// Calculates statistics for delete-to-listing propagation times
+/// 
+/// # Arguments
+/// 
+/// * `results` - Test results, some of which may carry a delete-list measurement
+/// 
+/// # Returns
+/// 
+/// The statistics, or `None` if no result has a delete-list measurement
+fn calculate_delete_list_statistics(results: &[TestResult]) -> Option<DeleteListStatistics> {
+    let mut durations: Vec<u64> = results
+        .iter()
+        .filter_map(|r| r.delete_list_propagation_duration_ms)
+        .collect();
+    durations.sort_unstable();
+    
+    Some(DeleteListStatistics {
+        measured_count: durations.len(),
+        min_ms: *durations.first()?,
+        max_ms: *durations.last()?,
+        avg_ms: durations.iter().sum::<u64>() as f64 / durations.len() as f64,
+        median_ms: calculate_median(&durations)?,
+        percentile_95_ms: calculate_sampled_percentile(&durations, 95.0, MIN_SAMPLES_P95),
+    })
+}
+
+/// Calculates statistics for how long deleted keys took to become readable again
+/// 
+/// # Arguments
+/// 
+/// * `results` - Test results, some of which may carry a re-create measurement
+/// 
+/// # Returns
+/// 
+/// The statistics, or `None` if no file was deleted and re-created
+fn calculate_recreate_statistics(results: &[TestResult]) -> Option<RecreateStatistics> {
+    let failed_count = results.iter().filter(|r| r.recreate_error.is_some()).count();
+    let mut durations: Vec<u64> = results
+        .iter()
+        .filter_map(|r| r.recreate_after_delete_propagation_ms)
+        .collect();
+    if durations.is_empty() && failed_count == 0 {
+        return None;
     }
+    durations.sort_unstable();
+    
+    Some(RecreateStatistics {
+        measured_count: durations.len(),
+        failed_count,
+        min_ms: durations.first().copied(),
+        max_ms: durations.last().copied(),
+        avg_ms: (!durations.is_empty()).then(|| durations.iter().sum::<u64>() as f64 / durations.len() as f64),
+        median_ms: calculate_median(&durations),
+        percentile_95_ms: calculate_sampled_percentile(&durations, 95.0, MIN_SAMPLES_P95),
+        stale_tombstone_files: results.iter().filter(|r| r.stale_tombstone_reads > 0).count(),
+        stale_tombstone_reads: results.iter().map(|r| r.stale_tombstone_reads as u64).sum(),
+    })
 }
 
-/// Calculates a specific percentile from a sorted vector of durations
+/// Calculates statistics for how long files took to reach the replication target
 /// 
-/// Uses the nearest-rank method for percentile calculation.
-/// For example, the 95th percentile is the value below which 95% of the data falls.
+/// # Arguments
+/// 
+/// * `results` - Test results, some of which may carry a replication measurement
+/// 
+/// # Returns
+/// 
+/// The statistics, or `None` if no result has a replication measurement
+fn calculate_replication_statistics(results: &[TestResult]) -> Option<ReplicationStatistics> {
+    let mut durations: Vec<u64> = results
+        .iter()
+        .filter_map(|r| r.replication_propagation_duration_ms)
+        .collect();
+    durations.sort_unstable();
+    
+    Some(ReplicationStatistics {
+        measured_count: durations.len(),
+        min_ms: *durations.first()?,
+        max_ms: *durations.last()?,
+        avg_ms: durations.iter().sum::<u64>() as f64 / durations.len() as f64,
+        median_ms: calculate_median(&durations)?,
+        percentile_95_ms: calculate_sampled_percentile(&durations, 95.0, MIN_SAMPLES_P95),
+    })
+}
+
+/// Calculates statistics for the skew between object body and metadata
 /// 
 /// # Arguments
 /// 
-/// * `sorted_durations` - A sorted vector of duration values
-/// * `percentile` - The percentile to calculate (0.0 to 100.0)
+/// * `results` - Test results, some of which may carry a skew measurement
 /// 
 /// # Returns
 /// 
-/// The percentile value, or `None` if the vector is empty or percentile is invalid
+/// The statistics, or `None` if no result has a skew measurement
+fn calculate_metadata_skew_statistics(results: &[TestResult]) -> Option<MetadataSkewStatistics> {
+    let mut skews: Vec<i64> = results
+        .iter()
+        .filter_map(|r| r.metadata_body_skew_ms)
+        .collect();
+    skews.sort_unstable();
+    
+    let count = skews.len();
+    Some(MetadataSkewStatistics {
+        measured_count: count,
+        metadata_lagged: skews.iter().filter(|&&skew| skew > 0).count(),
+        body_lagged: skews.iter().filter(|&&skew| skew < 0).count(),
+        min_skew_ms: *skews.first()?,
+        max_skew_ms: *skews.last()?,
+        avg_skew_ms: skews.iter().sum::<i64>() as f64 / count as f64,
+        median_skew_ms: (skews[(count - 1) / 2] + skews[count / 2]) / 2,
+    })
+}
+
+/// Calculates how often the writer and the reader saw a new file right away
 /// 
-/// # Examples
+/// # Arguments
 /// 
-/// ```rust
-/// let durations = vec![100, 200, 300, 400, 500];
-/// let p95 = calculate_percentile(&durations, 95.0); // Some(500)
-/// let p50 = calculate_percentile(&durations, 50.0); // Some(300)
-/// ```
-fn calculate_percentile(sorted_durations: &[u64], percentile: f64) -> Option<u64> {
-    if sorted_durations.is_empty() || percentile < 0.0 || percentile > 100.0 {
+/// * `results` - Test results, some of which may carry a writer read-back check
+/// 
+/// # Returns
+/// 
+/// The statistics, or `None` if no result has a writer read-back check
+fn calculate_read_your_writes_statistics(results: &[TestResult]) -> Option<ReadYourWritesStatistics> {
+    let measured: Vec<_> = results
+        .iter()
+        .filter(|r| r.writer_read_your_writes.is_some())
+        .collect();
+    if measured.is_empty() {
         return None;
     }
     
-    let len = sorted_durations.len();
+    let measured_count = measured.len();
+    let writer_immediate_count = measured.iter()
+        .filter(|r| r.writer_read_your_writes == Some(true))
+        .count();
+    let reader_first_attempt_count = measured.iter()
+        .filter(|r| r.success && r.total_attempts == 1)
+        .count();
     
-    // Handle edge cases
-    if percentile == 0.0 {
-        return sorted_durations.first().copied();
-    }
-    if percentile == 100.0 {
-        return sorted_durations.last().copied();
+    Some(ReadYourWritesStatistics {
+        measured_count,
+        writer_immediate_count,
+        writer_immediate_rate: writer_immediate_count as f64 / measured_count as f64 * 100.0,
+        reader_first_attempt_count,
+        reader_first_attempt_rate: reader_first_attempt_count as f64 / measured_count as f64 * 100.0,
+    })
+}
+
+/// Calculates how often simulated organic reads saw inconsistent data
+/// 
+/// # Arguments
+/// 
+/// * `results` - Test results, some of which may carry an organic read
+/// 
+/// # Returns
+/// 
+/// The statistics, or `None` if no result has an organic read
+fn calculate_organic_read_statistics(results: &[TestResult]) -> Option<OrganicReadStatistics> {
+    let simulated_reads = results.iter().filter(|r| r.organic_read_consistent.is_some()).count();
+    if simulated_reads == 0 {
+        return None;
     }
     
-    // Calculate the index using the nearest-rank method
-    let rank = (percentile / 100.0) * (len as f64);
-    let index = (rank.ceil() as usize).saturating_sub(1);
-    
-    // Ensure index is within bounds
-    let index = index.min(len - 1);
+    let inconsistent_reads = results.iter()
+        .filter(|r| r.organic_read_consistent == Some(false))
+        .count();
     
-    sorted_durations.get(index).copied()
+    Some(OrganicReadStatistics {
+        simulated_reads,
+        inconsistent_reads,
+        inconsistent_rate: inconsistent_reads as f64 / simulated_reads as f64 * 100.0,
+    })
 }
 
-/// Prints a comprehensive summary of test results to the console
+/// Calculates how polling time split between sleeping and read requests
 /// 
-/// Displays a formatted report including test configuration, success rates,
-/// timing statistics, and individual test results. The output is designed
-/// to be human-readable and suitable for console display.
+/// The request time of a file is its polling time until the successful
+/// read minus the time it spent sleeping between attempts.
 /// 
 /// # Arguments
 /// 
-/// * `report` - The consistency report to display
+/// * `results` - Test results, some of which may have become readable
 /// 
-/// # Output Format
+/// # Returns
 /// 
-/// The summary includes:
-/// - Test metadata (duration, endpoint, bucket, file count)
-/// - Success/failure statistics with percentages
-/// - Timing statistics (min, max, average, median, percentiles)
-/// - Individual test results with status and timing
+/// The statistics, or `None` if no result recorded its polling time
+fn calculate_polling_time_statistics(results: &[TestResult]) -> Option<PollingTimeStatistics> {
+    let splits: Vec<(u64, u64)> = results.iter()
+        .filter_map(|r| {
+            let elapsed_ms = r.elapsed_ms_at_success?;
+            let sleep_ms = r.cumulative_sleep_ms?;
+            Some((sleep_ms, elapsed_ms.saturating_sub(sleep_ms)))
+        })
+        .collect();
+    if splits.is_empty() {
+        return None;
+    }
+    
+    let sleep_ms = splits.iter().map(|(sleep_ms, _)| sleep_ms).sum();
+    let request_ms = splits.iter().map(|(_, request_ms)| request_ms).sum();
+    Some(PollingTimeStatistics {
+        measured_files: splits.len(),
+        sleep_ms,
+        request_ms,
+        sleep_percent: sleep_percent(sleep_ms, request_ms),
+    })
+}
+
+/// Calculates the percentage of polling time spent sleeping
 /// 
-/// # Examples
+/// # Arguments
 /// 
-/// ```rust
-/// print_summary(&consistency_report);
-/// ```
-pub fn print_summary(report: &ConsistencyReport) {
-    println!("\n{}", "=".repeat(50));
-    println!("           S3 CONSISTENCY TEST SUMMARY");
-    println!("{}", "=".repeat(50));
-    
-    // Test metadata
-    println!("Test Duration: {}ms", report.total_duration_ms);
-    println!("S3 Endpoint: {}", report.config.endpoint);
-    println!("Bucket: {}", report.config.bucket);
-    println!("Files Tested: {}", report.test_parameters.test_count);
-    println!("File Size: {} bytes", report.test_parameters.file_size);
-    println!("Max Wait Time: {}s", report.test_parameters.max_wait_seconds);
-    println!("Check Interval: {}ms", report.test_parameters.check_interval_ms);
-    
-    println!("\n{}", "-".repeat(30));
-    println!("RESULTS OVERVIEW");
-    println!("{}", "-".repeat(30));
-    
-    let stats = &report.statistics;
-    let total_tests = stats.successful_tests + stats.failed_tests;
-    
-    println!("✅ Successful Tests: {} ({:.1}%)", 
-             stats.successful_tests, stats.success_rate);
-    println!("❌ Failed Tests: {} ({:.1}%)", 
-             stats.failed_tests, 
-             100.0 - stats.success_rate);
-    println!("📊 Total Tests: {}", total_tests);
+/// * `sleep_ms` - Total time spent sleeping
+/// * `request_ms` - Total time spent in read requests
+/// 
+/// # Returns
+/// 
+/// The sleep share (0.0 to 100.0), or 0.0 if no time was recorded
+fn sleep_percent(sleep_ms: u64, request_ms: u64) -> f64 {
+    let total_ms = sleep_ms + request_ms;
+    if total_ms == 0 {
+        return 0.0;
+    }
+    sleep_ms as f64 / total_ms as f64 * 100.0
+}
+
+/// Counts SLA breaches among the files classified against `--sla-ms`
+/// 
+/// # Arguments
+/// 
+/// * `results` - Test results, some of which may be classified
+/// 
+/// # Returns
+/// 
+/// The number of breaches, and the percentage of classified files that met
+/// the target (`None` if no file was classified)
+fn calculate_sla_compliance(results: &[TestResult]) -> (usize, Option<f64>) {
+    let classified = results.iter().filter(|r| r.sla_met.is_some()).count();
+    let breaches = results.iter().filter(|r| r.sla_met == Some(false)).count();
+    let compliance_rate = (classified > 0)
+        .then(|| (classified - breaches) as f64 / classified as f64 * 100.0);
     
-    // Only show timing statistics if we have successful tests
-    if stats.successful_tests > 0 {
-        println!("\n{}", "-".repeat(30));
-        println!("PROPAGATION TIMING ANALYSIS");
-        println!("{}", "-".repeat(30));
-        
-        if let Some(min) = stats.min_propagation_time_ms {
-            println!("⚡ Fastest: {}ms", min);
-        }
-        
-        if let Some(max) = stats.max_propagation_time_ms {
-            println!("🐌 Slowest: {}ms", max);
-        }
-        
-        if let Some(avg) = stats.avg_propagation_time_ms {
-            println!("📊 Average: {:.1}ms", avg);
-        }
-        
-        if let Some(median) = stats.median_propagation_time_ms {
-            println!("📈 Median: {}ms", median);
-        }
-        
-        // Percentiles section
-        println!("\n📋 Percentiles:");
-        if let Some(p95) = stats.percentile_95_ms {
-            println!("   95th: {}ms (95% of tests completed within this time)", p95);
-        }
-        if let Some(p99) = stats.percentile_99_ms {
-            println!("   99th: {}ms (99% of tests completed within this time)", p99);
-        }
-        
-        // Distribution analysis
-        print_distribution_analysis(stats);
+    (breaches, compliance_rate)
+}
+
+/// Groups successful files by the attempt on which they became consistent
+/// 
+/// Unlike the timing percentiles, this shows whether files are usually
+/// readable on the first attempt with a long tail, or converge gradually,
+/// which helps choose a check interval.
+/// 
+/// # Arguments
+/// 
+/// * `results` - Test results to group
+/// 
+/// # Returns
+/// 
+/// The non-empty attempt ranges in ascending order, or an empty vector if
+/// no test succeeded
+fn calculate_attempts_distribution(results: &[TestResult]) -> Vec<AttemptsBucket> {
+    let attempts: Vec<u32> = results.iter()
+        .filter(|r| r.success)
+        .map(|r| r.total_attempts)
+        .collect();
+    if attempts.is_empty() {
+        return Vec::new();
     }
     
-    // Individual test results
-    println!("\n{}", "-".repeat(30));
-    println!("INDIVIDUAL TEST RESULTS");
-    println!("{}", "-".repeat(30));
+    let mut ranges = Vec::with_capacity(ATTEMPTS_BUCKET_BOUNDS.len() + 1);
+    let mut min_attempts = 1;
+    for max in ATTEMPTS_BUCKET_BOUNDS {
+        ranges.push((min_attempts, Some(max)));
+        min_attempts = max + 1;
+    }
+    ranges.push((min_attempts, None));
     
-    for (i, result) in report.results.iter().enumerate() {
-        print!("Test {:2}: ", i + 1);
-        
-        if result.success {
-            if let Some(duration) = result.propagation_duration_ms {
-                println!("✅ SUCCESS - {}ms ({} attempts)", 
-                         duration, result.total_attempts);
+    ranges.into_iter()
+        .map(|(min, max)| {
+            let count = attempts.iter()
+                .filter(|&&a| a >= min && max.is_none_or(|max| a <= max))
+                .count();
+            AttemptsBucket {
+                min_attempts: min,
+                max_attempts: max,
+                count,
+                percentage: count as f64 / attempts.len() as f64 * 100.0,
+            }
+        })
+        .filter(|bucket| bucket.count > 0)
+        .collect()
+}
+
+/// Tallies failed requests by error category and retry outcome
+/// 
+/// A failed request counts as recovered if the upload or polling it was
+/// part of eventually succeeded, and as permanent otherwise. Comparing the
+/// two per category separates backend flakiness from hard failures.
+/// 
+/// # Arguments
+/// 
+/// * `results` - Test results carrying their failed requests
+/// 
+/// # Returns
+/// 
+/// One entry per category seen, most frequent first
+fn calculate_error_retries(results: &[TestResult]) -> Vec<ErrorRetryStatistics> {
+    let mut categories: BTreeMap<&str, ErrorRetryStatistics> = BTreeMap::new();
+    for result in results {
+        let outcomes = result.recovered_errors.iter().map(|(category, &count)| (category, count, true))
+            .chain(result.permanent_errors.iter().map(|(category, &count)| (category, count, false)));
+        for (category, count, recovered) in outcomes {
+            let entry = categories.entry(category).or_insert_with(|| ErrorRetryStatistics {
+                category: category.clone(),
+                occurrences: 0,
+                recovered: 0,
+                permanent: 0,
+            });
+            entry.occurrences += count as u64;
+            if recovered {
+                entry.recovered += count as u64;
             } else {
-                println!("✅ SUCCESS - immediate");
+                entry.permanent += count as u64;
             }
-        } else {
-            let error_msg = result.error_details
-                .as_deref()
-                .unwrap_or("Unknown error");
-            println!("❌ FAILED - {}", error_msg);
         }
     }
     
-    println!("\n{}", "=".repeat(50));
-    println!("Report saved to: consistency-report-{}.json", 
-             report.test_start_time.format("%Y%m%d-%H%M%S"));
-    println!("{}", "=".repeat(50));
+    sort_error_retries(categories.into_values().collect())
 }
 
-/// Provides additional analysis of the timing distribution
+/// Orders error categories by number of occurrences, most frequent first
 /// 
-/// Analyzes the consistency statistics to provide insights about
-/// the distribution of propagation times and potential performance characteristics.
+/// Ties keep their existing order, so categories sorted by name stay that way.
+fn sort_error_retries(mut categories: Vec<ErrorRetryStatistics>) -> Vec<ErrorRetryStatistics> {
+    categories.sort_by(|a, b| b.occurrences.cmp(&a.occurrences));
+    categories
+}
+
+/// Counts files by the S3 error code of their last failed request
 /// 
 /// # Arguments
 /// 
-/// * `stats` - The consistency statistics to analyze
-fn print_distribution_analysis(stats: &ConsistencyStatistics) {
-    println!("\n🔍 Distribution Analysis:");
+/// * `results` - Test results carrying their last error code
+/// 
+/// # Returns
+/// 
+/// One entry per error code seen, most frequent first
+fn calculate_error_codes(results: &[TestResult]) -> Vec<ErrorCodeCount> {
+    let mut codes: BTreeMap<&str, usize> = BTreeMap::new();
+    for code in results.iter().filter_map(|r| r.last_error_code.as_deref()) {
+        *codes.entry(code).or_default() += 1;
+    }
     
-    if let (Some(min), Some(max), Some(avg)) = (
-        stats.min_propagation_time_ms,
-        stats.max_propagation_time_ms,
-        stats.avg_propagation_time_ms,
-    ) {
-        let range = max - min;
-        let variance_indicator = if range > (avg * 2.0) as u64 {
-            "High variance - inconsistent propagation times"
-        } else if range > avg as u64 {
-            "Moderate variance - some variation in propagation times"
-        } else {
-            "Low variance - consistent propagation times"
-        };
-        
-        println!("   Range: {}ms ({}ms to {}ms)", range, min, max);
-        println!("   Consistency: {}", variance_indicator);
-        
-        // Performance assessment
-        if avg < 100.0 {
-            println!("   Performance: ⚡ Excellent (< 100ms average)");
-        } else if avg < 500.0 {
-            println!("   Performance: ✅ Good (< 500ms average)");
-        } else if avg < 2000.0 {
+    sort_error_codes(codes.into_iter().map(|(code, files)| ErrorCodeCount { code: code.to_string(), files }).collect())
+}
+
+/// Orders error codes by number of files, most frequent first
+/// 
+/// Ties keep their existing order, so codes sorted by name stay that way.
+fn sort_error_codes(mut codes: Vec<ErrorCodeCount>) -> Vec<ErrorCodeCount> {
+    codes.sort_by(|a, b| b.files.cmp(&a.files));
+    codes
+}
+
+/// Calculates propagation times for cohorts of files by attempts needed
+/// 
+/// Files consistent on the first attempt may have been fast by luck of
+/// timing, while files needing many attempts waited through real
+/// propagation. Comparing the cohorts shows whether propagation time
+/// grows with the attempt count or is independent of it.
+/// 
+/// # Arguments
+/// 
+/// * `results` - Test results to group
+/// 
+/// # Returns
+/// 
+/// The non-empty cohorts in ascending order of attempts, or an empty
+/// vector if no test succeeded
+fn calculate_attempt_cohorts(results: &[TestResult]) -> Vec<AttemptCohortStatistics> {
+    let mut cohorts = Vec::with_capacity(ATTEMPT_COHORT_BOUNDS.len() + 1);
+    let mut min_attempts = 1;
+    for max in ATTEMPT_COHORT_BOUNDS {
+        cohorts.push((min_attempts, Some(max)));
+        min_attempts = max + 1;
+    }
+    cohorts.push((min_attempts, None));
+    
+    cohorts.into_iter()
+        .filter_map(|(min, max)| {
+            let mut durations: Vec<u64> = results.iter()
+                .filter(|r| r.success && r.total_attempts >= min && max.is_none_or(|max| r.total_attempts <= max))
+                .filter_map(|r| r.propagation_duration_ms)
+                .collect();
+            if durations.is_empty() {
+                return None;
+            }
+            durations.sort_unstable();
+            
+            Some(AttemptCohortStatistics {
+                min_attempts: min,
+                max_attempts: max,
+                count: durations.len(),
+                avg_propagation_time_ms: durations.iter().sum::<u64>() as f64 / durations.len() as f64,
+                percentile_95_ms: calculate_sampled_percentile(&durations, 95.0, MIN_SAMPLES_P95),
+            })
+        })
+        .collect()
+}
+
+/// Combines the statistics of two sets of results into one
+/// 
+/// Every quantity is weighted by the number of samples behind it, so runs
+/// with different file counts are combined correctly: averages are weighted
+/// means over the successful tests, and rates are recalculated from the
+/// summed counts. When the raw results of both sets are still available,
+/// `calculate_statistics` on the pooled results is preferable, since it is
+/// exact for every field.
+/// 
+/// Order statistics (median, percentiles), the confidence interval width and
+/// the outlier threshold cannot be recovered from two summaries. They are
+/// kept when only one side has successful tests and are `None` otherwise.
+/// Likewise, delete-list statistics are only kept if just one side measured
+/// them. Outlier counts are summed, each counted against its own threshold.
+/// 
+/// # Arguments
+/// 
+/// * `existing` - Statistics accumulated so far
+/// * `new` - Statistics of the results to add
+/// 
+/// # Returns
+/// 
+/// The combined statistics
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// let combined = merge_statistics(&calculate_statistics(&first), &calculate_statistics(&second));
+/// assert_eq!(combined.successful_tests, first_successes + second_successes);
+/// ```
+pub fn merge_statistics(existing: &ConsistencyStatistics, new: &ConsistencyStatistics) -> ConsistencyStatistics {
+    let successful_tests = existing.successful_tests + new.successful_tests;
+    let failed_tests = existing.failed_tests + new.failed_tests;
+    let total_tests = successful_tests + failed_tests;
+    let success_rate = if total_tests > 0 {
+        successful_tests as f64 / total_tests as f64 * 100.0
+    } else {
+        0.0
+    };
+    
+    // Order statistics only survive if one side has no samples to merge
+    let (existing_n, new_n) = (existing.successful_tests, new.successful_tests);
+    let order_statistic = |a: Option<u64>, b: Option<u64>| match (existing_n, new_n) {
+        (_, 0) => a,
+        (0, _) => b,
+        _ => None,
+    };
+    let weighted_mean = |a: Option<f64>, b: Option<f64>| match (a, b) {
+        (Some(a), Some(b)) => Some((a * existing_n as f64 + b * new_n as f64) / (existing_n + new_n) as f64),
+        (a, b) => a.or(b),
+    };
+    
+    // Weight compliance by test count, since only breaches are counted per file
+    let sla_compliance_rate = match (existing.sla_compliance_rate, new.sla_compliance_rate) {
+        (Some(a), Some(b)) => {
+            let existing_total = (existing.successful_tests + existing.failed_tests) as f64;
+            let new_total = (new.successful_tests + new.failed_tests) as f64;
+            Some((a * existing_total + b * new_total) / (existing_total + new_total))
+        }
+        (a, b) => a.or(b),
+    };
+    
+    ConsistencyStatistics {
+        successful_tests,
+        failed_tests,
+        success_rate,
+        min_propagation_time_ms: merge_option(existing.min_propagation_time_ms, new.min_propagation_time_ms, u64::min),
+        max_propagation_time_ms: merge_option(existing.max_propagation_time_ms, new.max_propagation_time_ms, u64::max),
+        avg_propagation_time_ms: weighted_mean(existing.avg_propagation_time_ms, new.avg_propagation_time_ms),
+        median_propagation_time_ms: order_statistic(existing.median_propagation_time_ms, new.median_propagation_time_ms),
+        percentile_95_ms: order_statistic(existing.percentile_95_ms, new.percentile_95_ms),
+        percentile_99_ms: order_statistic(existing.percentile_99_ms, new.percentile_99_ms),
+        min_propagation_time_us: merge_option(existing.min_propagation_time_us, new.min_propagation_time_us, u64::min),
+        max_propagation_time_us: merge_option(existing.max_propagation_time_us, new.max_propagation_time_us, u64::max),
+        avg_propagation_time_us: weighted_mean(existing.avg_propagation_time_us, new.avg_propagation_time_us),
+        median_propagation_time_us: order_statistic(existing.median_propagation_time_us, new.median_propagation_time_us),
+        percentile_95_us: order_statistic(existing.percentile_95_us, new.percentile_95_us),
+        percentile_99_us: order_statistic(existing.percentile_99_us, new.percentile_99_us),
+        mean_ci_width_ms: match (existing_n, new_n) {
+            (_, 0) => existing.mean_ci_width_ms,
+            (0, _) => new.mean_ci_width_ms,
+            _ => None,
+        },
+        outlier_threshold_ms: order_statistic(existing.outlier_threshold_ms, new.outlier_threshold_ms),
+        outlier_count: existing.outlier_count + new.outlier_count,
+        size_mismatch_events: existing.size_mismatch_events + new.size_mismatch_events,
+        flapping_tests: existing.flapping_tests + new.flapping_tests,
+        excluded_results: existing.excluded_results + new.excluded_results,
+        key_collisions: existing.key_collisions + new.key_collisions,
+        delete_list: match (&existing.delete_list, &new.delete_list) {
+            (Some(_), Some(_)) => None,
+            (a, b) => a.clone().or_else(|| b.clone()),
+        },
+        recreate_after_delete: match (&existing.recreate_after_delete, &new.recreate_after_delete) {
+            (Some(_), Some(_)) => None,
+            (a, b) => a.clone().or_else(|| b.clone()),
+        },
+        replication: match (&existing.replication, &new.replication) {
+            (Some(_), Some(_)) => None,
+            (a, b) => a.clone().or_else(|| b.clone()),
+        },
+        metadata_skew: match (&existing.metadata_skew, &new.metadata_skew) {
+            (Some(_), Some(_)) => None,
+            (a, b) => a.clone().or_else(|| b.clone()),
+        },
+        read_your_writes: merge_read_your_writes(existing.read_your_writes.as_ref(), new.read_your_writes.as_ref()),
+        organic_reads: merge_organic_reads(existing.organic_reads.as_ref(), new.organic_reads.as_ref()),
+        polling_time: merge_option(existing.polling_time.clone(), new.polling_time.clone(), |a, b| {
+            let sleep_ms = a.sleep_ms + b.sleep_ms;
+            let request_ms = a.request_ms + b.request_ms;
+            PollingTimeStatistics {
+                measured_files: a.measured_files + b.measured_files,
+                sleep_ms,
+                request_ms,
+                sleep_percent: sleep_percent(sleep_ms, request_ms),
+            }
+        }),
+        sla_breaches: existing.sla_breaches + new.sla_breaches,
+        sla_compliance_rate,
+        attempts_distribution: merge_attempts_distribution(
+            &existing.attempts_distribution,
+            &new.attempts_distribution,
+            successful_tests,
+        ),
+        attempt_cohorts: merge_attempt_cohorts(&existing.attempt_cohorts, &new.attempt_cohorts),
+        error_retries: merge_error_retries(&existing.error_retries, &new.error_retries),
+        error_codes: merge_error_codes(&existing.error_codes, &new.error_codes),
+    }
+}
+
+/// Completes merged statistics with order statistics estimated from a sample
+/// 
+/// `merge_statistics` keeps counts, rates, extremes and averages exact but
+/// loses the order statistics. When the results were also sampled uniformly
+/// at random, the sample's median, percentiles and outlier threshold are
+/// unbiased estimates of the whole run's, and its outlier count and
+/// confidence interval width can be scaled to the full number of tests.
+/// 
+/// # Arguments
+/// 
+/// * `totals` - Merged statistics covering every result
+/// * `sample` - Statistics of a uniform random sample of the results
+/// 
+/// # Returns
+/// 
+/// `totals` with the order statistics filled in from `sample`
+pub fn combine_sampled_statistics(totals: &ConsistencyStatistics, sample: &ConsistencyStatistics) -> ConsistencyStatistics {
+    let scale = if sample.successful_tests > 0 {
+        totals.successful_tests as f64 / sample.successful_tests as f64
+    } else {
+        1.0
+    };
+    
+    ConsistencyStatistics {
+        median_propagation_time_ms: sample.median_propagation_time_ms,
+        percentile_95_ms: sample.percentile_95_ms,
+        percentile_99_ms: sample.percentile_99_ms,
+        median_propagation_time_us: sample.median_propagation_time_us,
+        percentile_95_us: sample.percentile_95_us,
+        percentile_99_us: sample.percentile_99_us,
+        // The interval narrows with the square root of the number of tests
+        mean_ci_width_ms: sample.mean_ci_width_ms.map(|width| width / scale.sqrt()),
+        outlier_threshold_ms: sample.outlier_threshold_ms,
+        outlier_count: (sample.outlier_count as f64 * scale).round() as usize,
+        delete_list: totals.delete_list.clone().or_else(|| sample.delete_list.clone()),
+        recreate_after_delete: totals.recreate_after_delete.clone().or_else(|| sample.recreate_after_delete.clone()),
+        replication: totals.replication.clone().or_else(|| sample.replication.clone()),
+        metadata_skew: totals.metadata_skew.clone().or_else(|| sample.metadata_skew.clone()),
+        ..totals.clone()
+    }
+}
+
+/// Combines two optional values, keeping whichever is present
+fn merge_option<T>(a: Option<T>, b: Option<T>, combine: impl FnOnce(T, T) -> T) -> Option<T> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(combine(a, b)),
+        (a, b) => a.or(b),
+    }
+}
+
+/// Combines two read-your-writes comparisons by summing their counts
+/// 
+/// # Arguments
+/// 
+/// * `existing` - The comparison accumulated so far, if any
+/// * `new` - The comparison to add, if any
+/// 
+/// # Returns
+/// 
+/// The combined comparison with rates recalculated, or `None` if neither
+/// side measured any file
+fn merge_read_your_writes(
+    existing: Option<&ReadYourWritesStatistics>,
+    new: Option<&ReadYourWritesStatistics>,
+) -> Option<ReadYourWritesStatistics> {
+    let (existing, new) = match (existing, new) {
+        (Some(existing), Some(new)) => (existing, new),
+        (existing, new) => return existing.or(new).cloned(),
+    };
+    
+    let measured_count = existing.measured_count + new.measured_count;
+    let writer_immediate_count = existing.writer_immediate_count + new.writer_immediate_count;
+    let reader_first_attempt_count = existing.reader_first_attempt_count + new.reader_first_attempt_count;
+    
+    Some(ReadYourWritesStatistics {
+        measured_count,
+        writer_immediate_count,
+        writer_immediate_rate: writer_immediate_count as f64 / measured_count as f64 * 100.0,
+        reader_first_attempt_count,
+        reader_first_attempt_rate: reader_first_attempt_count as f64 / measured_count as f64 * 100.0,
+    })
+}
+
+/// Combines two organic read summaries by summing their counts
+/// 
+/// # Arguments
+/// 
+/// * `existing` - The summary accumulated so far, if any
+/// * `new` - The summary to add, if any
+/// 
+/// # Returns
+/// 
+/// The combined summary with the rate recalculated, or `None` if neither
+/// side simulated any read
+fn merge_organic_reads(
+    existing: Option<&OrganicReadStatistics>,
+    new: Option<&OrganicReadStatistics>,
+) -> Option<OrganicReadStatistics> {
+    let (existing, new) = match (existing, new) {
+        (Some(existing), Some(new)) => (existing, new),
+        (existing, new) => return existing.or(new).cloned(),
+    };
+    
+    let simulated_reads = existing.simulated_reads + new.simulated_reads;
+    let inconsistent_reads = existing.inconsistent_reads + new.inconsistent_reads;
+    
+    Some(OrganicReadStatistics {
+        simulated_reads,
+        inconsistent_reads,
+        inconsistent_rate: inconsistent_reads as f64 / simulated_reads as f64 * 100.0,
+    })
+}
+
+/// Combines two attempts-to-consistency distributions by summing range counts
+/// 
+/// # Arguments
+/// 
+/// * `existing` - The distribution accumulated so far
+/// * `new` - The distribution to add
+/// * `successful_tests` - Combined number of successful tests
+/// 
+/// # Returns
+/// 
+/// The non-empty ranges in ascending order with recalculated percentages
+fn merge_attempts_distribution(
+    existing: &[AttemptsBucket],
+    new: &[AttemptsBucket],
+    successful_tests: usize,
+) -> Vec<AttemptsBucket> {
+    let mut ranges: BTreeMap<u32, AttemptsBucket> = BTreeMap::new();
+    for bucket in existing.iter().chain(new) {
+        ranges.entry(bucket.min_attempts)
+            .or_insert_with(|| AttemptsBucket { count: 0, ..bucket.clone() })
+            .count += bucket.count;
+    }
+    
+    ranges.into_values()
+        .map(|bucket| AttemptsBucket {
+            percentage: bucket.count as f64 / successful_tests as f64 * 100.0,
+            ..bucket
+        })
+        .collect()
+}
+
+/// Combines two error retry tables
+/// 
+/// # Arguments
+/// 
+/// * `existing` - The table accumulated so far
+/// * `new` - The table to add
+/// 
+/// # Returns
+/// 
+/// The combined categories, most frequent first
+fn merge_error_retries(existing: &[ErrorRetryStatistics], new: &[ErrorRetryStatistics]) -> Vec<ErrorRetryStatistics> {
+    let mut categories: BTreeMap<&str, ErrorRetryStatistics> = BTreeMap::new();
+    for category in existing.iter().chain(new) {
+        categories.entry(&category.category)
+            .and_modify(|merged| {
+                merged.occurrences += category.occurrences;
+                merged.recovered += category.recovered;
+                merged.permanent += category.permanent;
+            })
+            .or_insert_with(|| category.clone());
+    }
+    
+    sort_error_retries(categories.into_values().collect())
+}
+
+/// Combines two error code frequency tables
+/// 
+/// # Arguments
+/// 
+/// * `existing` - The table accumulated so far
+/// * `new` - The table to add
+/// 
+/// # Returns
+/// 
+/// The combined codes, most frequent first
+fn merge_error_codes(existing: &[ErrorCodeCount], new: &[ErrorCodeCount]) -> Vec<ErrorCodeCount> {
+    let mut codes: BTreeMap<&str, usize> = BTreeMap::new();
+    for count in existing.iter().chain(new) {
+        *codes.entry(&count.code).or_default() += count.files;
+    }
+    
+    sort_error_codes(codes.into_iter().map(|(code, files)| ErrorCodeCount { code: code.to_string(), files }).collect())
+}
+
+/// Combines two sets of attempt cohorts
+/// 
+/// Averages are weighted by the cohort sizes. As for the overall
+/// percentiles, a cohort's p95 is only kept if just one side had files in
+/// that cohort.
+/// 
+/// # Arguments
+/// 
+/// * `existing` - The cohorts accumulated so far
+/// * `new` - The cohorts to add
+/// 
+/// # Returns
+/// 
+/// The non-empty cohorts in ascending order of attempts
+fn merge_attempt_cohorts(
+    existing: &[AttemptCohortStatistics],
+    new: &[AttemptCohortStatistics],
+) -> Vec<AttemptCohortStatistics> {
+    let mut cohorts: BTreeMap<u32, AttemptCohortStatistics> = BTreeMap::new();
+    for cohort in existing.iter().chain(new) {
+        cohorts.entry(cohort.min_attempts)
+            .and_modify(|merged| {
+                let count = merged.count + cohort.count;
+                merged.avg_propagation_time_ms = (merged.avg_propagation_time_ms * merged.count as f64
+                    + cohort.avg_propagation_time_ms * cohort.count as f64) / count as f64;
+                merged.percentile_95_ms = None;
+                merged.count = count;
+            })
+            .or_insert_with(|| cohort.clone());
+    }
+    
+    cohorts.into_values().collect()
+}
+
+/// Calculates propagation statistics for each hour of the day
+/// 
+/// # Arguments
+/// 
+/// * `samples` - (hour of day, propagation time in milliseconds) pairs
+/// 
+/// # Returns
+/// 
+/// Statistics for each hour that has at least one sample, in hour order
+pub fn calculate_hourly_statistics(samples: &[(u32, u64)]) -> Vec<HourlyStatistics> {
+    let mut by_hour: BTreeMap<u32, Vec<u64>> = BTreeMap::new();
+    for &(hour, duration) in samples {
+        by_hour.entry(hour).or_default().push(duration);
+    }
+    
+    by_hour.into_iter()
+        .map(|(hour, mut durations)| {
+            durations.sort_unstable();
+            HourlyStatistics {
+                hour,
+                successful_tests: durations.len(),
+                avg_propagation_time_ms: durations.iter().sum::<u64>() as f64 / durations.len() as f64,
+                percentile_95_ms: calculate_sampled_percentile(&durations, 95.0, MIN_SAMPLES_P95),
+            }
+        })
+        .collect()
+}
+
+/// Calculates propagation statistics for each file size
+/// 
+/// # Arguments
+/// 
+/// * `results` - Test results; results without a recorded file size are skipped
+/// 
+/// # Returns
+/// 
+/// Statistics for each file size that was tested, in size order
+pub fn calculate_size_statistics(results: &[TestResult]) -> Vec<SizeStatistics> {
+    let mut by_size: BTreeMap<usize, Vec<&TestResult>> = BTreeMap::new();
+    for result in results {
+        if let Some(size) = result.file_size {
+            by_size.entry(size).or_default().push(result);
+        }
+    }
+    
+    by_size.into_iter()
+        .map(|(file_size, results)| {
+            let mut durations: Vec<u64> = results.iter()
+                .filter(|r| r.success)
+                .filter_map(|r| r.propagation_duration_ms)
+                .collect();
+            durations.sort_unstable();
+            SizeStatistics {
+                file_size,
+                total_tests: results.len(),
+                successful_tests: durations.len(),
+                avg_propagation_time_ms: (!durations.is_empty())
+                    .then(|| durations.iter().sum::<u64>() as f64 / durations.len() as f64),
+                percentile_95_ms: calculate_sampled_percentile(&durations, 95.0, MIN_SAMPLES_P95),
+            }
+        })
+        .collect()
+}
+
+/// Calculates propagation statistics for each bucket
+/// 
+/// # Arguments
+/// 
+/// * `results` - Test results; results without a recorded bucket are skipped
+/// 
+/// # Returns
+/// 
+/// Statistics for each bucket files were uploaded to, in bucket name order
+pub fn calculate_bucket_statistics(results: &[TestResult]) -> Vec<BucketStatistics> {
+    let mut by_bucket: BTreeMap<&str, Vec<&TestResult>> = BTreeMap::new();
+    for result in results {
+        if let Some(bucket) = &result.bucket {
+            by_bucket.entry(bucket).or_default().push(result);
+        }
+    }
+    
+    by_bucket.into_iter()
+        .map(|(bucket, results)| {
+            let mut durations: Vec<u64> = results.iter()
+                .filter(|r| r.success)
+                .filter_map(|r| r.propagation_duration_ms)
+                .collect();
+            durations.sort_unstable();
+            BucketStatistics {
+                bucket: bucket.to_string(),
+                total_tests: results.len(),
+                successful_tests: durations.len(),
+                avg_propagation_time_ms: (!durations.is_empty())
+                    .then(|| durations.iter().sum::<u64>() as f64 / durations.len() as f64),
+                percentile_95_ms: calculate_sampled_percentile(&durations, 95.0, MIN_SAMPLES_P95),
+                max_propagation_time_ms: durations.last().copied(),
+            }
+        })
+        .collect()
+}
+
+/// Flags individual results whose propagation time is an outlier
+/// 
+/// Marks each successful result whose propagation time exceeds the
+/// `outlier_threshold_ms` of the given statistics by setting `is_outlier`.
+/// 
+/// # Arguments
+/// 
+/// * `results` - The test results to flag
+/// * `statistics` - Statistics previously calculated from the same results
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// let statistics = calculate_statistics(&results);
+/// flag_outliers(&mut results, &statistics);
+/// ```
+pub fn flag_outliers(results: &mut [TestResult], statistics: &ConsistencyStatistics) {
+    let Some(threshold) = statistics.outlier_threshold_ms else {
+        return;
+    };
+    
+    for result in results.iter_mut() {
+        result.is_outlier = result.success
+            && result.propagation_duration_ms.is_some_and(|d| d > threshold);
+    }
+}
+
+/// Selects the slowest successful files
+/// 
+/// # Arguments
+/// 
+/// * `results` - Test results to select from
+/// * `count` - Maximum number of files to return
+/// 
+/// # Returns
+/// 
+/// Up to `count` successful results, slowest first
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// for result in slowest_results(&report.results, 5) {
+///     println!("{}", result.file_key);
+/// }
+/// ```
+fn slowest_results(results: &[TestResult], count: usize) -> Vec<&TestResult> {
+    let mut successful: Vec<&TestResult> = results.iter()
+        .filter(|r| r.success && r.propagation_duration_us.is_some())
+        .collect();
+    
+    successful.sort_by(|a, b| b.propagation_duration_us.cmp(&a.propagation_duration_us));
+    successful.truncate(count);
+    successful
+}
+
+/// Calculates the upper Tukey fence from a sorted vector of durations
+/// 
+/// The fence is `Q3 + 1.5 × IQR` where `IQR = Q3 - Q1`. Values above the
+/// fence are considered outliers.
+/// 
+/// # Arguments
+/// 
+/// * `sorted_durations` - A sorted vector of duration values
+/// 
+/// # Returns
+/// 
+/// The upper fence, or `None` if the vector is empty
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// let durations = vec![100, 110, 120, 130, 1000];
+/// let threshold = calculate_outlier_threshold(&durations); // Some(160)
+/// ```
+fn calculate_outlier_threshold(sorted_durations: &[u64]) -> Option<u64> {
+    let q1 = calculate_percentile(sorted_durations, 25.0)?;
+    let q3 = calculate_percentile(sorted_durations, 75.0)?;
+    let iqr = q3 - q1;
+    
+    Some(q3 + iqr * 3 / 2)
+}
+
+/// Calculates the width of the 95% confidence interval on the mean duration
+/// 
+/// Uses the normal approximation `2 × 1.96 × s / √n`, where `s` is the
+/// sample standard deviation.
+/// 
+/// # Arguments
+/// 
+/// * `durations` - The duration values
+/// 
+/// # Returns
+/// 
+/// The interval width, or `None` if there are fewer than two values
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// let durations = vec![100, 200, 300, 400];
+/// let width = calculate_mean_ci_width(&durations); // Some(253.0...)
+/// ```
+fn calculate_mean_ci_width(durations: &[u64]) -> Option<f64> {
+    if durations.len() < 2 {
+        return None;
+    }
+    
+    let n = durations.len() as f64;
+    let mean = durations.iter().sum::<u64>() as f64 / n;
+    let variance = durations.iter()
+        .map(|&d| (d as f64 - mean).powi(2))
+        .sum::<f64>() / (n - 1.0);
+    
+    Some(2.0 * 1.96 * variance.sqrt() / n.sqrt())
+}
+
+/// Calculates the median value from a sorted vector of durations
+/// 
+/// For even-length vectors, returns the average of the two middle values.
+/// For odd-length vectors, returns the exact middle value.
+/// 
+/// # Arguments
+/// 
+/// * `sorted_durations` - A sorted vector of duration values
+/// 
+/// # Returns
+/// 
+/// The median value, or `None` if the vector is empty
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// let durations = vec![100, 200, 300, 400, 500];
+/// let median = calculate_median(&durations); // Some(300)
+/// ```
+fn calculate_median(sorted_durations: &[u64]) -> Option<u64> {
+    if sorted_durations.is_empty() {
+        return None;
+    }
+    
+    let len = sorted_durations.len();
+    if len.is_multiple_of(2) {
+        // Even number of elements - average the two middle values
+        let mid1 = sorted_durations[len / 2 - 1];
+        let mid2 = sorted_durations[len / 2];
+        Some((mid1 + mid2) / 2)
+    } else {
+        // Odd number of elements - take the middle value
+        Some(sorted_durations[len / 2])
+    }
+}
+
+/// Calculates a specific percentile from a sorted vector of durations
+/// 
+/// Uses the nearest-rank method for percentile calculation.
+/// For example, the 95th percentile is the value below which 95% of the data falls.
+/// 
+/// # Arguments
+/// 
+/// * `sorted_durations` - A sorted vector of duration values
+/// * `percentile` - The percentile to calculate (0.0 to 100.0)
+/// 
+/// # Returns
+/// 
+/// The percentile value, or `None` if the vector is empty or percentile is invalid
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// let durations = vec![100, 200, 300, 400, 500];
+/// let p95 = calculate_percentile(&durations, 95.0); // Some(500)
+/// let p50 = calculate_percentile(&durations, 50.0); // Some(300)
+/// ```
+fn calculate_percentile(sorted_durations: &[u64], percentile: f64) -> Option<u64> {
+    if sorted_durations.is_empty() || !(0.0..=100.0).contains(&percentile) {
+        return None;
+    }
+    
+    let len = sorted_durations.len();
+    
+    // Handle edge cases
+    if percentile == 0.0 {
+        return sorted_durations.first().copied();
+    }
+    if percentile == 100.0 {
+        return sorted_durations.last().copied();
+    }
+    
+    // Calculate the index using the nearest-rank method
+    let rank = (percentile / 100.0) * (len as f64);
+    let index = (rank.ceil() as usize).saturating_sub(1);
+    
+    // Ensure index is within bounds
+    let index = index.min(len - 1);
+    
+    sorted_durations.get(index).copied()
+}
+
+/// Calculates a percentile only if there are enough samples to support it
+/// 
+/// # Arguments
+/// 
+/// * `sorted_durations` - A sorted vector of duration values
+/// * `percentile` - The percentile to calculate (0.0 to 100.0)
+/// * `min_samples` - Minimum number of values required
+/// 
+/// # Returns
+/// 
+/// The percentile value, or `None` if there are fewer than `min_samples`
+/// values or the percentile is invalid
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// let durations = vec![100, 200, 300, 400, 500];
+/// let p95 = calculate_sampled_percentile(&durations, 95.0, MIN_SAMPLES_P95); // None
+/// ```
+fn calculate_sampled_percentile(sorted_durations: &[u64], percentile: f64, min_samples: usize) -> Option<u64> {
+    if sorted_durations.len() < min_samples {
+        return None;
+    }
+    
+    calculate_percentile(sorted_durations, percentile)
+}
+
+/// Prints a comprehensive summary of test results to the console
+/// 
+/// Displays a formatted report including test configuration, success rates,
+/// timing statistics, and individual test results. The output is designed
+/// to be human-readable and suitable for console display.
+/// 
+/// # Arguments
+/// 
+/// * `report` - The consistency report to display
+/// * `display` - Time unit and precision used for durations
+/// 
+/// # Output Format
+/// 
+/// The summary includes:
+/// - Test metadata (duration, endpoint, bucket, file count)
+/// - Success/failure statistics with percentages
+/// - Timing statistics (min, max, average, median, percentiles)
+/// - Individual test results with status and timing
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// print_summary(&consistency_report, &DisplayOptions::default());
+/// ```
+pub fn print_summary(report: &ConsistencyReport, display: &DisplayOptions) {
+    println!("\n{}", "=".repeat(50));
+    println!("           S3 CONSISTENCY TEST SUMMARY");
+    println!("{}", "=".repeat(50));
+    
+    // Test metadata
+    println!("Test Duration: {}", display.format_ms(report.total_duration_ms as f64));
+    println!("S3 Endpoint: {}", report.config.endpoint);
+    if let Some(reader_config) = report.config.reader_config() {
+        println!("Read Endpoint: {} ({})", reader_config.endpoint, reader_config.region);
+    }
+    match report.config.bucket_pool().as_slice() {
+        [bucket] => println!("Bucket: {}", bucket),
+        buckets => println!("Buckets: {}", buckets.join(", ")),
+    }
+    if let Some(prefix) = &report.config.prefix {
+        println!("Key Prefix: {}", prefix);
+    }
+    println!("Files Tested: {}", report.test_parameters.test_count);
+    if report.interrupted {
+        println!("⚠️  Stopped early with Ctrl+C; remaining files were skipped");
+    }
+    if let Some(target) = report.test_parameters.target_ci_width_ms {
+        println!("Adaptive Count: until 95% CI width <= {}", display.format_ms(target));
+    }
+    match &report.test_parameters.size_distribution {
+        Some(distribution) => println!("File Sizes: {}", distribution),
+        None => println!("File Size: {} bytes", report.test_parameters.file_size),
+    }
+    if let (Some(threshold), Some(part_size)) = (report.test_parameters.multipart_threshold, report.test_parameters.multipart_part_size) {
+        println!("Multipart: files >= {} bytes in {}-byte parts", threshold, part_size);
+    }
+    if let Some(acl) = &report.config.acl {
+        println!("Upload ACL: {}", acl);
+    }
+    if let Some(tagging) = report.config.tagging() {
+        println!("Upload Tags: {}", tagging);
+    }
+    println!("Max Wait Time: {}s", report.test_parameters.max_wait_seconds);
+    if report.test_parameters.auto_interval {
+        println!("Check Interval: auto");
+    } else {
+        println!("Check Interval: {}ms", report.test_parameters.check_interval_ms);
+    }
+    if report.test_parameters.upload_jitter_ms > 0 {
+        println!("Upload Jitter: up to {}ms", report.test_parameters.upload_jitter_ms);
+    }
+    if let Some(bytes_per_second) = report.test_parameters.max_upload_bps {
+        println!("Upload Bandwidth: at most {} bytes/s", bytes_per_second);
+    }
+    if let Some(threads) = report.test_parameters.worker_threads {
+        println!("Worker Threads: {}", threads);
+    }
+    match (&report.test_parameters.sse, &report.test_parameters.sse_kms_key_id) {
+        (Some(sse), Some(key_id)) => println!("Server-Side Encryption: {} (key {})", sse, key_id),
+        (Some(sse), None) => println!("Server-Side Encryption: {}", sse),
+        (None, _) if report.test_parameters.sse_skipped => {
+            println!("Server-Side Encryption: skipped, not supported by the backend");
+        }
+        (None, _) => {}
+    }
+    if let Some(algorithm) = report.test_parameters.checksum {
+        let mismatches = report.results.iter().filter(|result| result.checksum_verified == Some(false)).count();
+        println!("Checksum: {}, {} mismatched or missing", algorithm, mismatches);
+    }
+    if report.test_parameters.expect_404_first {
+        let anomalous = report.results.iter().filter(|result| result.status_anomaly.is_some()).count();
+        println!("Status Pattern: 404s then the object expected, {} files anomalous", anomalous);
+    }
+    if let Some(total) = report.results_sampled_from {
+        println!("Results: random sample of {} of {} kept (median and percentiles estimated from it)",
+                 report.results.len(), total);
+    }
+    if let (Some(max_in_flight), Some(peak)) = (report.test_parameters.max_in_flight, report.peak_in_flight) {
+        println!("Files in Flight: at most {} (peak {})", max_in_flight, peak);
+    }
+    if !report.labels.is_empty() {
+        let mut labels: Vec<_> = report.labels.iter()
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect();
+        labels.sort();
+        println!("Labels: {}", labels.join(", "));
+    }
+    
+    println!("\n{}", "-".repeat(30));
+    println!("RESULTS OVERVIEW");
+    println!("{}", "-".repeat(30));
+    
+    let stats = &report.statistics;
+    let total_tests = stats.successful_tests + stats.failed_tests;
+    
+    println!("✅ Successful Tests: {} ({:.1}%)", 
+             stats.successful_tests, stats.success_rate);
+    println!("❌ Failed Tests: {} ({:.1}%)", 
+             stats.failed_tests, 
+             100.0 - stats.success_rate);
+    println!("📊 Total Tests: {}", total_tests);
+    if stats.excluded_results > 0 {
+        println!("🚫 Excluded from Statistics: {} (outside --stats-min-ms/--stats-max-ms, kept in results)",
+                 stats.excluded_results);
+    }
+    println!("🧾 S3 Requests: {} ({} uploads, {} reads, {} lists, {} deletes)",
+             report.total_requests, report.requests.uploads, report.requests.reads,
+             report.requests.lists, report.requests.deletes);
+    if let Some(cost) = report.estimated_request_cost {
+        println!("💰 Estimated Request Cost: {:.4}", cost);
+    }
+    if let (Some(target), Some(compliance_rate)) = (report.test_parameters.sla_ms, stats.sla_compliance_rate) {
+        println!("🎯 SLA Compliance (<= {}): {:.1}% ({} breaches)",
+                 display.format_ms(target as f64), compliance_rate, stats.sla_breaches);
+    }
+    if let Some(polling_time) = &stats.polling_time {
+        println!("😴 Polling Time: {:.1}% sleeping ({}), {:.1}% in read requests ({})",
+                 polling_time.sleep_percent, display.format_ms(polling_time.sleep_ms as f64),
+                 100.0 - polling_time.sleep_percent, display.format_ms(polling_time.request_ms as f64));
+    }
+    if stats.size_mismatch_events > 0 {
+        println!("📏 Size Mismatches: {} (reads with a wrong body length, kept polling)",
+                 stats.size_mismatch_events);
+    }
+    if stats.flapping_tests > 0 {
+        println!("🔁 Flapping Files: {} (a streak of --consecutive-reads was broken by a failed read)",
+                 stats.flapping_tests);
+    }
+    if stats.key_collisions > 0 {
+        println!("🔑 Key Collisions: {} (existing objects found before upload, keys regenerated)",
+                 stats.key_collisions);
+    }
+    
+    // Only show timing statistics if we have successful tests
+    if stats.successful_tests > 0 {
+        print_timing_analysis(stats, display);
+        print_attempts_distribution(&stats.attempts_distribution);
+        print_attempt_cohorts(&stats.attempt_cohorts, display);
+        
+        // Outliers
+        if stats.outlier_count > 0 {
+            println!("\n{}", "-".repeat(30));
+            println!("OUTLIERS");
+            println!("{}", "-".repeat(30));
+            
+            if let Some(threshold) = stats.outlier_threshold_ms {
+                println!("⚠️  {} file(s) above the outlier threshold of {}:",
+                         stats.outlier_count, display.format_ms(threshold as f64));
+            }
+            
+            for result in report.results.iter().filter(|r| r.is_outlier) {
+                if let Some(duration) = result.propagation_duration_ms {
+                    println!("   {} - {} ({} attempts)",
+                             result.file_key, display.format_ms(duration as f64), result.total_attempts);
+                }
+            }
+        }
+        
+        // Slowest individual files
+        let slowest = slowest_results(&report.results, display.top_slow);
+        if !slowest.is_empty() {
+            println!("\n{}", "-".repeat(30));
+            println!("TOP {} SLOWEST FILES", slowest.len());
+            println!("{}", "-".repeat(30));
+            
+            for (i, result) in slowest.iter().enumerate() {
+                if let Some(duration) = result.propagation_duration_us {
+                    println!("{:2}. {} - {} ({} attempts)",
+                             i + 1, result.file_key, display.format_us(duration as f64), result.total_attempts);
+                }
+            }
+        }
+    }
+    
+    // Delete-to-listing propagation
+    if let Some(delete_list) = &stats.delete_list {
+        print_delete_list_consistency(delete_list, display);
+    }
+    
+    // Tombstone-to-live transitions
+    if let Some(recreate) = &stats.recreate_after_delete {
+        print_recreate_after_delete(recreate, display);
+    }
+    
+    // Cross-bucket replication lag
+    if let Some(replication) = &stats.replication {
+        print_replication(replication, display);
+    }
+    
+    // Object body versus metadata
+    if let Some(metadata_skew) = &stats.metadata_skew {
+        print_metadata_skew(metadata_skew, display);
+    }
+    
+    // Transient versus permanent request failures
+    if !stats.error_retries.is_empty() {
+        print_error_retries(&stats.error_retries);
+    }
+    
+    // Exact S3 error codes behind the failures
+    if !stats.error_codes.is_empty() {
+        print_error_codes(&stats.error_codes);
+    }
+    
+    // Same-connection vs cross-connection visibility
+    if let Some(read_your_writes) = &stats.read_your_writes {
+        print_read_your_writes(read_your_writes);
+    }
+    
+    // User-visible impact for readers arriving at random times
+    if let Some(organic_reads) = &stats.organic_reads {
+        print_organic_reads(organic_reads, &report.test_parameters, display);
+    }
+    
+    // Bucket listing completeness
+    if let Some(list_result) = &report.list_completeness {
+        print_list_completeness(list_result, display);
+    }
+    
+    // Convergence after concurrent overwrites
+    if let Some(race) = &report.overwrite_race {
+        print_overwrite_race(race, display);
+    }
+    
+    // Backend availability timeline
+    if let Some(timeline) = &report.availability_timeline {
+        print_availability_summary(timeline, display);
+    }
+    
+    // Connection setup timing
+    if let Some(timing) = &report.connection_timing {
+        print_connection_timing(timing, display);
+    }
+    
+    // Propagation by file size
+    if let Some(sizes) = &report.size_breakdown {
+        print_size_breakdown(sizes, display);
+    }
+    
+    // Propagation by bucket
+    if let Some(buckets) = &report.bucket_breakdown {
+        print_bucket_breakdown(buckets, display);
+    }
+    
+    // Individual test results
+    println!("\n{}", "-".repeat(30));
+    if report.test_parameters.failures_only {
+        println!("FAILED TEST RESULTS");
+    } else {
+        println!("INDIVIDUAL TEST RESULTS");
+    }
+    println!("{}", "-".repeat(30));
+    
+    let results = listed_results(&report.results, display.sort_results, report.test_parameters.failures_only);
+    if report.test_parameters.failures_only && results.is_empty() {
+        println!("No failed tests");
+    }
+    
+    if display.table {
+        print_results_table(&results, display);
+    } else {
+        for (i, result) in results {
+            print!("Test {:2}: ", i + 1);
+            
+            if result.success {
+                if let Some(duration) = result.propagation_duration_us {
+                    let outlier_marker = if result.is_outlier { " ⚠️  OUTLIER" } else { "" };
+                    let content_marker = if result.content_self_verified == Some(false) { " 🧬 CONTENT MISMATCH" } else { "" };
+                    let checksum_marker = if result.checksum_verified == Some(false) { " 🔏 CHECKSUM MISMATCH" } else { "" };
+                    println!("✅ SUCCESS - {} ({} attempts){}{}{}{}", 
+                             display.format_us(duration as f64), result.total_attempts,
+                             format_file_retries(result.file_retries), outlier_marker, content_marker, checksum_marker);
+                    if !result.captured_headers.is_empty() {
+                        let mut headers: Vec<String> = result.captured_headers.iter()
+                            .map(|(name, value)| format!("{}: {}", name, value))
+                            .collect();
+                        headers.sort();
+                        println!("          {}", headers.join(", "));
+                    }
+                } else {
+                    println!("✅ SUCCESS - immediate");
+                }
+            } else {
+                let error_msg = result.error_details
+                    .as_deref()
+                    .unwrap_or("Unknown error");
+                println!("❌ FAILED - {}{}", error_msg, format_file_retries(result.file_retries));
+            }
+            if let Some(anomaly) = &result.status_anomaly {
+                println!("          🚦 {} [{}]", anomaly, result.status_sequence.as_deref().unwrap_or_default());
+            }
+        }
+    }
+    
+    println!("\n{}", "=".repeat(50));
+    println!("Report saved to: consistency-report-{}.json", 
+             report.test_start_time.format("%Y%m%d-%H%M%S"));
+    println!("{}", "=".repeat(50));
+}
+
+/// Orders the individual results for display
+/// 
+/// # Arguments
+/// 
+/// * `results` - The results in execution order
+/// * `order` - Order to list them in
+/// 
+/// # Returns
+/// 
+/// Each result with its index in execution order, in the requested order.
+/// Results that compare equal keep their execution order.
+fn sort_results(results: &[TestResult], order: ResultOrder) -> Vec<(usize, &TestResult)> {
+    let mut sorted: Vec<(usize, &TestResult)> = results.iter().enumerate().collect();
+    match order {
+        ResultOrder::Order => {}
+        // Files without a propagation time sort as `None`, after every duration
+        ResultOrder::Duration => sorted.sort_by_key(|(_, r)| {
+            std::cmp::Reverse(r.propagation_duration_us.or(r.propagation_duration_ms.map(|ms| ms * 1000)))
+        }),
+        ResultOrder::Status => sorted.sort_by_key(|(_, r)| r.success),
+    }
+    sorted
+}
+
+/// Selects and orders the results listed individually in the summary
+/// 
+/// With `--failures-only` only this list is limited to failures; the
+/// sections above it, such as the slowest files, still cover every result.
+/// 
+/// # Arguments
+/// 
+/// * `results` - The results in execution order
+/// * `order` - Order to list them in
+/// * `failures_only` - Whether to list failed results only
+/// 
+/// # Returns
+/// 
+/// Each listed result with its index in execution order, in the requested order
+fn listed_results(results: &[TestResult], order: ResultOrder, failures_only: bool) -> Vec<(usize, &TestResult)> {
+    let mut listed = sort_results(results, order);
+    if failures_only {
+        listed.retain(|(_, result)| !result.success);
+    }
+    listed
+}
+
+/// Prints the individual results as an aligned fixed-width table
+/// 
+/// Keys are shown without the shared test key prefix. Keys and errors
+/// longer than their column are truncated with `…`.
+/// 
+/// # Arguments
+/// 
+/// * `results` - The results to list, with their index in execution order
+/// * `display` - Time unit and precision used for durations
+fn print_results_table(results: &[(usize, &TestResult)], display: &DisplayOptions) {
+    if results.is_empty() {
+        return;
+    }
+    
+    println!(
+        "{:>5}  {:<width$}  {:<7}  {:>10}  {:>8}  Error",
+        "Test#", "Key", "Status", "Duration", "Attempts", width = TABLE_KEY_WIDTH
+    );
+    for &(i, result) in results {
+        let key = result.file_key.split_once(TEST_KEY_PREFIX).map_or(result.file_key.as_str(), |(_, key)| key);
+        let duration = result.propagation_duration_us
+            .map_or("-".to_string(), |duration| display.format_us(duration as f64));
+        let error = result.error_details.as_deref().unwrap_or("");
+        println!(
+            "{:>5}  {:<width$}  {:<7}  {:>10}  {:>8}  {}",
+            i + 1,
+            truncate(key, TABLE_KEY_WIDTH),
+            if result.success { "SUCCESS" } else { "FAILED" },
+            duration,
+            result.total_attempts,
+            truncate(error, TABLE_ERROR_WIDTH),
+            width = TABLE_KEY_WIDTH
+        );
+    }
+}
+
+/// Shortens text to at most `width` characters for a table column
+/// 
+/// # Arguments
+/// 
+/// * `text` - The text to shorten
+/// * `width` - Maximum number of characters
+/// 
+/// # Returns
+/// 
+/// The text unchanged if it fits, otherwise its start followed by `…`
+fn truncate(text: &str, width: usize) -> String {
+    if text.chars().count() <= width {
+        return text.to_string();
+    }
+    let mut truncated: String = text.chars().take(width.saturating_sub(1)).collect();
+    truncated.push('…');
+    truncated
+}
+
+/// Formats a file's upload retry count for the individual results
+/// 
+/// # Arguments
+/// 
+/// * `retries` - Number of upload retries the file needed
+/// 
+/// # Returns
+/// 
+/// A suffix such as `" (2 retries)"`, or an empty string if there were none
+fn format_file_retries(retries: u32) -> String {
+    match retries {
+        0 => String::new(),
+        1 => " (1 retry)".to_string(),
+        n => format!(" ({} retries)", n),
+    }
+}
+
+/// Prints the propagation timing section of the summary
+/// 
+/// # Arguments
+/// 
+/// * `stats` - Statistics with at least one successful test
+/// * `display` - Time unit and precision used for durations
+fn print_timing_analysis(stats: &ConsistencyStatistics, display: &DisplayOptions) {
+    println!("\n{}", "-".repeat(30));
+    println!("PROPAGATION TIMING ANALYSIS");
+    println!("{}", "-".repeat(30));
+    
+    if let Some(min) = stats.min_propagation_time_us {
+        println!("⚡ Fastest: {}", display.format_us(min as f64));
+    }
+    
+    if let Some(max) = stats.max_propagation_time_us {
+        println!("🐌 Slowest: {}", display.format_us(max as f64));
+    }
+    
+    if let Some(avg) = stats.avg_propagation_time_us {
+        println!("📊 Average: {}", display.format_us(avg));
+    }
+    
+    if let Some(width) = stats.mean_ci_width_ms {
+        println!("📏 95% CI on Average: ±{} (width {})",
+                 display.format_ms(width / 2.0), display.format_ms(width));
+    }
+    
+    if let Some(median) = stats.median_propagation_time_us {
+        println!("📈 Median: {}", display.format_us(median as f64));
+    }
+    
+    // Percentiles section
+    println!("\n📋 Percentiles:");
+    match stats.percentile_95_us {
+        Some(p95) => println!("   95th: {} (95% of tests completed within this time)", display.format_us(p95 as f64)),
+        None => println!("   95th: n/a (sample too small, needs at least {} successful tests)", MIN_SAMPLES_P95),
+    }
+    match stats.percentile_99_us {
+        Some(p99) => println!("   99th: {} (99% of tests completed within this time)", display.format_us(p99 as f64)),
+        None => println!("   99th: n/a (sample too small, needs at least {} successful tests)", MIN_SAMPLES_P99),
+    }
+    
+    // Distribution analysis
+    print_distribution_analysis(stats, display);
+}
+
+/// Prints a summary of statistics calculated from imported durations
+/// 
+/// Used by the `stats` subcommand, which has no S3 run to describe, so only
+/// the result counts and timing analysis are shown.
+/// 
+/// # Arguments
+/// 
+/// * `stats` - Statistics calculated from the imported durations
+/// * `source` - Where the durations were read from
+/// * `display` - Time unit and precision used for durations
+pub fn print_statistics_summary(stats: &ConsistencyStatistics, source: &str, display: &DisplayOptions) {
+    println!("\n{}", "=".repeat(50));
+    println!("           PROPAGATION STATISTICS");
+    println!("{}", "=".repeat(50));
+    
+    println!("Source: {}", source);
+    println!("📊 Durations: {}", stats.successful_tests);
+    if stats.excluded_results > 0 {
+        println!("🚫 Excluded: {} (outside --stats-min-ms/--stats-max-ms)", stats.excluded_results);
+    }
+    
+    if stats.successful_tests > 0 {
+        print_timing_analysis(stats, display);
+    }
+    
+    println!("{}", "=".repeat(50));
+}
+
+/// Prints propagation times broken down by hour of day
+/// 
+/// # Arguments
+/// 
+/// * `hourly` - Statistics for each hour with data, in hour order
+/// * `display` - Time unit and precision used for durations
+pub fn print_time_of_day_report(hourly: &[HourlyStatistics], display: &DisplayOptions) {
+    println!("\n{}", "-".repeat(30));
+    println!("PROPAGATION BY TIME OF DAY (UTC)");
+    println!("{}", "-".repeat(30));
+    
+    if hourly.is_empty() {
+        println!("No successful results recorded yet");
+        return;
+    }
+    
+    println!("{:>5}  {:>6}  {:>12}  {:>12}", "Hour", "Tests", "Average", "p95");
+    for hour in hourly {
+        println!(
+            "{:>5}  {:>6}  {:>12}  {:>12}",
+            format!("{:02}:00", hour.hour),
+            hour.successful_tests,
+            display.format_ms(hour.avg_propagation_time_ms),
+            hour.percentile_95_ms.map_or("n/a".to_string(), |p95| display.format_ms(p95 as f64))
+        );
+    }
+}
+
+/// Prints the results of a concurrency breakpoint search
+/// 
+/// Displays a table of concurrency level against p95 propagation time and
+/// success rate, followed by the concurrency at which consistency degraded.
+/// 
+/// # Arguments
+/// 
+/// * `report` - The breakpoint report to display
+pub fn print_breakpoint_summary(report: &BreakpointReport) {
+    println!("\n{}", "=".repeat(50));
+    println!("        CONSISTENCY BREAKPOINT SEARCH");
+    println!("{}", "=".repeat(50));
+    
+    println!("S3 Endpoint: {}", report.config.endpoint);
+    println!("Bucket: {}", report.config.bucket);
+    println!("Files per Level: {}", report.test_parameters.test_count);
+    println!("Thresholds: p95 <= {}ms, success rate >= {:.1}%",
+             report.p95_threshold_ms, report.min_success_rate);
+    
+    println!("\n{:>11} | {:>10} | {:>10} | {:>8}", "Concurrency", "p95", "Average", "Success");
+    println!("{}", "-".repeat(50));
+    
+    for level in &report.levels {
+        let stats = &level.statistics;
+        let p95 = stats.percentile_95_ms.map_or("n/a".to_string(), |v| format!("{}ms", v));
+        let avg = stats.avg_propagation_time_ms.map_or("n/a".to_string(), |v| format!("{:.1}ms", v));
+        let marker = if level.degraded { " ❌" } else { "" };
+        
+        println!("{:>11} | {:>10} | {:>10} | {:>7.1}%{}",
+                 level.concurrency, p95, avg, stats.success_rate, marker);
+    }
+    
+    // A stopped search can leave the last level short, so weight levels by file count
+    let overall = report.levels.iter()
+        .map(|level| level.statistics.clone())
+        .reduce(|existing, new| merge_statistics(&existing, &new));
+    if let Some(overall) = overall.filter(|_| report.levels.len() > 1) {
+        let avg = overall.avg_propagation_time_ms.map_or("n/a".to_string(), |v| format!("{:.1}ms", v));
+        println!("{}", "-".repeat(50));
+        println!("{:>11} | {:>10} | {:>10} | {:>7.1}%", "All", "-", avg, overall.success_rate);
+    }
+    
+    println!();
+    match report.breakpoint_concurrency {
+        Some(concurrency) => println!("📉 Consistency degraded at concurrency {}", concurrency),
+        None => println!("✅ No degradation found up to concurrency {}",
+                         report.levels.last().map_or(0, |l| l.concurrency)),
+    }
+    println!("{}", "=".repeat(50));
+}
+
+/// Orders benchmark entries from best to worst and numbers them
+/// 
+/// Runs are ranked by success rate, highest first, then by p95 propagation
+/// time, fastest first. Runs with too few successful files for a p95 are
+/// compared by their slowest propagation time instead, and runs without any
+/// propagation time come after those with one. Failed runs without
+/// statistics come last and are not ranked. Ties keep their original order.
+/// 
+/// # Arguments
+/// 
+/// * `entries` - The benchmark entries to rank, in the order they were run
+pub fn rank_benchmark_entries(entries: &mut [BenchmarkEntry]) {
+    entries.sort_by(|a, b| match (&a.statistics, &b.statistics) {
+        (Some(a), Some(b)) => b.success_rate.total_cmp(&a.success_rate).then_with(|| {
+            let a_tail = a.percentile_95_ms.or(a.max_propagation_time_ms);
+            let b_tail = b.percentile_95_ms.or(b.max_propagation_time_ms);
+            a_tail.is_none().cmp(&b_tail.is_none()).then(a_tail.cmp(&b_tail))
+        }),
+        (a, b) => a.is_none().cmp(&b.is_none()),
+    });
+    
+    for (i, entry) in entries.iter_mut().enumerate() {
+        entry.rank = entry.statistics.as_ref().map(|_| i + 1);
+    }
+}
+
+/// Prints the results of a benchmark as a providers × statistics matrix
+/// 
+/// Lists one row per configuration in ranking order, followed by the
+/// errors of runs that failed.
+/// 
+/// # Arguments
+/// 
+/// * `report` - The ranked benchmark report to display
+/// * `display` - Time unit and precision used for durations
+pub fn print_benchmark_matrix(report: &BenchmarkReport, display: &DisplayOptions) {
+    println!("\n{}", "=".repeat(50));
+    println!("        PROVIDER BENCHMARK");
+    println!("{}", "=".repeat(50));
+    
+    println!("Files per Provider: {}", report.test_parameters.test_count);
+    println!("Duration: {:.2}s",
+             (report.test_end_time - report.test_start_time).num_milliseconds() as f64 / 1000.0);
+    
+    println!(
+        "\n{:>4}  {:<width$}  {:>8}  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}",
+        "Rank", "Provider", "Success", "Average", "Median", "p95", "p99", "Max",
+        width = BENCHMARK_PROVIDER_WIDTH
+    );
+    
+    let format = |value: Option<u64>| value.map_or("n/a".to_string(), |ms| display.format_ms(ms as f64));
+    for entry in &report.entries {
+        let provider = entry.config_path.display().to_string();
+        let (Some(rank), Some(stats)) = (entry.rank, &entry.statistics) else {
+            println!("{:>4}  {:<width$}  {:>8}", "-", truncate(&provider, BENCHMARK_PROVIDER_WIDTH), "FAILED",
+                     width = BENCHMARK_PROVIDER_WIDTH);
+            continue;
+        };
+        println!(
+            "{:>4}  {:<width$}  {:>7.1}%  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}",
+            rank,
+            truncate(&provider, BENCHMARK_PROVIDER_WIDTH),
+            stats.success_rate,
+            stats.avg_propagation_time_ms.map_or("n/a".to_string(), |ms| display.format_ms(ms)),
+            format(stats.median_propagation_time_ms),
+            format(stats.percentile_95_ms),
+            format(stats.percentile_99_ms),
+            format(stats.max_propagation_time_ms),
+            width = BENCHMARK_PROVIDER_WIDTH
+        );
+    }
+    
+    println!();
+    for entry in &report.entries {
+        println!("   {}: {} ({})", entry.config_path.display(), entry.endpoint, entry.bucket);
+        if let Some(error) = &entry.error_details {
+            println!("      ❌ {}", error);
+        }
+    }
+    
+    if let Some(best) = report.entries.first().filter(|entry| entry.rank.is_some()) {
+        println!("\n🏆 Best: {}", best.config_path.display());
+    }
+    println!("{}", "=".repeat(50));
+}
+
+/// Prints a summary of the backend availability timeline
+/// 
+/// Shows the fraction of successful probes, probe latency and the time
+/// windows in which the backend was unavailable, so that availability dips
+/// can be correlated with slow or failed files.
+/// 
+/// # Arguments
+/// 
+/// * `timeline` - Availability samples in time order
+/// * `display` - Time unit and precision used for durations
+fn print_availability_summary(timeline: &[AvailabilitySample], display: &DisplayOptions) {
+    println!("\n{}", "-".repeat(30));
+    println!("BACKEND AVAILABILITY");
+    println!("{}", "-".repeat(30));
+    
+    if timeline.is_empty() {
+        println!("No availability probes were recorded");
+        return;
+    }
+    
+    let available = timeline.iter().filter(|s| s.available).count();
+    let availability = available as f64 / timeline.len() as f64 * 100.0;
+    let max_latency = timeline.iter().map(|s| s.latency_ms).max().unwrap_or(0);
+    let avg_latency = timeline.iter().map(|s| s.latency_ms).sum::<u64>() as f64 / timeline.len() as f64;
+    
+    println!("🩺 Probes: {}/{} succeeded ({:.1}%)", available, timeline.len(), availability);
+    println!("⏱️  Probe latency: {} average, {} max",
+             display.format_ms(avg_latency), display.format_ms(max_latency as f64));
+    
+    // Group consecutive failed probes into outage windows
+    let mut outage_start = None;
+    for (i, sample) in timeline.iter().enumerate() {
+        match (sample.available, outage_start) {
+            (false, None) => outage_start = Some(i),
+            (true, Some(start)) => {
+                print_outage_window(&timeline[start..i]);
+                outage_start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(start) = outage_start {
+        print_outage_window(&timeline[start..]);
+    }
+}
+
+/// Prints the propagation by file size section of the summary
+/// 
+/// # Arguments
+/// 
+/// * `sizes` - Statistics for each tested file size
+/// * `display` - Time unit and precision used for durations
+fn print_size_breakdown(sizes: &[SizeStatistics], display: &DisplayOptions) {
+    println!("\n{}", "-".repeat(30));
+    println!("PROPAGATION BY FILE SIZE");
+    println!("{}", "-".repeat(30));
+    
+    println!("{:>12}  {:>9}  {:>12}  {:>12}", "Size (bytes)", "Succeeded", "Average", "p95");
+    for size in sizes {
+        println!(
+            "{:>12}  {:>9}  {:>12}  {:>12}",
+            size.file_size,
+            format!("{}/{}", size.successful_tests, size.total_tests),
+            size.avg_propagation_time_ms.map_or("n/a".to_string(), |avg| display.format_ms(avg)),
+            size.percentile_95_ms.map_or("n/a".to_string(), |p95| display.format_ms(p95 as f64))
+        );
+    }
+}
+
+/// Prints the propagation statistics for each bucket
+/// 
+/// # Arguments
+/// 
+/// * `buckets` - Statistics for each bucket files were uploaded to
+/// * `display` - Time unit and precision used for durations
+fn print_bucket_breakdown(buckets: &[BucketStatistics], display: &DisplayOptions) {
+    println!("\n{}", "-".repeat(30));
+    println!("PROPAGATION BY BUCKET");
+    println!("{}", "-".repeat(30));
+    
+    let width = buckets.iter().map(|b| b.bucket.chars().count()).max().unwrap_or(0).max(6);
+    println!("{:<width$}  {:>9}  {:>12}  {:>12}  {:>12}", "Bucket", "Succeeded", "Average", "p95", "Max");
+    for bucket in buckets {
+        println!(
+            "{:<width$}  {:>9}  {:>12}  {:>12}  {:>12}",
+            bucket.bucket,
+            format!("{}/{}", bucket.successful_tests, bucket.total_tests),
+            bucket.avg_propagation_time_ms.map_or("n/a".to_string(), |avg| display.format_ms(avg)),
+            bucket.percentile_95_ms.map_or("n/a".to_string(), |p95| display.format_ms(p95 as f64)),
+            bucket.max_propagation_time_ms.map_or("n/a".to_string(), |max| display.format_ms(max as f64))
+        );
+    }
+}
+
+/// Prints how many failed requests of each error category were recovered by retrying
+/// 
+/// # Arguments
+/// 
+/// * `categories` - Retry outcomes for each error category seen
+fn print_error_retries(categories: &[ErrorRetryStatistics]) {
+    println!("\n{}", "-".repeat(30));
+    println!("ERROR RETRY OUTCOMES");
+    println!("{}", "-".repeat(30));
+    
+    let width = categories.iter().map(|c| c.category.chars().count()).max().unwrap_or(0).max(5);
+    println!("{:<width$}  {:>11}  {:>9}  {:>9}", "Error", "Occurrences", "Recovered", "Permanent");
+    for category in categories {
+        println!(
+            "{:<width$}  {:>11}  {:>9}  {:>9}",
+            category.category, category.occurrences, category.recovered, category.permanent
+        );
+    }
+}
+
+/// Prints how many files last failed with each S3 error code
+/// 
+/// # Arguments
+/// 
+/// * `codes` - Number of files for each error code seen
+fn print_error_codes(codes: &[ErrorCodeCount]) {
+    println!("\n{}", "-".repeat(30));
+    println!("S3 ERROR CODES");
+    println!("{}", "-".repeat(30));
+    
+    let width = codes.iter().map(|c| c.code.chars().count()).max().unwrap_or(0).max(4);
+    println!("{:<width$}  {:>5}", "Code", "Files");
+    for code in codes {
+        println!("{:<width$}  {:>5}", code.code, code.files);
+    }
+}
+
+/// Prints the bucket listing completeness section of the summary
+/// 
+/// # Arguments
+/// 
+/// * `list_result` - Outcome of the listing completeness check
+/// * `display` - Time unit and precision used for durations
+fn print_list_completeness(list_result: &ListCompletenessResult, display: &DisplayOptions) {
+    println!("\n{}", "-".repeat(30));
+    println!("LIST COMPLETENESS");
+    println!("{}", "-".repeat(30));
+    
+    match list_result.list_completeness_duration_ms {
+        Some(duration) => println!("✅ Listing returned all {} files after {} ({} listings)",
+                                   list_result.expected_count, display.format_ms(duration as f64),
+                                   list_result.total_attempts),
+        None => println!("❌ Listing returned only {}/{} files before timing out ({} listings)",
+                         list_result.observed_count, list_result.expected_count,
+                         list_result.total_attempts),
+    }
+    
+    if list_result.page_count > 1 {
+        println!("📄 Pages per listing: {}", list_result.page_count);
+    }
+    if list_result.list_snapshot_consistent {
+        println!("📸 The first listing was a consistent snapshot");
+    } else if let Some(seen) = list_result.keys_seen_duration_ms {
+        println!("🔁 Not a consistent snapshot: every file had been listed after {}, across {} listings",
+                 display.format_ms(seen as f64), list_result.total_attempts);
+    }
+    
+    if let Some(error) = &list_result.error_details {
+        println!("   Last listing error: {}", error);
+    }
+}
+
+/// Prints how reads converged after concurrent overwrites of one key
+/// 
+/// # Arguments
+/// 
+/// * `race` - Outcome of the overwrite race
+/// * `display` - Time unit and precision used for durations
+fn print_overwrite_race(race: &OverwriteRaceResult, display: &DisplayOptions) {
+    println!("\n{}", "-".repeat(30));
+    println!("OVERWRITE RACE");
+    println!("{}", "-".repeat(30));
+    
+    println!("✍️  Writers: {} ({} uploads succeeded)", race.writers, race.successful_writes);
+    match (race.winner, race.converged_value_stable_ms) {
+        (Some(winner), Some(stable_ms)) => {
+            println!("🏁 Reads settled on writer {} after {}", winner, display.format_ms(stable_ms as f64));
+            match race.last_completed_writer {
+                Some(last) if last == winner => println!("✅ The last upload to complete won"),
+                Some(last) => println!("⚠️  Writer {} completed its upload last but did not win", last),
+                None => {}
+            }
+        }
+        _ => println!("❌ Reads did not settle on one payload ({} reads)", race.total_reads),
+    }
+    if race.oscillated {
+        println!("🔁 Reads oscillated between payloads ({} switches)", race.value_changes);
+    } else if race.value_changes > 0 {
+        println!("🔀 Reads switched payloads {} times without returning to an earlier one", race.value_changes);
+    }
+    
+    if let Some(error) = &race.error_details {
+        println!("   {}", error);
+    }
+}
+
+/// Prints how long deleted files kept appearing in listings
+/// 
+/// # Arguments
+/// 
+/// * `delete_list` - Delete-to-listing propagation statistics
+/// * `display` - Time unit and precision used for durations
+fn print_delete_list_consistency(delete_list: &DeleteListStatistics, display: &DisplayOptions) {
+    println!("\n{}", "-".repeat(30));
+    println!("DELETE LIST CONSISTENCY");
+    println!("{}", "-".repeat(30));
+    
+    println!("🗑️  Files measured: {}", delete_list.measured_count);
+    println!("⚡ Fastest: {}", display.format_ms(delete_list.min_ms as f64));
+    println!("🐌 Slowest: {}", display.format_ms(delete_list.max_ms as f64));
+    println!("📊 Average: {}", display.format_ms(delete_list.avg_ms));
+    println!("📈 Median: {}", display.format_ms(delete_list.median_ms as f64));
+    match delete_list.percentile_95_ms {
+        Some(p95) => println!("   95th: {}", display.format_ms(p95 as f64)),
+        None => println!("   95th: n/a (sample too small, needs at least {} files)", MIN_SAMPLES_P95),
+    }
+}
+
+/// Prints how long deleted keys took to become readable again
+/// 
+/// # Arguments
+/// 
+/// * `recreate` - Delete and re-create statistics
+/// * `display` - Time unit and precision used for durations
+fn print_recreate_after_delete(recreate: &RecreateStatistics, display: &DisplayOptions) {
+    println!("\n{}", "-".repeat(30));
+    println!("RECREATE AFTER DELETE");
+    println!("{}", "-".repeat(30));
+    
+    println!("♻️  Files re-created: {}", recreate.measured_count);
+    if recreate.failed_count > 0 {
+        println!("❌ Failed: {}", recreate.failed_count);
+    }
+    let format = |ms: Option<u64>| ms.map_or("n/a".to_string(), |ms| display.format_ms(ms as f64));
+    println!("⚡ Fastest: {}", format(recreate.min_ms));
+    println!("🐌 Slowest: {}", format(recreate.max_ms));
+    println!("📊 Average: {}", recreate.avg_ms.map_or("n/a".to_string(), |ms| display.format_ms(ms)));
+    println!("📈 Median: {}", format(recreate.median_ms));
+    match recreate.percentile_95_ms {
+        Some(p95) => println!("   95th: {}", display.format_ms(p95 as f64)),
+        None => println!("   95th: n/a (sample too small, needs at least {} files)", MIN_SAMPLES_P95),
+    }
+    if recreate.stale_tombstone_files > 0 {
+        println!(
+            "🪦 Stale tombstones: {} files returned 404 after a fresh key would have been readable ({} reads)",
+            recreate.stale_tombstone_files, recreate.stale_tombstone_reads
+        );
+    } else {
+        println!("✅ No stale-tombstone reads");
+    }
+}
+
+/// Prints how long files took to reach the replication target
+/// 
+/// # Arguments
+/// 
+/// * `replication` - Cross-bucket replication statistics
+/// * `display` - Time unit and precision used for durations
+fn print_replication(replication: &ReplicationStatistics, display: &DisplayOptions) {
+    println!("\n{}", "-".repeat(30));
+    println!("BUCKET REPLICATION");
+    println!("{}", "-".repeat(30));
+    
+    println!("🪞 Files replicated: {}", replication.measured_count);
+    println!("⚡ Fastest: {}", display.format_ms(replication.min_ms as f64));
+    println!("🐌 Slowest: {}", display.format_ms(replication.max_ms as f64));
+    println!("📊 Average: {}", display.format_ms(replication.avg_ms));
+    println!("📈 Median: {}", display.format_ms(replication.median_ms as f64));
+    match replication.percentile_95_ms {
+        Some(p95) => println!("   95th: {}", display.format_ms(p95 as f64)),
+        None => println!("   95th: n/a (sample too small, needs at least {} files)", MIN_SAMPLES_P95),
+    }
+}
+
+/// Prints the skew between object body and metadata
+/// 
+/// # Arguments
+/// 
+/// * `metadata_skew` - Body versus metadata skew statistics
+/// * `display` - Time unit and precision used for durations
+fn print_metadata_skew(metadata_skew: &MetadataSkewStatistics, display: &DisplayOptions) {
+    println!("\n{}", "-".repeat(30));
+    println!("BODY VS METADATA SKEW");
+    println!("{}", "-".repeat(30));
+    
+    println!("🏷️  Files measured: {}", metadata_skew.measured_count);
+    println!("🐢 Metadata lagged the body: {}", metadata_skew.metadata_lagged);
+    println!("🐇 Metadata led the body: {}", metadata_skew.body_lagged);
+    println!("📉 Smallest skew: {}", format_skew(metadata_skew.min_skew_ms as f64, display));
+    println!("📈 Largest skew: {}", format_skew(metadata_skew.max_skew_ms as f64, display));
+    println!("📊 Average skew: {}", format_skew(metadata_skew.avg_skew_ms, display));
+    println!("   Median skew: {}", format_skew(metadata_skew.median_skew_ms as f64, display));
+}
+
+/// Formats a signed skew, positive when the metadata lagged the body
+/// 
+/// # Arguments
+/// 
+/// * `skew_ms` - The skew in milliseconds
+/// * `display` - Time unit and precision used for durations
+fn format_skew(skew_ms: f64, display: &DisplayOptions) -> String {
+    let sign = if skew_ms < 0.0 { "-" } else { "+" };
+    format!("{}{}", sign, display.format_ms(skew_ms.abs()))
+}
+
+/// Prints how often the writer and the reader saw new files right away
+/// 
+/// # Arguments
+/// 
+/// * `read_your_writes` - Writer and reader visibility statistics
+fn print_read_your_writes(read_your_writes: &ReadYourWritesStatistics) {
+    println!("\n{}", "-".repeat(30));
+    println!("READ-YOUR-WRITES");
+    println!("{}", "-".repeat(30));
+    
+    println!("✍️  Writer saw own write immediately: {}/{} ({:.1}%)",
+             read_your_writes.writer_immediate_count, read_your_writes.measured_count,
+             read_your_writes.writer_immediate_rate);
+    println!("📖 Reader saw write on first attempt: {}/{} ({:.1}%)",
+             read_your_writes.reader_first_attempt_count, read_your_writes.measured_count,
+             read_your_writes.reader_first_attempt_rate);
+    
+    let gap = read_your_writes.writer_immediate_rate - read_your_writes.reader_first_attempt_rate;
+    if gap > 0.0 {
+        println!("🔀 Same-connection advantage: {:.1} percentage points", gap);
+    }
+}
+
+/// Prints how often simulated organic reads would have seen inconsistent data
+/// 
+/// # Arguments
+/// 
+/// * `organic_reads` - Organic read statistics
+/// * `parameters` - Test parameters holding the mean read delay
+/// * `display` - Time unit and precision used for durations
+fn print_organic_reads(organic_reads: &OrganicReadStatistics, parameters: &TestParameters, display: &DisplayOptions) {
+    println!("\n{}", "-".repeat(30));
+    println!("ORGANIC READS");
+    println!("{}", "-".repeat(30));
+    
+    if let Some(mean_ms) = parameters.organic_read_mean_ms {
+        println!("⏱️  Mean first-read delay: {}", display.format_ms(mean_ms as f64));
+    }
+    println!("👤 Simulated reads: {}", organic_reads.simulated_reads);
+    println!("💥 Would have seen inconsistent data: {} ({:.1}%)",
+             organic_reads.inconsistent_reads, organic_reads.inconsistent_rate);
+}
+
+/// Prints how many files became consistent within each attempt range
+/// 
+/// # Arguments
+/// 
+/// * `distribution` - The non-empty attempt ranges
+fn print_attempts_distribution(distribution: &[AttemptsBucket]) {
+    println!("\n🔢 Attempts to Consistency:");
+    
+    for bucket in distribution {
+        let range = match bucket.max_attempts {
+            Some(max) if max == bucket.min_attempts => max.to_string(),
+            Some(max) => format!("{}-{}", bucket.min_attempts, max),
+            None => format!("{}+", bucket.min_attempts),
+        };
+        let bar = "█".repeat((bucket.percentage / 5.0).round() as usize);
+        println!("   {:>7} attempts: {:4} ({:5.1}%) {}", range, bucket.count, bucket.percentage, bar);
+    }
+}
+
+/// Prints the propagation times of each attempt cohort
+/// 
+/// # Arguments
+/// 
+/// * `cohorts` - The non-empty attempt cohorts
+/// * `display` - Time unit and precision used for durations
+fn print_attempt_cohorts(cohorts: &[AttemptCohortStatistics], display: &DisplayOptions) {
+    println!("\n🧮 Propagation by Attempts Needed:");
+    
+    for cohort in cohorts {
+        let range = match cohort.max_attempts {
+            Some(max) if max == cohort.min_attempts => max.to_string(),
+            Some(max) => format!("{}-{}", cohort.min_attempts, max),
+            None => format!("{}+", cohort.min_attempts),
+        };
+        println!(
+            "   {:>7} attempts: {:4} files, avg {}, p95 {}",
+            range,
+            cohort.count,
+            display.format_ms(cohort.avg_propagation_time_ms),
+            cohort.percentile_95_ms.map_or("n/a".to_string(), |p95| display.format_ms(p95 as f64)),
+        );
+    }
+}
+
+/// Prints the connection setup timing section of the summary
+/// 
+/// # Arguments
+/// 
+/// * `timing` - Network setup timing for the endpoint
+/// * `display` - Time unit and precision used for durations
+fn print_connection_timing(timing: &ConnectionTiming, display: &DisplayOptions) {
+    println!("\n{}", "-".repeat(30));
+    println!("CONNECTION TIMING");
+    println!("{}", "-".repeat(30));
+    
+    println!("🔌 Endpoint: {}:{} ({})", timing.host, timing.port, timing.resolved_address);
+    println!("🌐 DNS resolution: {}", display.format_ms(timing.dns_resolution_ms));
+    println!("🤝 TCP connect: {}", display.format_ms(timing.tcp_connect_ms));
+    match timing.tls_handshake_ms {
+        Some(tls) => println!("🔒 TLS handshake: {}", display.format_ms(tls)),
+        None => println!("🔒 TLS handshake: n/a (plain HTTP)"),
+    }
+}
+
+/// Prints a single window of consecutive failed availability probes
+fn print_outage_window(window: &[AvailabilitySample]) {
+    if let (Some(first), Some(last)) = (window.first(), window.last()) {
+        println!("   ❌ Unavailable {} to {} ({} probes): {}",
+                 first.timestamp.format("%H:%M:%S%.3f"),
+                 last.timestamp.format("%H:%M:%S%.3f"),
+                 window.len(),
+                 first.error.as_deref().unwrap_or("Unknown error"));
+    }
+}
+
+/// Provides additional analysis of the timing distribution
+/// 
+/// Analyzes the consistency statistics to provide insights about
+/// the distribution of propagation times and potential performance characteristics.
+/// 
+/// # Arguments
+/// 
+/// * `stats` - The consistency statistics to analyze
+/// * `display` - Time unit and precision used for durations
+fn print_distribution_analysis(stats: &ConsistencyStatistics, display: &DisplayOptions) {
+    println!("\n🔍 Distribution Analysis:");
+    
+    if let (Some(min), Some(max), Some(avg)) = (
+        stats.min_propagation_time_ms,
+        stats.max_propagation_time_ms,
+        stats.avg_propagation_time_ms,
+    ) {
+        let range = max - min;
+        let variance_indicator = if range > (avg * 2.0) as u64 {
+            "High variance - inconsistent propagation times"
+        } else if range > avg as u64 {
+            "Moderate variance - some variation in propagation times"
+        } else {
+            "Low variance - consistent propagation times"
+        };
+        
+        println!("   Range: {} ({} to {})",
+                 display.format_ms(range as f64), display.format_ms(min as f64), display.format_ms(max as f64));
+        println!("   Consistency: {}", variance_indicator);
+        
+        // Performance assessment
+        if avg < 100.0 {
+            println!("   Performance: ⚡ Excellent (< 100ms average)");
+        } else if avg < 500.0 {
+            println!("   Performance: ✅ Good (< 500ms average)");
+        } else if avg < 2000.0 {
             println!("   Performance: ⚠️  Moderate (< 2s average)");
         } else {
             println!("   Performance: ❌ Slow (> 2s average)");
@@ -342,8 +2534,6 @@ fn print_distribution_analysis(stats: &ConsistencyStatistics) {
 mod tests {
     use super::*;
     use chrono::Utc;
-    use crate::config::S3Config;
-    use crate::types::TestParameters;
 
     fn create_test_result(success: bool, duration_ms: Option<u64>) -> TestResult {
         let now = Utc::now();
@@ -351,7 +2541,7 @@ mod tests {
             TestResult::success(
                 "test-key".to_string(),
                 now,
-                now,
+                now + chrono::Duration::milliseconds(duration_ms.unwrap_or(0) as i64),
                 1,
             )
         } else {
@@ -390,11 +2580,33 @@ mod tests {
         assert_eq!(calculate_percentile(&durations, 100.0), Some(500));
         
         // Test edge cases
-        assert_eq!(calculate_percentile(&vec![], 50.0), None);
+        assert_eq!(calculate_percentile(&[], 50.0), None);
         assert_eq!(calculate_percentile(&durations, -1.0), None);
         assert_eq!(calculate_percentile(&durations, 101.0), None);
     }
 
+    #[test]
+    fn test_high_percentiles_suppressed_for_small_samples() {
+        let results_of = |count: usize| -> Vec<TestResult> {
+            (0..count).map(|i| create_test_result(true, Some(100 + i as u64))).collect()
+        };
+        
+        let stats = calculate_statistics(&results_of(MIN_SAMPLES_P95 - 1));
+        assert_eq!(stats.percentile_95_ms, None);
+        assert_eq!(stats.percentile_99_ms, None);
+        assert!(stats.median_propagation_time_ms.is_some());
+        
+        let stats = calculate_statistics(&results_of(MIN_SAMPLES_P95));
+        assert_eq!(stats.percentile_95_ms, Some(118));
+        assert_eq!(stats.percentile_99_ms, None);
+        
+        let stats = calculate_statistics(&results_of(MIN_SAMPLES_P99 - 1));
+        assert_eq!(stats.percentile_99_ms, None);
+        
+        let stats = calculate_statistics(&results_of(MIN_SAMPLES_P99));
+        assert_eq!(stats.percentile_99_ms, Some(198));
+    }
+    
     #[test]
     fn test_calculate_statistics_empty() {
         let results = vec![];
@@ -423,4 +2635,601 @@ mod tests {
         assert_eq!(stats.min_propagation_time_ms, Some(100));
         assert_eq!(stats.max_propagation_time_ms, Some(300));
     }
+
+    #[test]
+    fn test_sub_millisecond_statistics() {
+        let now = Utc::now();
+        let results: Vec<TestResult> = [250, 400, 900]
+            .iter()
+            .map(|&us| TestResult::success(
+                "test-key".to_string(),
+                now,
+                now + chrono::Duration::microseconds(us),
+                1,
+            ))
+            .collect();
+        
+        let stats = calculate_statistics(&results);
+        
+        // Millisecond fields keep their truncated values for compatibility
+        assert_eq!(stats.min_propagation_time_ms, Some(0));
+        assert_eq!(stats.max_propagation_time_ms, Some(0));
+        
+        assert_eq!(stats.min_propagation_time_us, Some(250));
+        assert_eq!(stats.max_propagation_time_us, Some(900));
+        assert_eq!(stats.median_propagation_time_us, Some(400));
+        assert_eq!(stats.avg_propagation_time_us, Some(550.0));
+    }
+    
+    #[test]
+    fn test_calculate_hourly_statistics() {
+        let mut samples = vec![(14, 300), (2, 100), (14, 500)];
+        samples.extend((0..MIN_SAMPLES_P95 as u64).map(|i| (3, i * 10)));
+        
+        let hourly = calculate_hourly_statistics(&samples);
+        
+        assert_eq!(hourly.iter().map(|h| h.hour).collect::<Vec<_>>(), vec![2, 3, 14]);
+        assert_eq!(hourly[0].successful_tests, 1);
+        assert_eq!(hourly[2].avg_propagation_time_ms, 400.0);
+        assert_eq!(hourly[2].percentile_95_ms, None);
+        assert_eq!(hourly[1].percentile_95_ms, Some(180));
+    }
+    
+    #[test]
+    fn test_calculate_size_statistics() {
+        let mut results: Vec<TestResult> = [(1024, 100), (1024, 300), (4096, 500)].iter()
+            .map(|&(size, duration)| {
+                let upload_time = Utc::now();
+                let mut result = TestResult::success(
+                    format!("file-{}", duration),
+                    upload_time,
+                    upload_time + chrono::Duration::milliseconds(duration),
+                    1,
+                );
+                result.file_size = Some(size);
+                result
+            })
+            .collect();
+        let mut failure = TestResult::failure("failed".to_string(), Utc::now(), "timeout".to_string());
+        failure.file_size = Some(4096);
+        results.push(failure);
+        results.push(TestResult::failure("unsized".to_string(), Utc::now(), "timeout".to_string()));
+        
+        let sizes = calculate_size_statistics(&results);
+        
+        assert_eq!(sizes.iter().map(|s| s.file_size).collect::<Vec<_>>(), vec![1024, 4096]);
+        assert_eq!(sizes[0].avg_propagation_time_ms, Some(200.0));
+        assert_eq!(sizes[1].total_tests, 2);
+        assert_eq!(sizes[1].successful_tests, 1);
+        assert_eq!(sizes[1].percentile_95_ms, None);
+    }
+    
+    #[test]
+    fn test_calculate_bucket_statistics() {
+        let mut results: Vec<TestResult> = [("shard-b", 100), ("shard-a", 300), ("shard-b", 500)].iter()
+            .map(|&(bucket, duration)| {
+                let upload_time = Utc::now();
+                let mut result = TestResult::success(
+                    format!("file-{}", duration),
+                    upload_time,
+                    upload_time + chrono::Duration::milliseconds(duration),
+                    1,
+                );
+                result.bucket = Some(bucket.to_string());
+                result
+            })
+            .collect();
+        let mut failure = TestResult::failure("failed".to_string(), Utc::now(), "timeout".to_string());
+        failure.bucket = Some("shard-a".to_string());
+        results.push(failure);
+        
+        let buckets = calculate_bucket_statistics(&results);
+        
+        assert_eq!(buckets.iter().map(|b| b.bucket.as_str()).collect::<Vec<_>>(), vec!["shard-a", "shard-b"]);
+        assert_eq!(buckets[0].total_tests, 2);
+        assert_eq!(buckets[0].successful_tests, 1);
+        assert_eq!(buckets[1].avg_propagation_time_ms, Some(300.0));
+        assert_eq!(buckets[1].max_propagation_time_ms, Some(500));
+    }
+    
+    #[test]
+    fn test_output_literals_have_no_mojibake() {
+        // Prefixes of UTF-8 emoji mis-decoded as Windows-1252, plus the
+        // replacement character; escaped so this test does not match itself
+        let mojibake = ["\u{f0}\u{178}", "\u{e2}\u{153}", "\u{e2}\u{152}", "\u{e2}\u{161}", "\u{e2}\u{20ac}", "\u{fffd}"];
+        let sources = [
+            ("statistics.rs", include_str!("statistics.rs")),
+            ("main.rs", include_str!("main.rs")),
+            ("tester.rs", include_str!("tester.rs")),
+            ("cleanup.rs", include_str!("cleanup.rs")),
+            ("tui.rs", include_str!("tui.rs")),
+        ];
+        
+        for (file, source) in sources {
+            for (number, line) in source.lines().enumerate() {
+                assert!(
+                    !mojibake.iter().any(|pattern| line.contains(pattern)),
+                    "{}:{} contains garbled characters: {}", file, number + 1, line
+                );
+            }
+        }
+    }
+    
+    #[test]
+    fn test_sort_results() {
+        let results = vec![
+            create_test_result(true, Some(100)),
+            create_test_result(false, None),
+            create_test_result(true, Some(300)),
+            create_test_result(false, None),
+        ];
+        let order = |order| sort_results(&results, order).iter().map(|&(i, _)| i).collect::<Vec<_>>();
+        
+        assert_eq!(order(ResultOrder::Order), vec![0, 1, 2, 3]);
+        assert_eq!(order(ResultOrder::Duration), vec![2, 0, 1, 3]);
+        assert_eq!(order(ResultOrder::Status), vec![1, 3, 0, 2]);
+    }
+    
+    #[test]
+    fn test_listed_results_failures_only() {
+        let results = vec![
+            create_test_result(true, Some(100)),
+            create_test_result(false, None),
+            create_test_result(true, Some(300)),
+            create_test_result(false, None),
+        ];
+        let listed = |failures_only| listed_results(&results, ResultOrder::Order, failures_only)
+            .iter().map(|&(i, _)| i).collect::<Vec<_>>();
+        
+        assert_eq!(listed(false), vec![0, 1, 2, 3]);
+        assert_eq!(listed(true), vec![1, 3]);
+    }
+    
+    #[test]
+    fn test_rank_benchmark_entries() {
+        let entry = |name: &str, statistics: Option<ConsistencyStatistics>| BenchmarkEntry {
+            config_path: name.into(),
+            endpoint: "http://localhost:9000".to_string(),
+            bucket: "bucket".to_string(),
+            rank: None,
+            error_details: statistics.is_none().then(|| "unreachable".to_string()),
+            statistics,
+        };
+        let fast: Vec<_> = (0..20).map(|_| create_test_result(true, Some(100))).collect();
+        let slow: Vec<_> = (0..20).map(|_| create_test_result(true, Some(500))).collect();
+        let mut flaky = fast.clone();
+        flaky.push(create_test_result(false, None));
+        let few = vec![create_test_result(true, Some(300))];
+        
+        let mut entries = vec![
+            entry("failed.toml", None),
+            entry("flaky.toml", Some(calculate_statistics(&flaky))),
+            entry("slow.toml", Some(calculate_statistics(&slow))),
+            entry("few.toml", Some(calculate_statistics(&few))),
+            entry("fast.toml", Some(calculate_statistics(&fast))),
+        ];
+        rank_benchmark_entries(&mut entries);
+        
+        let ranking: Vec<_> = entries.iter()
+            .map(|entry| (entry.config_path.display().to_string(), entry.rank))
+            .collect();
+        assert_eq!(ranking, vec![
+            ("fast.toml".to_string(), Some(1)),
+            ("few.toml".to_string(), Some(2)),
+            ("slow.toml".to_string(), Some(3)),
+            ("flaky.toml".to_string(), Some(4)),
+            ("failed.toml".to_string(), None),
+        ]);
+    }
+    
+    #[test]
+    fn test_truncate() {
+        assert_eq!(truncate("short", 10), "short");
+        assert_eq!(truncate("exactly-10", 10), "exactly-10");
+        assert_eq!(truncate("much-too-long", 10), "much-too-…");
+        assert_eq!(truncate("é-accents-ü", 5).chars().count(), 5);
+    }
+    
+    #[test]
+    fn test_calculate_mean_ci_width() {
+        // s = √200, n = 2 -> 2 × 1.96 × √200 / √2 = 39.2
+        let width = calculate_mean_ci_width(&[90, 110]).unwrap();
+        assert!((width - 39.2).abs() < 1e-9);
+        
+        assert_eq!(calculate_mean_ci_width(&[100, 100, 100]), Some(0.0));
+        assert_eq!(calculate_mean_ci_width(&[100]), None);
+    }
+    
+    #[test]
+    fn test_calculate_delete_list_statistics() {
+        let mut results = vec![
+            create_test_result(true, Some(100)),
+            create_test_result(false, None),
+            create_test_result(true, Some(100)),
+        ];
+        assert!(calculate_statistics(&results).delete_list.is_none());
+        
+        // Failed reads still get deleted and measured
+        results[0].delete_list_propagation_duration_ms = Some(300);
+        results[1].delete_list_propagation_duration_ms = Some(100);
+        
+        let delete_list = calculate_statistics(&results).delete_list.unwrap();
+        assert_eq!(delete_list.measured_count, 2);
+        assert_eq!(delete_list.min_ms, 100);
+        assert_eq!(delete_list.max_ms, 300);
+        assert_eq!(delete_list.avg_ms, 200.0);
+    }
+    
+    #[test]
+    fn test_calculate_metadata_skew_statistics() {
+        let mut results = vec![
+            create_test_result(true, Some(100)),
+            create_test_result(true, Some(100)),
+            create_test_result(true, Some(100)),
+        ];
+        assert!(calculate_statistics(&results).metadata_skew.is_none());
+        
+        results[0].metadata_body_skew_ms = Some(-40);
+        results[1].metadata_body_skew_ms = Some(0);
+        results[2].metadata_body_skew_ms = Some(250);
+        
+        let metadata_skew = calculate_statistics(&results).metadata_skew.unwrap();
+        assert_eq!(metadata_skew.measured_count, 3);
+        assert_eq!(metadata_skew.metadata_lagged, 1);
+        assert_eq!(metadata_skew.body_lagged, 1);
+        assert_eq!(metadata_skew.min_skew_ms, -40);
+        assert_eq!(metadata_skew.max_skew_ms, 250);
+        assert_eq!(metadata_skew.avg_skew_ms, 70.0);
+        assert_eq!(metadata_skew.median_skew_ms, 0);
+    }
+    
+    #[test]
+    fn test_calculate_replication_statistics() {
+        let mut results = vec![
+            create_test_result(true, Some(100)),
+            create_test_result(true, Some(100)),
+        ];
+        assert!(calculate_statistics(&results).replication.is_none());
+        
+        results[0].replication_propagation_duration_ms = Some(1500);
+        results[1].replication_propagation_duration_ms = Some(500);
+        
+        let replication = calculate_statistics(&results).replication.unwrap();
+        assert_eq!(replication.measured_count, 2);
+        assert_eq!(replication.min_ms, 500);
+        assert_eq!(replication.max_ms, 1500);
+        assert_eq!(replication.avg_ms, 1000.0);
+        assert!(replication.percentile_95_ms.is_none());
+    }
+    
+    #[test]
+    fn test_calculate_filtered_statistics() {
+        let results = vec![
+            create_test_result(true, Some(5)),
+            create_test_result(true, Some(100)),
+            create_test_result(true, Some(200)),
+            create_test_result(true, Some(280_000)),
+            create_test_result(false, None),
+        ];
+        
+        let unfiltered = calculate_filtered_statistics(&results, None, None);
+        assert_eq!(unfiltered.excluded_results, 0);
+        assert_eq!(unfiltered.max_propagation_time_ms, Some(280_000));
+        
+        let filtered = calculate_filtered_statistics(&results, Some(10), Some(60_000));
+        assert_eq!(filtered.excluded_results, 2);
+        assert_eq!(filtered.successful_tests, 2);
+        assert_eq!(filtered.failed_tests, 1);
+        assert_eq!(filtered.min_propagation_time_ms, Some(100));
+        assert_eq!(filtered.max_propagation_time_ms, Some(200));
+    }
+    
+    #[test]
+    fn test_flapping_tests_count() {
+        let mut results = vec![
+            create_test_result(true, Some(100)),
+            create_test_result(false, None),
+            create_test_result(true, Some(200)),
+        ];
+        results[1].flapping_detected = true;
+        results[2].flapping_detected = true;
+        
+        assert_eq!(calculate_statistics(&results).flapping_tests, 2);
+        assert_eq!(calculate_statistics(&[]).flapping_tests, 0);
+    }
+    
+    #[test]
+    fn test_size_mismatch_events_total() {
+        let mut results = vec![
+            create_test_result(true, Some(100)),
+            create_test_result(false, None),
+        ];
+        results[0].size_mismatch_events = 2;
+        results[1].size_mismatch_events = 3;
+        
+        assert_eq!(calculate_statistics(&results).size_mismatch_events, 5);
+        assert_eq!(calculate_statistics(&[]).size_mismatch_events, 0);
+    }
+    
+    #[test]
+    fn test_merge_statistics_weights_by_sample_size() {
+        let first = vec![
+            create_test_result(true, Some(100)),
+            create_test_result(true, Some(200)),
+            create_test_result(true, Some(300)),
+            create_test_result(false, None),
+        ];
+        let second = vec![create_test_result(true, Some(1000))];
+        
+        let merged = merge_statistics(&calculate_statistics(&first), &calculate_statistics(&second));
+        assert_eq!(merged.successful_tests, 4);
+        assert_eq!(merged.failed_tests, 1);
+        assert_eq!(merged.success_rate, 80.0);
+        assert_eq!(merged.min_propagation_time_ms, Some(100));
+        assert_eq!(merged.max_propagation_time_ms, Some(1000));
+        // The pooled mean, not the mean of the two averages (600ms)
+        assert_eq!(merged.avg_propagation_time_ms, Some(400.0));
+        assert_eq!(merged.median_propagation_time_ms, None);
+        assert_eq!(merged.attempts_distribution.len(), 1);
+        assert_eq!(merged.attempts_distribution[0].count, 4);
+        assert_eq!(merged.attempts_distribution[0].percentage, 100.0);
+        
+        // Merging with an empty run keeps the order statistics
+        let merged = merge_statistics(&calculate_statistics(&first), &calculate_statistics(&[]));
+        assert_eq!(merged.median_propagation_time_ms, Some(200));
+        assert_eq!(merged.avg_propagation_time_ms, Some(200.0));
+    }
+    
+    #[test]
+    fn test_key_collisions_total() {
+        let mut results = vec![
+            create_test_result(true, Some(100)),
+            create_test_result(false, None),
+        ];
+        results[0].key_collisions = 1;
+        results[1].key_collisions = 4;
+        
+        assert_eq!(calculate_statistics(&results).key_collisions, 5);
+    }
+    
+    #[test]
+    fn test_calculate_organic_read_statistics() {
+        let mut results = vec![
+            create_test_result(true, Some(100)),
+            create_test_result(true, Some(900)),
+            create_test_result(true, Some(200)),
+        ];
+        assert!(calculate_statistics(&results).organic_reads.is_none());
+        
+        results[0].organic_read_consistent = Some(true);
+        results[1].organic_read_consistent = Some(false);
+        
+        let organic_reads = calculate_statistics(&results).organic_reads.unwrap();
+        assert_eq!(organic_reads.simulated_reads, 2);
+        assert_eq!(organic_reads.inconsistent_reads, 1);
+        assert_eq!(organic_reads.inconsistent_rate, 50.0);
+    }
+    
+    #[test]
+    fn test_calculate_polling_time_statistics() {
+        let mut results = vec![
+            create_test_result(true, Some(100)),
+            create_test_result(true, Some(900)),
+            create_test_result(false, None),
+        ];
+        assert!(calculate_statistics(&results).polling_time.is_none());
+        
+        results[0].elapsed_ms_at_success = Some(100);
+        results[0].cumulative_sleep_ms = Some(60);
+        results[1].elapsed_ms_at_success = Some(900);
+        results[1].cumulative_sleep_ms = Some(840);
+        
+        let polling_time = calculate_statistics(&results).polling_time.unwrap();
+        assert_eq!(polling_time.measured_files, 2);
+        assert_eq!(polling_time.sleep_ms, 900);
+        assert_eq!(polling_time.request_ms, 100);
+        assert_eq!(polling_time.sleep_percent, 90.0);
+    }
+    
+    #[test]
+    fn test_calculate_read_your_writes_statistics() {
+        let mut results = vec![
+            create_test_result(true, Some(100)),
+            create_test_result(true, Some(200)),
+        ];
+        assert!(calculate_statistics(&results).read_your_writes.is_none());
+        
+        results[0].writer_read_your_writes = Some(true);
+        results[0].total_attempts = 3;
+        results[1].writer_read_your_writes = Some(true);
+        results[1].total_attempts = 1;
+        
+        let read_your_writes = calculate_statistics(&results).read_your_writes.unwrap();
+        assert_eq!(read_your_writes.measured_count, 2);
+        assert_eq!(read_your_writes.writer_immediate_count, 2);
+        assert_eq!(read_your_writes.writer_immediate_rate, 100.0);
+        assert_eq!(read_your_writes.reader_first_attempt_count, 1);
+        assert_eq!(read_your_writes.reader_first_attempt_rate, 50.0);
+    }
+    
+    #[test]
+    fn test_calculate_outlier_threshold() {
+        let durations = vec![100, 110, 120, 130, 1000];
+        // Q1 = 110, Q3 = 130, IQR = 20 -> fence = 130 + 30
+        assert_eq!(calculate_outlier_threshold(&durations), Some(160));
+        assert_eq!(calculate_outlier_threshold(&[]), None);
+    }
+
+    #[test]
+    fn test_flag_outliers() {
+        let mut results = vec![
+            create_test_result(true, Some(100)),
+            create_test_result(true, Some(110)),
+            create_test_result(true, Some(120)),
+            create_test_result(true, Some(130)),
+            create_test_result(true, Some(1000)),
+            create_test_result(false, None),
+        ];
+        
+        let stats = calculate_statistics(&results);
+        assert_eq!(stats.outlier_count, 1);
+        
+        flag_outliers(&mut results, &stats);
+        let flagged: Vec<bool> = results.iter().map(|r| r.is_outlier).collect();
+        assert_eq!(flagged, vec![false, false, false, false, true, false]);
+    }
+    
+    #[test]
+    fn test_calculate_sla_compliance() {
+        let mut results = vec![
+            create_test_result(true, Some(100)),
+            create_test_result(true, Some(900)),
+            create_test_result(false, None),
+            create_test_result(false, None),
+        ];
+        assert_eq!(calculate_sla_compliance(&results), (0, None));
+        
+        // The last result is an upload failure and is not classified
+        let classifications = [Some(true), Some(false), Some(false), None];
+        for (result, sla_met) in results.iter_mut().zip(classifications) {
+            result.sla_met = sla_met;
+        }
+        
+        let stats = calculate_statistics(&results);
+        assert_eq!(stats.sla_breaches, 2);
+        assert!((stats.sla_compliance_rate.unwrap() - 100.0 / 3.0).abs() < 1e-9);
+    }
+    
+    #[test]
+    fn test_calculate_attempts_distribution() {
+        let mut results: Vec<TestResult> = [1, 1, 3, 5, 6, 150]
+            .iter()
+            .map(|&attempts| {
+                let mut result = create_test_result(true, Some(100));
+                result.total_attempts = attempts;
+                result
+            })
+            .collect();
+        results.push(create_test_result(false, None));
+        
+        let distribution = calculate_attempts_distribution(&results);
+        let ranges: Vec<(u32, Option<u32>, usize)> = distribution.iter()
+            .map(|b| (b.min_attempts, b.max_attempts, b.count))
+            .collect();
+        assert_eq!(ranges, vec![(1, Some(1), 2), (2, Some(5), 2), (6, Some(20), 1), (101, None, 1)]);
+        assert!((distribution[0].percentage - 100.0 / 3.0).abs() < 1e-9);
+        
+        assert!(calculate_attempts_distribution(&[create_test_result(false, None)]).is_empty());
+    }
+    
+    #[test]
+    fn test_calculate_attempt_cohorts() {
+        let mut results: Vec<TestResult> = [(1, 50), (1, 70), (3, 400), (12, 2000)]
+            .iter()
+            .map(|&(attempts, duration_ms)| {
+                let mut result = create_test_result(true, Some(duration_ms));
+                result.total_attempts = attempts;
+                result
+            })
+            .collect();
+        results.push(create_test_result(false, None));
+        
+        let cohorts = calculate_statistics(&results).attempt_cohorts;
+        let summary: Vec<(u32, Option<u32>, usize, f64)> = cohorts.iter()
+            .map(|c| (c.min_attempts, c.max_attempts, c.count, c.avg_propagation_time_ms))
+            .collect();
+        assert_eq!(summary, vec![(1, Some(1), 2, 60.0), (2, Some(5), 1, 400.0), (6, None, 1, 2000.0)]);
+        assert!(cohorts.iter().all(|c| c.percentile_95_ms.is_none()));
+        
+        let merged = merge_attempt_cohorts(&cohorts, &cohorts[..1]);
+        assert_eq!(merged[0].count, 4);
+        assert_eq!(merged[0].avg_propagation_time_ms, 60.0);
+    }
+    
+    #[test]
+    fn test_calculate_error_retries() {
+        let mut recovered = create_test_result(true, Some(100));
+        recovered.recovered_errors.insert("503".to_string(), 3);
+        recovered.recovered_errors.insert("timeout".to_string(), 1);
+        let mut failed = create_test_result(false, None);
+        failed.permanent_errors.insert("503".to_string(), 2);
+        failed.permanent_errors.insert("403".to_string(), 5);
+        
+        let retries = calculate_statistics(&[recovered, failed, create_test_result(true, Some(50))]).error_retries;
+        let summary: Vec<(&str, u64, u64, u64)> = retries.iter()
+            .map(|c| (c.category.as_str(), c.occurrences, c.recovered, c.permanent))
+            .collect();
+        assert_eq!(summary, vec![("403", 5, 0, 5), ("503", 5, 3, 2), ("timeout", 1, 1, 0)]);
+        
+        let merged = merge_error_retries(&retries, &retries[2..]);
+        assert_eq!(merged[2].category, "timeout");
+        assert_eq!(merged[2].occurrences, 2);
+        assert_eq!(merged[2].recovered, 2);
+    }
+    
+    #[test]
+    fn test_calculate_recreate_statistics() {
+        let mut results = vec![
+            create_test_result(true, Some(100)),
+            create_test_result(true, Some(100)),
+            create_test_result(true, Some(100)),
+        ];
+        assert!(calculate_statistics(&results).recreate_after_delete.is_none());
+        
+        results[0].recreate_after_delete_propagation_ms = Some(150);
+        results[1].recreate_after_delete_propagation_ms = Some(450);
+        results[1].stale_tombstone_reads = 3;
+        results[2].recreate_error = Some("Re-upload failed".to_string());
+        
+        let recreate = calculate_statistics(&results).recreate_after_delete.unwrap();
+        assert_eq!(recreate.measured_count, 2);
+        assert_eq!(recreate.failed_count, 1);
+        assert_eq!(recreate.min_ms, Some(150));
+        assert_eq!(recreate.max_ms, Some(450));
+        assert_eq!(recreate.avg_ms, Some(300.0));
+        assert_eq!(recreate.stale_tombstone_files, 1);
+        assert_eq!(recreate.stale_tombstone_reads, 3);
+    }
+    
+    #[test]
+    fn test_calculate_error_codes() {
+        let mut results = vec![
+            create_test_result(false, None),
+            create_test_result(true, Some(100)),
+            create_test_result(false, None),
+            create_test_result(true, Some(100)),
+        ];
+        results[0].last_error_code = Some("SlowDown".to_string());
+        results[1].last_error_code = Some("InternalError".to_string());
+        results[2].last_error_code = Some("SlowDown".to_string());
+        
+        let codes = calculate_statistics(&results).error_codes;
+        let summary: Vec<(&str, usize)> = codes.iter().map(|c| (c.code.as_str(), c.files)).collect();
+        assert_eq!(summary, vec![("SlowDown", 2), ("InternalError", 1)]);
+        
+        let merged = merge_error_codes(&codes, &codes[1..]);
+        let summary: Vec<(&str, usize)> = merged.iter().map(|c| (c.code.as_str(), c.files)).collect();
+        assert_eq!(summary, vec![("InternalError", 2), ("SlowDown", 2)]);
+    }
+    
+    #[test]
+    fn test_slowest_results() {
+        let mut results = vec![
+            create_test_result(true, Some(300)),
+            create_test_result(false, None),
+            create_test_result(true, Some(100)),
+            create_test_result(true, Some(200)),
+        ];
+        for (i, result) in results.iter_mut().enumerate() {
+            result.file_key = format!("key-{}", i);
+        }
+        
+        let keys: Vec<&str> = slowest_results(&results, 2).iter()
+            .map(|r| r.file_key.as_str())
+            .collect();
+        assert_eq!(keys, vec!["key-0", "key-3"]);
+        
+        assert_eq!(slowest_results(&results, 10).len(), 3);
+        assert!(slowest_results(&results, 0).is_empty());
+    }
 }
\ No newline at end of file
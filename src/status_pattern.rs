@@ -0,0 +1,170 @@
+//! Read status patterns for `--expect-404-first`
+//! 
+//! On an eventually consistent backend, reads of a new object are expected
+//! to return 404 until the object propagates and the object from then on.
+//! Some backends deviate from that, e.g. by answering 200 with a placeholder
+//! body before the real object, answering 403 instead of 404 for a missing
+//! object, or returning 404 again after the object was readable. Recording
+//! each read's status and checking the sequence against the expected pattern
+//! shows whether a backend behaves as its consistency documentation says.
+
+use std::fmt;
+
+/// Outcome of one read attempt
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReadStatus {
+    /// A success status with the uploaded object's content
+    Object(u16),
+    
+    /// A success status with a body that is not the uploaded object, e.g. an
+    /// empty or truncated placeholder
+    Placeholder(u16),
+    
+    /// An HTTP error status
+    Http(u16),
+    
+    /// The read timed out
+    Timeout,
+    
+    /// The read failed without an HTTP status, e.g. a connection error
+    Error,
+}
+
+impl ReadStatus {
+    /// Describes how this status deviates from the 404-then-object pattern
+    /// 
+    /// # Arguments
+    /// 
+    /// * `readable` - Whether an earlier read already returned the object
+    /// 
+    /// # Returns
+    /// 
+    /// A description of the anomaly, or `None` if the status is expected.
+    /// Timeouts, connection errors and 5xx responses are treated as transient.
+    fn anomaly(self, readable: bool) -> Option<String> {
+        match self {
+            Self::Placeholder(status) if readable => {
+                Some(format!("HTTP {} with placeholder content after the object was readable", status))
+            }
+            Self::Placeholder(status) => Some(format!("HTTP {} with placeholder content before the object", status)),
+            Self::Http(404) if readable => Some("404 after the object was readable".to_string()),
+            Self::Http(status) if (400..500).contains(&status) && status != 404 => {
+                Some(format!("HTTP {} instead of 404", status))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ReadStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Object(status) | Self::Http(status) => write!(f, "{}", status),
+            Self::Placeholder(status) => write!(f, "{}!", status),
+            Self::Timeout => write!(f, "T"),
+            Self::Error => write!(f, "E"),
+        }
+    }
+}
+
+/// Encodes read statuses compactly, run-length encoding repeats
+/// 
+/// Placeholder responses are marked with `!`, timeouts with `T` and errors
+/// without a status with `E`.
+/// 
+/// # Arguments
+/// 
+/// * `statuses` - The status of each read attempt, in order
+/// 
+/// # Returns
+/// 
+/// The encoded sequence
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// let statuses = [ReadStatus::Http(404), ReadStatus::Http(404), ReadStatus::Object(200)];
+/// assert_eq!(format_status_sequence(&statuses), "404x2,200");
+/// ```
+pub fn format_status_sequence(statuses: &[ReadStatus]) -> String {
+    statuses
+        .chunk_by(|a, b| a == b)
+        .map(|run| match run.len() {
+            1 => run[0].to_string(),
+            n => format!("{}x{}", run[0], n),
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Checks read statuses against the 404-then-object pattern
+/// 
+/// # Arguments
+/// 
+/// * `statuses` - The status of each read attempt, in order
+/// * `grace` - Number of anomalous responses tolerated
+/// 
+/// # Returns
+/// 
+/// A description of the first anomaly and how many there were, or `None`
+/// if there were no more than `grace` anomalous responses
+pub fn find_status_anomaly(statuses: &[ReadStatus], grace: u32) -> Option<String> {
+    let mut readable = false;
+    let mut first = None;
+    let mut count = 0;
+    
+    for (attempt, status) in statuses.iter().enumerate() {
+        if let Some(anomaly) = status.anomaly(readable) {
+            count += 1;
+            first.get_or_insert_with(|| format!("{} (attempt {})", anomaly, attempt + 1));
+        }
+        readable |= matches!(status, ReadStatus::Object(_));
+    }
+    
+    if count <= grace {
+        return None;
+    }
+    first.map(|first| match count {
+        1 => first,
+        n => format!("{}, {} anomalous responses", first, n),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_format_status_sequence() {
+        let statuses = [
+            ReadStatus::Http(404),
+            ReadStatus::Http(404),
+            ReadStatus::Timeout,
+            ReadStatus::Placeholder(200),
+            ReadStatus::Object(200),
+            ReadStatus::Object(200),
+        ];
+        assert_eq!(format_status_sequence(&statuses), "404x2,T,200!,200x2");
+        assert_eq!(format_status_sequence(&[]), "");
+    }
+    
+    #[test]
+    fn test_find_status_anomaly() {
+        let expected = [ReadStatus::Http(404), ReadStatus::Http(503), ReadStatus::Object(200)];
+        assert_eq!(find_status_anomaly(&expected, 0), None);
+        assert_eq!(find_status_anomaly(&[ReadStatus::Object(200)], 0), None);
+        
+        let placeholder = [ReadStatus::Placeholder(200), ReadStatus::Http(404), ReadStatus::Object(200)];
+        assert_eq!(
+            find_status_anomaly(&placeholder, 0).as_deref(),
+            Some("HTTP 200 with placeholder content before the object (attempt 1)")
+        );
+        assert_eq!(find_status_anomaly(&placeholder, 1), None);
+        
+        let regressed = [ReadStatus::Http(403), ReadStatus::Object(200), ReadStatus::Http(404)];
+        assert_eq!(
+            find_status_anomaly(&regressed, 0).as_deref(),
+            Some("HTTP 403 instead of 404 (attempt 1), 2 anomalous responses")
+        );
+    }
+}
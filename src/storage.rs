@@ -0,0 +1,208 @@
+//! Object storage abstraction used by the consistency pipeline
+//! 
+//! The per-file pipeline (upload, polling reads, collision checks, listings
+//! and cleanup deletes) goes through the `ObjectStore` trait instead of
+//! calling `s3::Bucket` directly, so it can run against the in-memory mock
+//! used by `--self-test` and by unit tests, or against other backends.
+//! `Bucket` implements the trait by delegating to the corresponding S3
+//! requests.
+//! 
+//! Features that are inherently tied to the S3 wire protocol, such as
+//! streamed and multipart uploads, multi-object deletes, fresh reader
+//! connections and connection timing, still use `Bucket` directly.
+
+use futures::future::BoxFuture;
+use reqwest::header::HeaderMap;
+use s3::Bucket;
+use s3::error::S3Error;
+use s3::serde_types::ListBucketResult;
+use std::collections::HashMap;
+
+/// Content type of uploaded test files
+pub const UPLOAD_CONTENT_TYPE: &str = "application/octet-stream";
+
+/// Response to a successful object read
+#[derive(Debug, Clone)]
+pub struct ObjectResponse {
+    /// HTTP status code of the response
+    pub status_code: u16,
+    
+    /// The object data returned, which is a single byte for ranged reads
+    pub body: Vec<u8>,
+    
+    /// Response headers, keyed by lowercased name
+    pub headers: HashMap<String, String>,
+}
+
+/// Object operations the consistency pipeline needs from a storage backend
+/// 
+/// Errors use `S3Error` so callers can classify them the same way for every
+/// backend, e.g. `S3Error::HttpFailWithBody(404, _)` for a missing object.
+pub trait ObjectStore: std::fmt::Debug + Send + Sync {
+    /// Uploads an object with a single request
+    /// 
+    /// # Arguments
+    /// 
+    /// * `key` - The object key
+    /// * `data` - The object data
+    /// * `headers` - Extra request headers, e.g. object metadata
+    fn put<'a>(
+        &'a self,
+        key: &'a str,
+        data: &'a [u8],
+        headers: Option<HeaderMap>,
+    ) -> BoxFuture<'a, Result<(), S3Error>>;
+    
+    /// Reads an object, or an inclusive byte range of it
+    /// 
+    /// # Arguments
+    /// 
+    /// * `key` - The object key
+    /// * `range` - First and last byte to read, or `None` for the whole object
+    fn get<'a>(
+        &'a self,
+        key: &'a str,
+        range: Option<(u64, u64)>,
+    ) -> BoxFuture<'a, Result<ObjectResponse, S3Error>>;
+    
+    /// Checks whether an object exists
+    /// 
+    /// # Arguments
+    /// 
+    /// * `key` - The object key
+    /// 
+    /// # Returns
+    /// 
+    /// - `Ok(true)` if the object exists, `Ok(false)` if it does not
+    /// - `Err(S3Error)` if the check itself failed
+    fn head<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<bool, S3Error>>;
+    
+    /// Reads an object's metadata without its body
+    /// 
+    /// # Arguments
+    /// 
+    /// * `key` - The object key
+    /// 
+    /// # Returns
+    /// 
+    /// - `Ok(Some(headers))` with the metadata keyed by lowercased header name,
+    ///   e.g. `content-length`, `content-type` and `x-amz-meta-*`
+    /// - `Ok(None)` if the object does not exist
+    /// - `Err(S3Error)` if the request itself failed
+    fn metadata<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<Option<HashMap<String, String>>, S3Error>>;
+    
+    /// Deletes an object
+    /// 
+    /// # Arguments
+    /// 
+    /// * `key` - The object key
+    fn delete<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<(), S3Error>>;
+    
+    /// Lists one page of the keys under a prefix
+    /// 
+    /// # Arguments
+    /// 
+    /// * `prefix` - Key prefix to list
+    /// * `marker` - Continuation token or marker returned by the previous page
+    /// * `max_keys` - Maximum number of keys to return
+    fn list<'a>(
+        &'a self,
+        prefix: &'a str,
+        marker: Option<String>,
+        max_keys: Option<usize>,
+    ) -> BoxFuture<'a, Result<ListBucketResult, S3Error>>;
+}
+
+impl ObjectStore for Bucket {
+    fn put<'a>(
+        &'a self,
+        key: &'a str,
+        data: &'a [u8],
+        headers: Option<HeaderMap>,
+    ) -> BoxFuture<'a, Result<(), S3Error>> {
+        Box::pin(async move {
+            match headers {
+                Some(headers) => {
+                    self.put_object_with_content_type_and_headers(key, data, UPLOAD_CONTENT_TYPE, Some(headers)).await?;
+                }
+                None => {
+                    self.put_object(key, data).await?;
+                }
+            }
+            Ok(())
+        })
+    }
+    
+    fn get<'a>(
+        &'a self,
+        key: &'a str,
+        range: Option<(u64, u64)>,
+    ) -> BoxFuture<'a, Result<ObjectResponse, S3Error>> {
+        Box::pin(async move {
+            let response = match range {
+                Some((start, end)) => self.get_object_range(key, start, Some(end)).await?,
+                None => self.get_object(key).await?,
+            };
+            
+            Ok(ObjectResponse {
+                status_code: response.status_code(),
+                body: response.bytes().to_vec(),
+                headers: response.headers(),
+            })
+        })
+    }
+    
+    fn head<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<bool, S3Error>> {
+        Box::pin(async move {
+            match self.head_object(key).await {
+                Ok((_, 404)) | Err(S3Error::HttpFailWithBody(404, _)) => Ok(false),
+                Ok(_) => Ok(true),
+                Err(e) => Err(e),
+            }
+        })
+    }
+    
+    fn metadata<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<Option<HashMap<String, String>>, S3Error>> {
+        Box::pin(async move {
+            let head = match self.head_object(key).await {
+                Ok((_, 404)) | Err(S3Error::HttpFailWithBody(404, _)) => return Ok(None),
+                Ok((head, _)) => head,
+                Err(e) => return Err(e),
+            };
+            
+            let mut headers: HashMap<String, String> = head.metadata.unwrap_or_default()
+                .into_iter()
+                .map(|(name, value)| (format!("x-amz-meta-{}", name.to_lowercase()), value))
+                .collect();
+            if let Some(length) = head.content_length {
+                headers.insert("content-length".to_string(), length.to_string());
+            }
+            if let Some(content_type) = head.content_type {
+                headers.insert("content-type".to_string(), content_type);
+            }
+            if let Some(e_tag) = head.e_tag {
+                headers.insert("etag".to_string(), e_tag);
+            }
+            Ok(Some(headers))
+        })
+    }
+    
+    fn delete<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<(), S3Error>> {
+        Box::pin(async move {
+            self.delete_object(key).await?;
+            Ok(())
+        })
+    }
+    
+    fn list<'a>(
+        &'a self,
+        prefix: &'a str,
+        marker: Option<String>,
+        max_keys: Option<usize>,
+    ) -> BoxFuture<'a, Result<ListBucketResult, S3Error>> {
+        Box::pin(async move {
+            let (page, _) = self.list_page(prefix.to_string(), None, marker, None, max_keys).await?;
+            Ok(page)
+        })
+    }
+}
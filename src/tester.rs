@@ -4,311 +4,3313 @@
 //! `S3ConsistencyTester` struct that orchestrates the testing process.
 //! It handles S3 connection setup, file upload/read operations, and timing measurements.
 
+use crate::availability::AvailabilityProbe;
+use crate::batch_delete::{element_text, xml_unescape};
+use crate::checksum::{CHECKSUM_MODE_HEADER, ChecksumAlgorithm};
 use crate::cleanup::CleanupManager;
 use crate::config::S3Config;
+use crate::diagnostics::{describe_failed_request, diagnose_timing};
+use crate::error::ConsistencyTestError;
+use crate::failure_log::{FAILED_FIELD, FILE_SPAN, KEY_FIELD};
+use crate::keys::{KeyGenerator, KeyStyle};
+use crate::requests::{RequestCounter, RequestKind};
+use crate::results::ResultCollector;
 use crate::statistics;
-use crate::types::{Args, ConsistencyReport, TestParameters, TestResult};
+use crate::status_pattern::{ReadStatus, find_status_anomaly, format_status_sequence};
+use crate::storage::{ObjectStore, UPLOAD_CONTENT_TYPE};
+use crate::sts::{BucketBuilder, RoleSession, SessionStore};
+use crate::throttle::{THROTTLE_CHUNK_SIZE, UploadThrottle};
+use crate::tui::Dashboard;
+use crate::types::{Args, BreakpointLevel, BreakpointReport, ConnectionTiming, ConsistencyReport, EndpointSelection, FileTimeline, ListCompletenessResult, OverwriteRaceResult, TestParameters, TestResult};
 
-use anyhow::{Context, Result};
-use chrono::Utc;
-use rand::Rng;
+use chrono::{DateTime, Utc};
+use futures::future::{self, join_all};
+use futures::stream::{self, StreamExt};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use reqwest::header::{CONTENT_TYPE, HeaderMap, HeaderValue, RANGE};
 use s3::creds::Credentials;
+use s3::error::S3Error;
+use s3::serde_types::ListBucketResult;
 use s3::{Bucket, Region};
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::io;
+use std::pin::Pin;
 use std::str::FromStr;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::task::{self, Poll};
 use std::time::{Duration, Instant};
-use tokio::time::{sleep, timeout};
-use tracing::{debug, error, info, warn};
-use uuid::Uuid;
+use tokio::io::{AsyncRead, ReadBuf};
+use tokio::time::{Sleep, sleep, sleep_until, timeout};
+use tokio_util::sync::CancellationToken;
+use tracing::{Instrument, Span, debug, debug_span, error, field, info, warn};
 
-/// Core S3 consistency tester
+/// Creates an S3 bucket handle from a configuration
 /// 
-/// This structure manages S3 connections and orchestrates consistency testing.
-/// It handles file uploads, consistency checks, cleanup operations, and result compilation.
+/// Builds credentials and the region (standard AWS or custom endpoint),
+/// applies the path-style setting and routes requests through a proxy
+/// when one is configured.
 /// 
-/// # Examples
+/// # Arguments
 /// 
-/// ```rust
-/// let config = S3Config { /* ... */ };
-/// let tester = S3ConsistencyTester::new(config).await?;
-/// let report = tester.run_consistency_test(&args).await?;
-/// ```
-pub struct S3ConsistencyTester {
-    /// S3 bucket handle for all operations
-    bucket: Bucket,
+/// * `config` - S3 configuration including endpoint, credentials, and bucket
+/// 
+/// # Returns
+/// 
+/// A bucket handle ready for S3 operations
+/// 
+/// # Errors
+/// 
+/// Returns `ConsistencyTestError::Config` if:
+/// - Credentials are invalid
+/// - Region parsing fails
+/// - The proxy URL is invalid
+/// 
+/// Returns `ConsistencyTestError::Connection` if the bucket handle or the
+/// proxied client cannot be created
+fn create_bucket(config: &S3Config) -> std::result::Result<Box<Bucket>, ConsistencyTestError> {
+    // Create S3 credentials
+    let credentials = Credentials::new(
+        Some(&config.access_key),
+        Some(&config.secret_key),
+        config.session_token.as_deref(),
+        None, // profile
+        None, // role_arn
+    ).map_err(|e| ConsistencyTestError::Config(format!("Failed to create S3 credentials: {}", e)))?;
+
+    // Determine the appropriate region configuration
+    let region = if config.is_aws_s3() {
+        // For AWS S3, parse the region string
+        Region::from_str(&config.region)
+            .map_err(|e| ConsistencyTestError::Config(format!("Invalid AWS region {}: {}", config.region, e)))?
+    } else {
+        // For custom S3 services, use custom region with endpoint
+        Region::Custom {
+            region: config.region.clone(),
+            endpoint: config.endpoint.clone(),
+        }
+    };
+    
+    debug!("Using S3 region: {:?}", region);
+
+    // Create bucket handle
+    let mut bucket = Bucket::new(&config.bucket, region, credentials)
+        .map_err(|e| ConsistencyTestError::Connection {
+            endpoint: config.endpoint.clone(),
+            message: format!("Failed to create S3 bucket handle for {}: {}", config.bucket, e),
+        })?;
+    
+    // Configure path style if needed
+    if config.get_path_style() {
+        debug!("Using path-style URLs");
+        bucket = bucket.with_path_style();
+    } else {
+        debug!("Using virtual-hosted-style URLs");
+    }
+    
+    // Route requests through an explicitly configured proxy; environment
+    // proxies, including NO_PROXY exclusions, are applied by the HTTP client
+    if let Some(proxy_url) = &config.proxy {
+        let proxy = reqwest::Proxy::all(proxy_url)
+            .map_err(|e| ConsistencyTestError::Config(format!("Invalid proxy URL: {}", e)))?;
+        bucket = Box::new(bucket.set_proxy(proxy)
+            .map_err(|e| ConsistencyTestError::Connection {
+                endpoint: config.endpoint.clone(),
+                message: format!("Failed to configure HTTP proxy: {}", e),
+            })?);
+        debug!("Using HTTP proxy for S3 requests");
+    }
+    
+    Ok(bucket)
+}
+
+/// Builds the extra headers sent with every upload
+/// 
+/// # Arguments
+/// 
+/// * `config` - S3 configuration with the upload settings
+/// 
+/// # Returns
+/// 
+/// The headers to add to `put_object` requests, which may be empty
+/// 
+/// # Errors
+/// 
+/// Returns `ConsistencyTestError::Config` if a configured value is not a
+/// valid header value
+fn upload_headers(config: &S3Config) -> std::result::Result<HeaderMap, ConsistencyTestError> {
+    let mut headers = HeaderMap::new();
+    
+    if let Some(acl) = &config.acl {
+        headers.insert(
+            "x-amz-acl",
+            HeaderValue::from_str(acl)
+                .map_err(|_| ConsistencyTestError::Config(format!("Invalid ACL: {}", acl)))?,
+        );
+    }
+    
+    if let Some(tagging) = config.tagging() {
+        headers.insert(
+            "x-amz-tagging",
+            HeaderValue::from_str(&tagging)
+                .map_err(|_| ConsistencyTestError::Config(format!("Invalid tags: {}", tagging)))?,
+        );
+    }
+    
+    Ok(headers)
+}
+
+/// Creates a bucket handle for uploads
+/// 
+/// # Arguments
+/// 
+/// * `config` - S3 configuration with the upload settings
+/// 
+/// # Returns
+/// 
+/// A bucket handle that sends the `upload_headers` with every request
+fn upload_client(config: &S3Config) -> std::result::Result<Box<Bucket>, ConsistencyTestError> {
+    let bucket = create_bucket(config)?;
+    bucket.with_extra_headers(upload_headers(config)?)
+        .map(Box::new)
+        .map_err(|e| ConsistencyTestError::Config(format!("Failed to configure upload headers: {}", e)))
+}
+
+/// Creates a bucket handle for consistency reads
+/// 
+/// # Arguments
+/// 
+/// * `config` - S3 configuration for the endpoint, whose `read_*` settings
+///   are used if present
+/// * `checksum_mode` - Whether to ask for the object's stored checksum with
+///   every request
+/// 
+/// # Returns
+/// 
+/// A bucket handle for the reader configuration
+fn reader_bucket(config: &S3Config, checksum_mode: bool) -> std::result::Result<Box<Bucket>, ConsistencyTestError> {
+    let reader_config = config.reader_config().unwrap_or_else(|| config.clone());
+    let bucket = create_bucket(&reader_config)?;
+    if !checksum_mode {
+        return Ok(bucket);
+    }
+    
+    let mut headers = HeaderMap::new();
+    headers.insert(CHECKSUM_MODE_HEADER, HeaderValue::from_static("ENABLED"));
+    bucket.with_extra_headers(headers)
+        .map(Box::new)
+        .map_err(|e| ConsistencyTestError::Config(format!("Failed to configure checksum mode: {}", e)))
+}
+
+/// Creates the object store for a configuration
+/// 
+/// # Arguments
+/// 
+/// * `session` - The assumed role to sign with, if `assume_role_arn` is set
+/// * `config` - S3 configuration to build the bucket handle from
+/// * `build` - Function building the bucket handle, e.g. `create_bucket`
+/// 
+/// # Returns
+/// 
+/// A `SessionStore` following the role's credentials if a session is given,
+/// otherwise the bucket handle itself
+fn object_store(
+    session: Option<&Arc<RoleSession>>,
+    config: &S3Config,
+    build: BucketBuilder,
+) -> std::result::Result<Arc<dyn ObjectStore>, ConsistencyTestError> {
+    Ok(match session {
+        Some(session) => Arc::new(SessionStore::new(session.clone(), config, build)?),
+        None => Arc::new(*build(config)?),
+    })
+}
+
+/// Builds the server-side encryption headers sent with each upload
+/// 
+/// Unlike the ACL, these are not set on the bucket handle, since S3 rejects
+/// encryption headers on reads and HEAD requests.
+/// 
+/// # Arguments
+/// 
+/// * `config` - S3 configuration with the encryption settings
+/// 
+/// # Returns
+/// 
+/// The headers to add to each `put` request, empty without `sse`
+/// 
+/// # Errors
+/// 
+/// Returns `ConsistencyTestError::Config` if a configured value is not a
+/// valid header value
+fn sse_headers(config: &S3Config) -> std::result::Result<HeaderMap, ConsistencyTestError> {
+    let mut headers = HeaderMap::new();
+    
+    if let Some(sse) = &config.sse {
+        headers.insert(
+            "x-amz-server-side-encryption",
+            HeaderValue::from_str(sse)
+                .map_err(|_| ConsistencyTestError::Config(format!("Invalid server-side encryption: {}", sse)))?,
+        );
+    }
+    if let Some(key_id) = &config.sse_kms_key_id {
+        headers.insert(
+            "x-amz-server-side-encryption-aws-kms-key-id",
+            HeaderValue::from_str(key_id)
+                .map_err(|_| ConsistencyTestError::Config(format!("Invalid KMS key ID: {}", key_id)))?,
+        );
+    }
+    
+    Ok(headers)
+}
+
+/// Classifies a read error as non-retryable
+/// 
+/// Authorization failures (401/403) and credential errors will not resolve
+/// by waiting for propagation, unlike 404s, 5xx responses or timeouts.
+/// 
+/// # Arguments
+/// 
+/// * `error` - The error returned by a read attempt
+/// 
+/// # Returns
+/// 
+/// A description of the error if it is non-retryable, `None` otherwise
+fn non_retryable_reason(error: &S3Error) -> Option<String> {
+    match error {
+        S3Error::HttpFailWithBody(status @ (401 | 403), _) => {
+            Some(format!("HTTP {} (access denied)", status))
+        }
+        S3Error::Credentials(e) => Some(format!("Credential error: {}", e)),
+        _ => None,
+    }
+}
+
+/// Category recorded for a request that exceeded the request timeout
+const TIMEOUT_ERROR_CATEGORY: &str = "timeout";
+
+/// Classifies a failed request for the error retry outcome table
+/// 
+/// # Arguments
+/// 
+/// * `error` - The error returned by the request
+/// 
+/// # Returns
+/// 
+/// The HTTP status for error responses, e.g. `"503"`, or a short
+/// description of the kind of failure otherwise
+fn error_category(error: &S3Error) -> String {
+    match error {
+        S3Error::HttpFailWithBody(status, _) => status.to_string(),
+        S3Error::Credentials(_) => "credentials".to_string(),
+        _ => "request error".to_string(),
+    }
+}
+
+/// Extracts the S3 error code from a failed request
+/// 
+/// # Arguments
+/// 
+/// * `error` - The error returned by the request
+/// 
+/// # Returns
+/// 
+/// The `<Code>` of the error response, e.g. `"SlowDown"`, or `None` if the
+/// request got no response or the response carried no code, as for `HEAD`
+/// requests
+fn s3_error_code(error: &S3Error) -> Option<String> {
+    match error {
+        S3Error::HttpFailWithBody(_, body) => match element_text(body, "Code").trim() {
+            "" => None,
+            code => Some(xml_unescape(code)),
+        },
+        _ => None,
+    }
+}
+
+/// Counts one occurrence of an error category
+fn count_error(errors: &mut HashMap<String, u32>, category: String) {
+    *errors.entry(category).or_default() += 1;
+}
+
+/// Lists every key under a prefix, following pagination
+/// 
+/// # Arguments
+/// 
+/// * `storage` - Storage to list
+/// * `prefix` - Key prefix to list
+/// * `requests` - Counter that each page request is recorded in
+/// 
+/// # Returns
+/// 
+/// All keys returned across every page of the listing
+async fn list_all_keys(storage: &dyn ObjectStore, prefix: &str, requests: &RequestCounter) -> std::result::Result<Vec<String>, S3Error> {
+    let pages = list_pages(storage, prefix, None, requests).await?;
+    Ok(pages.into_iter().flatten().collect())
+}
+
+/// Lists every page of the keys under a prefix
+/// 
+/// Works with both ListObjectsV2 (continuation tokens) and ListObjects v1
+/// (markers), since some S3-compatible backends only support the latter.
+/// 
+/// # Arguments
+/// 
+/// * `storage` - Storage to list
+/// * `prefix` - Key prefix to list
+/// * `page_size` - Maximum keys per page, or `None` for the backend's default
+/// * `requests` - Counter that each page request is recorded in
+/// 
+/// # Returns
+/// 
+/// The keys of each page, in the order the pages were returned
+async fn list_pages(
+    storage: &dyn ObjectStore,
+    prefix: &str,
+    page_size: Option<usize>,
+    requests: &RequestCounter,
+) -> std::result::Result<Vec<Vec<String>>, S3Error> {
+    let mut pages = Vec::new();
+    let mut marker = None;
+    
+    loop {
+        requests.record(RequestKind::List);
+        let page = storage.list(prefix, marker, page_size).await?;
+        marker = next_list_marker(&page);
+        pages.push(page.contents.into_iter().map(|object| object.key).collect());
+        
+        if marker.is_none() {
+            return Ok(pages);
+        }
+    }
+}
+
+/// Determines where the next page of a listing starts
+/// 
+/// # Arguments
+/// 
+/// * `page` - The listing page just received
+/// 
+/// # Returns
+/// 
+/// The continuation token (V2) or last key (v1 marker) to request the next
+/// page with, or `None` if the listing is complete
+fn next_list_marker(page: &ListBucketResult) -> Option<String> {
+    if !page.is_truncated {
+        return None;
+    }
+    
+    page.next_continuation_token
+        .clone()
+        .or_else(|| page.contents.last().map(|object| object.key.clone()))
+}
+
+/// Consecutive listings a deleted key must be absent from to count as gone
+/// 
+/// Guards against listings where a deleted key transiently reappears.
+const DELETE_LIST_CONFIRMATIONS: u32 = 3;
+
+/// Consecutive reads of the same payload that settle an overwrite race
+/// 
+/// Guards against counting a payload as the winner while reads are still
+/// switching between the racing writers' payloads.
+const OVERWRITE_STABLE_READS: u32 = 5;
+
+/// Consecutive 404s confirming a deleted key is gone before it is re-created
+/// 
+/// Guards against re-creating the key while some reads still return the
+/// deleted object.
+const TOMBSTONE_CONFIRMATIONS: u32 = 3;
+
+/// Check interval the auto-tuner starts from, before any files are observed
+const AUTO_INTERVAL_INITIAL_MS: u64 = 10;
+
+/// Smallest check interval the auto-tuner will choose
+const AUTO_INTERVAL_MIN_MS: u64 = 5;
+
+/// Largest check interval the auto-tuner will choose
+const AUTO_INTERVAL_MAX_MS: u64 = 5000;
+
+/// Number of read attempts per file the auto-tuner aims for
+const AUTO_INTERVAL_TARGET_ATTEMPTS: u64 = 20;
+
+/// Number of recent propagation times the auto-tuner averages over
+const AUTO_INTERVAL_WINDOW: usize = 5;
+
+/// Delay before retrying a file whose upload failed
+const FILE_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// Metadata header holding the payload hash for `--self-verify`
+const CONTENT_HASH_HEADER: &str = "x-amz-meta-content-sha256";
+
+/// Number of hex digits of the payload's SHA-256 stored for `--self-verify`
+const CONTENT_HASH_LEN: usize = 16;
+
+/// Number of times `--check-collision` generates a new key for a file
+/// before failing it
+const MAX_KEY_REGENERATIONS: u32 = 3;
+
+/// Disagreement between the wall-clock and monotonic propagation durations
+/// beyond which the system clock is reported as having changed mid-test
+const CLOCK_CHANGE_TOLERANCE: Duration = Duration::from_millis(100);
+
+/// Delay before the first connectivity retry, doubled for each later retry
+const CONNECT_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// Upper bound on the delay between connectivity retries
+const CONNECT_RETRY_MAX_DELAY: Duration = Duration::from_secs(30);
+
+/// Async reader producing a fixed amount of random data
+/// 
+/// Used to stream large test files to S3 chunk by chunk, so the whole
+/// payload is never held in memory at once. With a throttle, data is only
+/// produced as fast as the upload bandwidth limit allows.
+struct RandomDataReader {
+    /// Number of bytes still to be produced
+    remaining: usize,
+    
+    /// Generator for the random bytes
+    rng: StdRng,
+    
+    /// Upload bandwidth limit the produced data is paced against, if any
+    throttle: Option<Arc<UploadThrottle>>,
+    
+    /// Bytes of the current throttle reservation not yet produced
+    reserved: usize,
+    
+    /// Wait for the current reservation's slot to start
+    wait: Option<Pin<Box<Sleep>>>,
+}
+
+impl RandomDataReader {
+    /// Creates a reader that produces `size` random bytes
+    fn new(size: usize) -> Self {
+        Self {
+            remaining: size,
+            rng: StdRng::from_entropy(),
+            throttle: None,
+            reserved: 0,
+            wait: None,
+        }
+    }
+    
+    /// Paces the produced data against an upload bandwidth limit
+    fn with_throttle(mut self, throttle: Option<Arc<UploadThrottle>>) -> Self {
+        self.throttle = throttle;
+        self
+    }
+}
+
+impl AsyncRead for RandomDataReader {
+    fn poll_read(self: Pin<&mut Self>, cx: &mut task::Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        let mut len = this.remaining.min(buf.remaining());
+        
+        if let Some(throttle) = &this.throttle
+            && len > 0
+        {
+            if this.reserved == 0 {
+                this.reserved = this.remaining.min(THROTTLE_CHUNK_SIZE);
+                this.wait = Some(Box::pin(sleep_until(throttle.reserve(this.reserved))));
+            }
+            if let Some(wait) = &mut this.wait {
+                task::ready!(wait.as_mut().poll(cx));
+                this.wait = None;
+            }
+            len = len.min(this.reserved);
+            this.reserved -= len;
+        }
+        
+        this.rng.fill(buf.initialize_unfilled_to(len));
+        buf.advance(len);
+        this.remaining -= len;
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// Builds the headers storing a payload's hash in the object's metadata
+/// 
+/// # Arguments
+/// 
+/// * `data` - The payload being uploaded
+/// 
+/// # Returns
+/// 
+/// Headers to send with the upload
+fn content_hash_headers(data: &[u8]) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        CONTENT_HASH_HEADER,
+        HeaderValue::from_str(&content_hash(data)).expect("hex digits are a valid header value"),
+    );
+    headers
+}
+
+/// Calculates the short payload hash stored for `--self-verify`
+/// 
+/// # Arguments
+/// 
+/// * `data` - The payload to hash
+/// 
+/// # Returns
+/// 
+/// The first `CONTENT_HASH_LEN` hex digits of the payload's SHA-256
+fn content_hash(data: &[u8]) -> String {
+    let mut hash = hex::encode(Sha256::digest(data));
+    hash.truncate(CONTENT_HASH_LEN);
+    hash
+}
+
+/// Warns when the system clock changed while a file was being polled
+/// 
+/// # Arguments
+/// 
+/// * `result` - The result holding the wall-clock upload and read timestamps
+/// * `propagation` - The monotonic propagation duration
+fn warn_on_clock_change(result: &TestResult, propagation: Duration) {
+    let Some(success_time) = result.first_read_success_time else {
+        return;
+    };
+    let wall_clock_ms = success_time.signed_duration_since(result.upload_time).num_milliseconds();
+    let drift_ms = (i128::from(wall_clock_ms) - propagation.as_millis() as i128).unsigned_abs();
+    
+    if drift_ms > CLOCK_CHANGE_TOLERANCE.as_millis() {
+        warn!(
+            "🕰️ System clock changed while polling {} (wall clock {}ms, monotonic {}ms), using the monotonic measurement",
+            result.file_key, wall_clock_ms, propagation.as_millis()
+        );
+    }
+}
+
+/// Decides whether a file gets a simulated organic read, and when
+/// 
+/// Readers arriving independently at a constant rate form a Poisson
+/// process, so the time until the first of them touches the file is
+/// exponentially distributed with the given mean.
+/// 
+/// # Arguments
+/// 
+/// * `rng` - Random number generator to sample from
+/// * `fraction` - Probability that the file is sampled at all (0.0 to 1.0)
+/// * `mean_ms` - Mean delay after upload in milliseconds
+/// * `max_delay` - Upper bound on the delay, normally `--max-wait`
+/// 
+/// # Returns
+/// 
+/// The delay after upload completion to read at, or `None` if the file
+/// was not sampled
+fn sample_organic_delay<R: Rng>(rng: &mut R, fraction: f64, mean_ms: u64, max_delay: Duration) -> Option<Duration> {
+    if !rng.gen_bool(fraction) {
+        return None;
+    }
+    
+    // Inverse transform sampling: -mean * ln(1 - U) for U uniform in [0, 1)
+    let uniform = rng.gen_range(0.0..1.0);
+    let delay_ms = -(mean_ms as f64) * (1.0 - uniform).ln();
+    Some(Duration::from_secs_f64(delay_ms / 1000.0).min(max_delay))
+}
+
+/// Describes a file that did not become readable within `--max-wait`
+/// 
+/// A `max_wait` of zero allows exactly one read attempt, so that case is
+/// reported as a failed single read rather than a timeout.
+/// 
+/// # Arguments
+/// 
+/// * `attempts` - Number of read attempts made
+/// * `elapsed` - Time spent polling
+/// * `max_duration` - The `--max-wait` limit
+/// 
+/// # Returns
+/// 
+/// The error message for the failed result
+fn read_timeout_message(attempts: u32, elapsed: Duration, max_duration: Duration) -> String {
+    if max_duration.is_zero() {
+        return "File was not readable on the single attempt allowed by --max-wait 0".to_string();
+    }
+    
+    format!(
+        "Consistency test timed out after {} attempts in {}ms (max: {}ms)",
+        attempts,
+        elapsed.as_millis(),
+        max_duration.as_millis()
+    )
+}
+
+/// Picks the `--capture-header` headers out of a read response
+/// 
+/// Header names are matched case-insensitively and stored lowercased.
+/// Headers missing from the response are left out.
+/// 
+/// # Arguments
+/// 
+/// * `headers` - The response headers
+/// * `names` - The header names to capture
+/// 
+/// # Returns
+/// 
+/// The captured headers, keyed by lowercased name
+fn capture_headers(headers: &HashMap<String, String>, names: &[String]) -> HashMap<String, String> {
+    names.iter()
+        .filter_map(|name| {
+            headers.iter()
+                .find(|(header, _)| header.eq_ignore_ascii_case(name))
+                .map(|(_, value)| (name.to_ascii_lowercase(), value.clone()))
+        })
+        .collect()
+}
+
+/// Logs how large files will be handled, if the file size calls for streaming
+/// 
+/// # Arguments
+/// 
+/// * `args` - Test arguments containing the file size and stream threshold
+fn log_large_file_handling(args: &Args) {
+    if args.max_file_size() > args.stream_threshold {
+        warn!(
+            "📦 File size {} bytes exceeds the stream threshold of {} bytes: uploads will be streamed and reads will fetch only the first byte",
+            args.max_file_size(), args.stream_threshold
+        );
+    }
+}
+
+/// Adapts the check interval to recently observed propagation times
+/// 
+/// The interval is chosen so that a file with the average recent propagation
+/// time would need about `AUTO_INTERVAL_TARGET_ATTEMPTS` read attempts.
+struct IntervalTuner {
+    /// Most recent successful propagation times in milliseconds
+    recent_durations: VecDeque<u64>,
+}
+
+impl IntervalTuner {
+    /// Creates a tuner with no observations
+    fn new() -> Self {
+        Self {
+            recent_durations: VecDeque::with_capacity(AUTO_INTERVAL_WINDOW),
+        }
+    }
+    
+    /// Records a successful propagation time
+    fn observe(&mut self, propagation_ms: u64) {
+        if self.recent_durations.len() == AUTO_INTERVAL_WINDOW {
+            self.recent_durations.pop_front();
+        }
+        self.recent_durations.push_back(propagation_ms);
+    }
+    
+    /// Gets the interval to use for the next file
+    fn interval_ms(&self) -> u64 {
+        if self.recent_durations.is_empty() {
+            return AUTO_INTERVAL_INITIAL_MS;
+        }
+        
+        let avg = self.recent_durations.iter().sum::<u64>() / self.recent_durations.len() as u64;
+        (avg / AUTO_INTERVAL_TARGET_ATTEMPTS).clamp(AUTO_INTERVAL_MIN_MS, AUTO_INTERVAL_MAX_MS)
+    }
+}
+
+/// A test file that has been uploaded but not yet polled for consistency
+struct UploadedFile {
+    /// The S3 object key of the uploaded file
+    file_key: String,
+    
+    /// Timestamp when the upload completed
+    upload_time: DateTime<Utc>,
+    
+    /// Monotonic instant when the upload completed, the baseline for propagation
+    upload_instant: Instant,
+    
+    /// Timestamp when the first upload attempt was sent
+    upload_start: DateTime<Utc>,
+    
+    /// Number of failed upload attempts before the successful one
+    retries: u32,
+    
+    /// Categories of the failed upload attempts, which the retries recovered from
+    upload_errors: HashMap<String, u32>,
+    
+    /// S3 error code of the last failed upload attempt that returned one
+    upload_error_code: Option<String>,
+    
+    /// Whether the file was uploaded with a multipart upload
+    multipart: bool,
+    
+    /// Whether the payload hash was stored in the object's metadata
+    self_verify: bool,
+    
+    /// Checksum algorithm the upload was sent with, if any
+    checksum: Option<ChecksumAlgorithm>,
+    
+    /// Number of generated keys that already existed before this one
+    key_collisions: u32,
+    
+    /// Size of the uploaded file in bytes
+    file_size: usize,
+    
+    /// Index of the endpoint the file was uploaded through
+    upload_endpoint: usize,
+    
+    /// Index of the endpoint the file is polled through
+    read_endpoint: usize,
+    
+    /// Span collecting the file's logs for `--verbose-failures`
+    /// 
+    /// A disabled span unless that flag is set.
+    span: Span,
+}
+
+/// What polling observed about a file besides its outcome
+#[derive(Default)]
+struct ReadObservations {
+    /// Number of reads whose body length was wrong
+    size_mismatches: u32,
+    
+    /// Whether a streak of `--consecutive-reads` reads was broken
+    flapping: bool,
+    
+    /// Send time of each attempt, recorded with `--detailed-timeline`
+    poll_times: Vec<DateTime<Utc>>,
+    
+    /// Status of each attempt, recorded with `--expect-404-first`
+    statuses: Vec<ReadStatus>,
+    
+    /// Categories of the failed attempts, other than the expected 404s
+    errors: HashMap<String, u32>,
+    
+    /// S3 error code of the last failed attempt that returned one, other than the expected 404s
+    last_error_code: Option<String>,
+}
+
+/// A run of matching successful reads for `--consecutive-reads`
+struct ReadStreak {
+    /// The first read of the streak, reported once the streak completes
+    first: ReadSuccess,
+    
+    /// Hash of the body returned by the first read of the streak
+    /// 
+    /// `None` when a single read suffices and the body is not compared.
+    body_hash: Option<String>,
+    
+    /// Number of matching reads in the streak so far
+    reads: u32,
+}
+
+/// Outcome of polling a file until it first became readable
+struct ReadSuccess {
+    /// Timestamp of the first successful read
+    success_time: DateTime<Utc>,
+    
+    /// Monotonic instant of the first successful read
+    success_instant: Instant,
+    
+    /// Number of read attempts made, including the successful one
+    attempts: u32,
+    
+    /// Time from the start of polling until the successful read (milliseconds)
+    elapsed_ms: u64,
+    
+    /// Total time spent sleeping between attempts (milliseconds)
+    sleep_ms: u64,
+    
+    /// Whether the body matched the payload hash in the object's metadata,
+    /// if it was checked
+    content_self_verified: Option<bool>,
+    
+    /// Whether the body matched the checksum returned with it, if it was checked
+    checksum_verified: Option<bool>,
+    
+    /// The `--capture-header` headers of the successful read
+    captured_headers: HashMap<String, String>,
+}
+
+/// Clients for one of several equivalent endpoints, or one of several buckets
+struct EndpointClients {
+    /// The endpoint URL
+    url: String,
+    
+    /// The bucket the clients operate on
+    bucket: String,
+    
+    /// S3 bucket handle used for uploads
+    /// 
+    /// Carries any per-upload headers, such as the configured ACL and tags,
+    /// so they are not sent with reads and deletes.
+    upload_bucket: Box<Bucket>,
+    
+    /// Storage that single-request uploads, collision checks and listings go through
+    /// 
+    /// The upload bucket handle, unless replaced with `with_storage`.
+    storage: Arc<dyn ObjectStore>,
+    
+    /// Storage that consistency reads go through
+    /// 
+    /// A separate bucket handle built from the `read_*` configuration, so
+    /// reads can come from a different endpoint, region or credential than
+    /// writes, or a handle for this endpoint if none is configured.
+    reader_storage: Arc<dyn ObjectStore>,
+}
+
+impl EndpointClients {
+    /// Creates the clients for one endpoint and bucket
+    /// 
+    /// # Arguments
+    /// 
+    /// * `config` - S3 configuration shared by all endpoints
+    /// * `url` - The endpoint to connect to
+    /// * `bucket` - The bucket to operate on
+    /// * `session` - The assumed role to sign with, if `assume_role_arn` is set
+    /// 
+    /// # Returns
+    /// 
+    /// The upload and reader clients for the endpoint
+    fn connect(config: &S3Config, url: String, bucket: String, session: Option<&Arc<RoleSession>>) -> std::result::Result<Self, ConsistencyTestError> {
+        let config = S3Config {
+            endpoint: url.clone(),
+            bucket: bucket.clone(),
+            ..config.clone()
+        };
+        
+        // Streamed and multipart uploads keep the credentials the run started with
+        let upload_bucket = match session {
+            Some(session) => upload_client(&session.current().apply(&config))?,
+            None => upload_client(&config)?,
+        };
+        
+        // Reads use a separate reader client if they should go to a different endpoint
+        if let Some(reader_config) = config.reader_config() {
+            info!("📖 Using separate reader endpoint: {}", reader_config.endpoint);
+        }
+        
+        Ok(Self {
+            url,
+            bucket,
+            storage: object_store(session, &config, upload_client)?,
+            upload_bucket,
+            reader_storage: object_store(session, &config, |config| reader_bucket(config, false))?,
+        })
+    }
+    
+    /// Builds the configuration for this endpoint and bucket
+    /// 
+    /// # Arguments
+    /// 
+    /// * `config` - S3 configuration shared by all endpoints
+    /// 
+    /// # Returns
+    /// 
+    /// A copy of `config` with the endpoint and bucket replaced
+    fn config(&self, config: &S3Config) -> S3Config {
+        S3Config {
+            endpoint: self.url.clone(),
+            bucket: self.bucket.clone(),
+            ..config.clone()
+        }
+    }
+}
+
+/// Core S3 consistency tester
+/// 
+/// This structure manages S3 connections and orchestrates consistency testing.
+/// It handles file uploads, consistency checks, cleanup operations, and result compilation.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// let config = S3Config { /* ... */ };
+/// let tester = S3ConsistencyTester::new(config).await?;
+/// let report = tester.run_consistency_test(&args).await?;
+/// ```
+pub struct S3ConsistencyTester {
+    /// S3 bucket handle for cleanup and other bucket-level operations
+    bucket: Box<Bucket>,
+    
+    /// Clients for every endpoint in the configured endpoint pool, or for
+    /// every bucket in the bucket pool if the config lists several `buckets`
+    /// 
+    /// The first entry is the primary `endpoint` and `bucket`, which is used
+    /// for run-level operations such as the final cleanup check.
+    endpoints: Vec<EndpointClients>,
+    
+    /// Whether `endpoints` holds one entry per bucket of a sharded deployment
+    sharded: bool,
+    
+    /// Counter rotating `--endpoint-selection round-robin` through `endpoints`
+    next_endpoint: AtomicUsize,
+    
+    /// Index of the endpoint chosen by `--endpoint-selection lowest-latency`
+    fastest_endpoint: AtomicUsize,
+    
+    /// Storage for the `replication_target` bucket, polled by `--test-replication`
+    /// 
+    /// `None` if no replication target is configured.
+    replica_storage: Option<Arc<dyn ObjectStore>>,
+    
+    /// Configuration used for this tester
+    config: S3Config,
+    
+    /// Cleanup manager for handling test file cleanup
+    cleanup_manager: Arc<CleanupManager>,
+    
+    /// Counter of every S3 request made by the tester
+    /// 
+    /// Shared with the cleanup manager and the availability probe.
+    requests: Arc<RequestCounter>,
+    
+    /// Generator for test file keys
+    keys: KeyGenerator,
+    
+    /// Live dashboard that finished tests are reported to, if enabled
+    dashboard: Option<Arc<Dashboard>>,
+    
+    /// Token cancelled to stop starting new files, e.g. on the first Ctrl+C
+    /// 
+    /// Files already in flight are finished and reported.
+    stop: CancellationToken,
+    
+    /// Whether `--debug-first-failure` has already logged a failed request
+    first_failure_logged: AtomicBool,
+    
+    /// Server-side encryption headers sent with every single-request upload
+    /// 
+    /// Emptied by `verify_sse_support` if the backend rejects them.
+    sse_headers: HeaderMap,
+    
+    /// Whether the configured encryption was skipped as unsupported
+    sse_skipped: bool,
+    
+    /// Whether reads ask for the object's stored checksum, set by `enable_checksum_mode`
+    checksum_mode: bool,
+    
+    /// Upload bandwidth limit shared by all uploads, set by `limit_upload_bandwidth`
+    upload_throttle: Option<Arc<UploadThrottle>>,
+    
+    /// The assumed role every request is signed with, if `assume_role_arn` is set
+    role_session: Option<Arc<RoleSession>>,
+}
+
+impl S3ConsistencyTester {
+    /// Creates a new S3 consistency tester
+    /// 
+    /// Establishes connection to the S3 service using the provided configuration
+    /// and sets up the cleanup manager for handling test files. When the
+    /// configuration specifies a separate reader endpoint or credential, a
+    /// second client is created and used for all consistency polling. With
+    /// `assume_role_arn`, the role is assumed first and its temporary
+    /// credentials are used for every request.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `config` - S3 configuration including endpoint, credentials, and bucket
+    /// 
+    /// # Returns
+    /// 
+    /// A new `S3ConsistencyTester` instance ready for testing
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `ConsistencyTestError::Config` if credentials, the region, the
+    /// ACL or the proxy URL are invalid, and `ConsistencyTestError::Connection`
+    /// if a bucket handle cannot be created or the role cannot be assumed.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let config = S3Config {
+    ///     endpoint: "https://s3.amazonaws.com".to_string(),
+    ///     region: "us-east-1".to_string(),
+    ///     bucket: "my-test-bucket".to_string(),
+    ///     access_key: "AKIAIO...".to_string(),
+    ///     secret_key: "wJalr...".to_string(),
+    ///     path_style: Some(false),
+    ///     ..Default::default()
+    /// };
+    /// let tester = S3ConsistencyTester::new(config).await?;
+    /// ```
+    pub async fn new(config: S3Config) -> std::result::Result<Self, ConsistencyTestError> {
+        debug!("Creating S3 consistency tester for endpoint: {}", config.endpoint);
+        
+        let role_session = match &config.assume_role_arn {
+            Some(_) => Some(Arc::new(RoleSession::start(&config).await?)),
+            None => None,
+        };
+        
+        // Bucket handles used directly keep the credentials the run started with
+        let signing_config = match &role_session {
+            Some(session) => session.current().apply(&config),
+            None => config.clone(),
+        };
+        
+        let bucket = create_bucket(&signing_config)?;
+        let sse_headers = sse_headers(&config)?;
+        let buckets = config.bucket_pool();
+        let sharded = buckets.len() > 1;
+        let endpoints = config.endpoint_pool().into_iter()
+            .flat_map(|url| buckets.iter().map(move |bucket| (url.clone(), bucket.clone())))
+            .map(|(url, bucket)| EndpointClients::connect(&config, url, bucket, role_session.as_ref()))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        if sharded {
+            info!("🧩 Spreading test files across {} buckets", buckets.len());
+        } else if endpoints.len() > 1 {
+            info!("🔀 Spreading requests across {} endpoints", endpoints.len());
+        }
+
+        // Connect to the bucket that test files replicate to, if any
+        let replica_storage = config.replication_config()
+            .map(|replica_config| object_store(role_session.as_ref(), &replica_config, create_bucket))
+            .transpose()?;
+        
+        // Create cleanup manager, counting its deletes with the tester's requests
+        let requests = Arc::new(RequestCounter::default());
+        let mut cleanup_manager = CleanupManager::new(object_store(role_session.as_ref(), &config, create_bucket)?)
+            .with_batch_delete(*bucket.clone())
+            .with_request_counter(requests.clone());
+        if let Some(replica_config) = signing_config.replication_config() {
+            let replica = create_bucket(&replica_config)?;
+            info!("🪞 Test files are also cleaned up in replication target: {}", replica.name());
+            cleanup_manager = cleanup_manager.with_replica(
+                CleanupManager::new(object_store(role_session.as_ref(), &replica_config, create_bucket)?)
+                    .with_batch_delete(*replica)
+                    .with_request_counter(requests.clone())
+            );
+        }
+        for shard in buckets.into_iter().skip(1) {
+            let shard_config = S3Config { bucket: shard.clone(), ..config.clone() };
+            let shard_bucket = create_bucket(&S3Config { bucket: shard.clone(), ..signing_config.clone() })?;
+            cleanup_manager = cleanup_manager.with_shard(
+                shard,
+                CleanupManager::new(object_store(role_session.as_ref(), &shard_config, create_bucket)?)
+                    .with_batch_delete(*shard_bucket)
+                    .with_request_counter(requests.clone())
+            );
+        }
+        let cleanup_manager = Arc::new(cleanup_manager);
+
+        info!("Successfully connected to S3 bucket: {}", config.bucket);
+        
+        let keys = KeyGenerator::new(&config.key_prefix());
+        Ok(Self {
+            bucket,
+            endpoints,
+            sharded,
+            next_endpoint: AtomicUsize::new(0),
+            fastest_endpoint: AtomicUsize::new(0),
+            replica_storage,
+            config,
+            cleanup_manager,
+            requests,
+            keys,
+            dashboard: None,
+            stop: CancellationToken::new(),
+            first_failure_logged: AtomicBool::new(false),
+            sse_headers,
+            sse_skipped: false,
+            checksum_mode: false,
+            upload_throttle: None,
+            role_session,
+        })
+    }
+    
+    /// Reports every finished test to a live dashboard
+    /// 
+    /// # Arguments
+    /// 
+    /// * `dashboard` - The dashboard to record results in
+    /// 
+    /// # Returns
+    /// 
+    /// The tester reporting to the given dashboard
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let tester = S3ConsistencyTester::new(config).await?.with_dashboard(dashboard.clone());
+    /// ```
+    pub fn with_dashboard(mut self, dashboard: Arc<Dashboard>) -> Self {
+        self.dashboard = Some(dashboard);
+        self
+    }
+    
+    /// Runs the per-file pipeline against another storage backend
+    /// 
+    /// Uploads, reads, collision checks, listings and cleanup deletes all go
+    /// through `storage` instead of the configured bucket, and only the
+    /// primary endpoint is used. Features that need the S3 protocol itself
+    /// (streamed and multipart uploads, fresh reader connections, connection
+    /// timing) still use the bucket handle.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `storage` - The backend to test against, e.g. the `--self-test` mock
+    /// 
+    /// # Returns
+    /// 
+    /// The tester using the given storage
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let storage = Arc::new(MockStorage::new(Duration::from_millis(200), 0.0));
+    /// let tester = S3ConsistencyTester::new(config).await?.with_storage(storage);
+    /// ```
+    #[cfg(any(test, feature = "self-test"))]
+    pub fn with_storage(mut self, storage: Arc<dyn ObjectStore>) -> Self {
+        self.cleanup_manager = Arc::new(
+            CleanupManager::new(storage.clone()).with_request_counter(self.requests.clone())
+        );
+        self.endpoints.truncate(1);
+        self.sharded = false;
+        let primary = &mut self.endpoints[0];
+        primary.reader_storage = storage.clone();
+        primary.storage = storage;
+        self
+    }
+    
+    /// Gets the clients for the primary endpoint
+    fn primary(&self) -> &EndpointClients {
+        &self.endpoints[0]
+    }
+    
+    /// Gets one client per bucket test files are uploaded to
+    /// 
+    /// # Returns
+    /// 
+    /// Every entry of `endpoints` when sharded, otherwise the primary only
+    fn shards(&self) -> &[EndpointClients] {
+        if self.sharded { &self.endpoints } else { &self.endpoints[..1] }
+    }
+    
+    /// Picks the endpoints the next file is uploaded and polled through
+    /// 
+    /// When sharded, the buckets are rotated through and each file is read
+    /// from the bucket it was uploaded to, regardless of `args`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `args` - Command-line arguments selecting the strategy
+    /// 
+    /// # Returns
+    /// 
+    /// Indices into `endpoints` for the upload and for the reads
+    fn select_endpoints(&self, args: &Args) -> (usize, usize) {
+        let count = self.endpoints.len();
+        if count == 1 {
+            return (0, 0);
+        }
+        if self.sharded {
+            let shard = self.next_endpoint.fetch_add(1, Ordering::Relaxed) % count;
+            return (shard, shard);
+        }
+        
+        match args.endpoint_selection {
+            EndpointSelection::RoundRobin => {
+                let upload = self.next_endpoint.fetch_add(1, Ordering::Relaxed) % count;
+                let read = self.next_endpoint.fetch_add(1, Ordering::Relaxed) % count;
+                (upload, read)
+            }
+            EndpointSelection::LowestLatency => {
+                let fastest = self.fastest_endpoint.load(Ordering::Relaxed);
+                (fastest, fastest)
+            }
+        }
+    }
+    
+    /// Probes every endpoint once and remembers the fastest
+    /// 
+    /// Only runs for `--endpoint-selection lowest-latency` with several
+    /// endpoints. Each probe is a single one-key listing through the reader
+    /// client; if every probe fails, the primary endpoint is used.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `args` - Command-line arguments selecting the strategy
+    async fn probe_endpoints(&self, args: &Args) {
+        if self.endpoints.len() == 1 || self.sharded || args.endpoint_selection != EndpointSelection::LowestLatency {
+            return;
+        }
+        
+        let mut fastest: Option<(usize, Duration)> = None;
+        for (index, endpoint) in self.endpoints.iter().enumerate() {
+            let start = Instant::now();
+            self.requests.record(RequestKind::List);
+            match timeout(Duration::from_secs(10), endpoint.reader_storage.list(&self.config.key_prefix(), None, Some(1))).await {
+                Ok(Ok(_)) => {
+                    let latency = start.elapsed();
+                    info!("📡 Endpoint {} answered in {:?}", endpoint.url, latency);
+                    if fastest.is_none_or(|(_, best)| latency < best) {
+                        fastest = Some((index, latency));
+                    }
+                }
+                Ok(Err(e)) => warn!("⚠️  Endpoint {} failed the latency probe: {}", endpoint.url, e),
+                Err(_) => warn!("⚠️  Endpoint {} timed out during the latency probe", endpoint.url),
+            }
+        }
+        
+        match fastest {
+            Some((index, _)) => {
+                info!("⚡ Using lowest-latency endpoint: {}", self.endpoints[index].url);
+                self.fastest_endpoint.store(index, Ordering::Relaxed);
+            }
+            None => warn!("⚠️  Every endpoint failed the latency probe, using {}", self.primary().url),
+        }
+    }
+    
+    /// Waits until every endpoint answers requests
+    /// 
+    /// Lists one key through each endpoint's upload client, and through its
+    /// reader client if reads use a separate configuration. An endpoint that
+    /// fails or does not answer within `connect_timeout` is checked again
+    /// after 1s, then 2s, 4s and so on, capped at 30s, until `retries` is
+    /// used up. Endpoints that already answered are not checked again.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `connect_timeout` - Time each check may take
+    /// * `retries` - Number of times a failed check is retried
+    /// 
+    /// # Returns
+    /// 
+    /// - `Ok(())` once every endpoint has answered
+    /// - `Err(ConsistencyTestError::Connection)` for the first endpoint that
+    ///   was still unreachable after the last retry
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let tester = S3ConsistencyTester::new(config).await?;
+    /// tester.connect_with_retry(Duration::from_secs(5), 10).await?;
+    /// ```
+    pub async fn connect_with_retry(&self, connect_timeout: Duration, retries: u32) -> std::result::Result<(), ConsistencyTestError> {
+        let separate_reader = self.config.reader_config().is_some();
+        let mut pending: Vec<(&str, &dyn ObjectStore)> = Vec::new();
+        for endpoint in &self.endpoints {
+            pending.push((endpoint.url.as_str(), endpoint.storage.as_ref()));
+            if separate_reader {
+                pending.push((endpoint.url.as_str(), endpoint.reader_storage.as_ref()));
+            }
+        }
+        
+        let mut delay = CONNECT_RETRY_DELAY;
+        for attempt in 0..=retries {
+            if attempt > 0 {
+                sleep(delay).await;
+                delay = (delay * 2).min(CONNECT_RETRY_MAX_DELAY);
+            }
+            
+            let mut failure = None;
+            let mut unreachable = Vec::new();
+            for (url, storage) in pending {
+                self.requests.record(RequestKind::List);
+                let message = match timeout(connect_timeout, storage.list(&self.config.key_prefix(), None, Some(1))).await {
+                    Ok(Ok(_)) => continue,
+                    Ok(Err(e)) => format!("Endpoint is not reachable: {}", e),
+                    Err(_) => format!("Endpoint did not answer within {}s", connect_timeout.as_secs()),
+                };
+                if failure.is_none() {
+                    failure = Some(ConsistencyTestError::Connection {
+                        endpoint: url.to_string(),
+                        message,
+                    });
+                }
+                unreachable.push((url, storage));
+            }
+            
+            let Some(failure) = failure else {
+                info!("🔌 All endpoints are reachable");
+                return Ok(());
+            };
+            if attempt == retries {
+                return Err(failure);
+            }
+            warn!("🔌 {}, retrying in {:?} ({}/{})...", failure, delay, attempt + 1, retries);
+            pending = unreachable;
+        }
+        
+        unreachable!("the last attempt always returns")
+    }
+    
+    /// Checks that the backend accepts the configured server-side encryption
+    /// 
+    /// Uploads and deletes a one-byte probe object with the encryption
+    /// headers. If the backend rejects them as unsupported (HTTP 400 or 501),
+    /// a warning is logged and the run continues without encryption, which
+    /// is recorded in the report's test parameters. Does nothing without
+    /// `sse` in the configuration.
+    /// 
+    /// # Returns
+    /// 
+    /// - `Ok(())` if encryption is supported, skipped, or not configured
+    /// - `Err(ConsistencyTestError::Upload)` if the probe upload failed for
+    ///   another reason, e.g. access to the KMS key was denied
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let mut tester = S3ConsistencyTester::new(config).await?;
+    /// tester.verify_sse_support().await?;
+    /// ```
+    pub async fn verify_sse_support(&mut self) -> std::result::Result<(), ConsistencyTestError> {
+        let Some(sse) = self.config.sse.clone() else {
+            return Ok(());
+        };
+        
+        let probe_key = self.keys.next_key(None, KeyStyle::Short);
+        let storage = self.primary().storage.clone();
+        self.requests.record(RequestKind::Upload);
+        match storage.put(&probe_key, b"0", Some(self.sse_headers.clone())).await {
+            Ok(()) => {
+                info!("🔐 Backend accepted server-side encryption {}", sse);
+                self.requests.record(RequestKind::Delete);
+                if let Err(e) = storage.delete(&probe_key).await {
+                    warn!("⚠️  Failed to delete encryption probe {}: {}", probe_key, e);
+                    self.cleanup_manager.register_file(&probe_key).await;
+                }
+                Ok(())
+            }
+            Err(S3Error::HttpFailWithBody(status @ (400 | 501), body)) => {
+                warn!(
+                    "⚠️  Backend does not support server-side encryption {} (HTTP {}: {}), uploading without it",
+                    sse, status, body.trim()
+                );
+                self.sse_headers.clear();
+                self.sse_skipped = true;
+                Ok(())
+            }
+            Err(e) => Err(ConsistencyTestError::Upload(e)),
+        }
+    }
+    
+    /// Makes every consistency read ask for the object's stored checksum
+    /// 
+    /// Replaces each endpoint's reader with one that sends
+    /// `x-amz-checksum-mode: ENABLED`, which `--checksum` needs to verify
+    /// the checksum returned with each read. Must be called before
+    /// `with_storage`, which would otherwise be overridden.
+    /// 
+    /// # Returns
+    /// 
+    /// - `Ok(())` once the readers have been replaced
+    /// - `Err(ConsistencyTestError::Config)` if a reader cannot be created
+    pub fn enable_checksum_mode(&mut self) -> std::result::Result<(), ConsistencyTestError> {
+        for endpoint in &mut self.endpoints {
+            let config = endpoint.config(&self.config);
+            endpoint.reader_storage = object_store(
+                self.role_session.as_ref(),
+                &config,
+                |config| reader_bucket(config, true),
+            )?;
+        }
+        self.checksum_mode = true;
+        Ok(())
+    }
+    
+    /// Limits the combined throughput of all uploads
+    /// 
+    /// Buffered uploads and multipart parts wait for their share of the
+    /// bandwidth before being sent, and streamed uploads produce their data
+    /// no faster than the limit allows.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `bytes_per_second` - Maximum upload throughput
+    pub fn limit_upload_bandwidth(&mut self, bytes_per_second: u64) {
+        self.upload_throttle = Some(Arc::new(UploadThrottle::new(bytes_per_second)));
+    }
+    
+    /// Builds the test parameters recorded in a report
+    /// 
+    /// # Arguments
+    /// 
+    /// * `args` - Command-line arguments the run used
+    /// 
+    /// # Returns
+    /// 
+    /// The parameters from `args`, plus the server-side encryption applied
+    fn test_parameters(&self, args: &Args) -> TestParameters {
+        let mut parameters = TestParameters::from(args);
+        if !self.sse_skipped {
+            parameters.sse = self.config.sse.clone();
+            parameters.sse_kms_key_id = self.config.sse_kms_key_id.clone();
+        }
+        parameters.sse_skipped = self.sse_skipped;
+        parameters
+    }
+    
+    /// Gets the URL of an endpoint for recording on a result
+    /// 
+    /// # Returns
+    /// 
+    /// The endpoint URL, or `None` if only one endpoint is configured
+    fn endpoint_url(&self, index: usize) -> Option<String> {
+        (self.endpoints.len() > 1 && !self.sharded).then(|| self.endpoints[index].url.clone())
+    }
+    
+    /// Gets the bucket of an endpoint for recording on a result
+    /// 
+    /// # Returns
+    /// 
+    /// The bucket name, or `None` if only one bucket is configured
+    fn bucket_name(&self, index: usize) -> Option<String> {
+        self.sharded.then(|| self.endpoints[index].bucket.clone())
+    }
+    
+    /// Creates a new reader bucket handle with its own connection pool
+    /// 
+    /// Used for `--fresh-connection`, where no read may reuse a connection.
+    /// With an assumed role, the handle signs with the role's current
+    /// credentials, which the shared clients keep renewed.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `endpoint` - The endpoint the reads are sent to
+    /// 
+    /// # Returns
+    /// 
+    /// A bucket handle for the reader configuration of the endpoint
+    fn fresh_reader(&self, endpoint: &EndpointClients) -> std::result::Result<Box<Bucket>, ConsistencyTestError> {
+        let config = endpoint.config(&self.config);
+        match &self.role_session {
+            Some(session) => reader_bucket(&session.current().apply(&config), self.checksum_mode),
+            None => reader_bucket(&config, self.checksum_mode),
+        }
+    }
+    
+    /// Gets a reference to the cleanup manager
+    /// 
+    /// This allows external code to set up signal handlers and perform
+    /// emergency cleanup operations.
+    /// 
+    /// # Returns
+    /// 
+    /// An `Arc` reference to the cleanup manager
+    pub fn cleanup_manager(&self) -> Arc<CleanupManager> {
+        self.cleanup_manager.clone()
+    }
+    
+    /// Gets the token that stops the run gracefully
+    /// 
+    /// Cancelling the token, e.g. from a signal handler, stops new files
+    /// from being started. Files already in flight are finished, and the run
+    /// returns a report for everything that completed.
+    /// 
+    /// # Returns
+    /// 
+    /// A clone of the tester's stop token
+    pub fn stop_token(&self) -> CancellationToken {
+        self.stop.clone()
+    }
+    
+    /// Verifies that no test files were left behind in the bucket
+    /// 
+    /// Lists the test key prefix and fails if any objects remain. This should
+    /// be called after the final cleanup check, and catches delete failures
+    /// that cleanup only logs as warnings. Leftover files from other runs
+    /// sharing the bucket are reported as well. When sharded, every bucket
+    /// is listed and remaining keys are prefixed with their bucket.
+    /// 
+    /// # Returns
+    /// 
+    /// - `Ok(())` if no test files remain
+    /// - `Err(ConsistencyTestError::Cleanup)` listing the remaining keys
+    /// - `Err(ConsistencyTestError::Connection)` if the listing fails
+    pub async fn verify_cleanup(&self) -> std::result::Result<(), ConsistencyTestError> {
+        info!("🔎 Verifying that no test files remain in the bucket...");
+        
+        let mut remaining = Vec::new();
+        for shard in self.shards() {
+            let keys = list_all_keys(shard.storage.as_ref(), &self.config.key_prefix(), &self.requests).await
+                .map_err(|e| ConsistencyTestError::Connection {
+                    endpoint: self.config.endpoint.clone(),
+                    message: format!("Failed to list bucket {} to verify cleanup: {}", shard.bucket, e),
+                })?;
+            remaining.extend(keys.into_iter()
+                .map(|key| if self.sharded { format!("{}/{}", shard.bucket, key) } else { key }));
+        }
+        
+        if remaining.is_empty() {
+            info!("✨ Bucket is clean, no test files remain");
+            return Ok(());
+        }
+        
+        for key in &remaining {
+            error!("🗑️  Test file remains after cleanup: {}", key);
+        }
+        
+        Err(ConsistencyTestError::Cleanup {
+            bucket: self.config.bucket_pool().join(", "),
+            remaining,
+        })
+    }
+    
+    /// Runs the complete consistency test suite
+    /// 
+    /// Executes the full testing process including file uploads, consistency checks,
+    /// statistics calculation, and report generation. This is the main entry point
+    /// for running consistency tests.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `args` - Command-line arguments specifying test parameters
+    /// 
+    /// # Returns
+    /// 
+    /// A `ConsistencyReport` containing all test results and statistics
+    /// 
+    /// # Process
+    /// 
+    /// 1. Log test configuration and start timing
+    /// 2. Execute individual file tests sequentially, or as a single upload burst
+    ///    followed by concurrent polling when `args.burst` is set
+    /// 3. Calculate comprehensive statistics
+    /// 4. Perform final cleanup check
+    /// 5. Generate and return complete report
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let report = tester.run_consistency_test(&args).await?;
+    /// println!("Success rate: {:.1}%", report.statistics.success_rate);
+    /// ```
+    pub async fn run_consistency_test(&self, args: &Args) -> std::result::Result<ConsistencyReport, ConsistencyTestError> {
+        let test_start = Utc::now();
+        let start_instant = Instant::now();
+        
+        // Log test configuration
+        info!("🚀 Starting S3 consistency test");
+        info!("📍 S3 endpoint: {}", self.config.endpoint);
+        if self.endpoints.len() > 1 && !self.sharded {
+            info!("🔀 Endpoint selection: {:?} across {} endpoints", args.endpoint_selection, self.endpoints.len());
+        }
+        if let Some(reader_config) = self.config.reader_config() {
+            info!("📖 Read endpoint: {}", reader_config.endpoint);
+        }
+        if self.sharded {
+            info!("🪣 Buckets: {}", self.config.bucket_pool().join(", "));
+        } else {
+            info!("🪣 Bucket: {}", self.config.bucket);
+        }
+        info!("📊 Test files: {}", args.test_count);
+        match &args.size_distribution {
+            Some(distribution) => info!("📁 File sizes: {}", distribution),
+            None => info!("📁 File size: {} bytes", args.file_size),
+        }
+        log_large_file_handling(args);
+        info!("⏰ Max wait time: {} seconds", args.max_wait);
+        if args.auto_interval {
+            info!("🔄 Check interval: auto (starting at {} ms)", AUTO_INTERVAL_INITIAL_MS);
+        } else {
+            info!("🔄 Check interval: {} ms", args.interval);
+        }
+        
+        // Time the connection setup separately from the consistency tests
+        let connection_timing = if args.diagnose_timing {
+            self.diagnose_connection_timing().await
+        } else {
+            None
+        };
+        
+        // Pick the fastest endpoint if requested
+        self.probe_endpoints(args).await;
+        
+        // Start the background availability probe if requested
+        let probe = args.availability_probe.then(|| {
+            info!("🩺 Probing backend availability every {} ms", args.probe_interval);
+            AvailabilityProbe::start(
+                self.primary().storage.clone(),
+                Duration::from_millis(args.probe_interval),
+                self.requests.clone(),
+            )
+        });
+        
+        let mut collector = ResultCollector::new(
+            args.max_results_in_memory as usize,
+            args.results_jsonl.as_deref(),
+            args.failures_only,
+            args.stats_min_ms,
+            args.stats_max_ms,
+        )?;
+        let mut list_completeness = None;
+        let mut peak_in_flight = None;
+        match args.max_in_flight {
+            Some(max_in_flight) if args.burst => {
+                info!("💥 Burst mode: uploading while at most {} files await consistency", max_in_flight);
+                peak_in_flight = Some(self.run_bounded_burst(args, max_in_flight, &mut collector).await);
+            }
+            _ if args.burst || args.list_completeness => {
+                info!("💥 Burst mode: uploading all files before polling");
+                let (results, list_result) = self.run_burst(args).await;
+                list_completeness = list_result;
+                results.into_iter().for_each(|result| collector.push(result));
+            }
+            _ => self.run_sequential(args, &mut collector).await,
+        }
+        
+        // Race concurrent overwrites of one key if requested
+        let overwrite_race = if args.overwrite_race && !self.stop.is_cancelled() {
+            Some(self.run_overwrite_race(args).await)
+        } else {
+            None
+        };
+
+        let test_end = Utc::now();
+        let total_duration = start_instant.elapsed();
+        
+        let availability_timeline = match probe {
+            Some(probe) => Some(probe.stop().await),
+            None => None,
+        };
+
+        // Calculate statistics
+        info!("📈 Calculating test statistics...");
+        let collected = collector.finish();
+        let sampled_from = collected.is_sampled().then_some(collected.total);
+        let (mut results, statistics) = (collected.results, collected.statistics);
+        statistics::flag_outliers(&mut results, &statistics);
+        
+        // Perform final cleanup check
+        self.cleanup_deferred_files(args).await;
+        info!("🧹 Performing final cleanup check...");
+        self.cleanup_manager.final_cleanup_check().await;
+        
+        // Record how many files adaptive mode, or an interrupted run, ended up testing
+        let interrupted = self.stop.is_cancelled();
+        let mut test_parameters = self.test_parameters(args);
+        if args.adaptive_count || interrupted {
+            test_parameters.test_count = collected.total;
+        }
+        
+        // Create comprehensive report
+        let mut report = ConsistencyReport::new(
+            test_start,
+            test_end,
+            self.config.clone(),
+            test_parameters,
+            results,
+            statistics,
+        );
+        report.total_duration_ms = total_duration.as_millis() as u64;
+        report.availability_timeline = availability_timeline;
+        report.results_sampled_from = sampled_from;
+        report.list_completeness = list_completeness;
+        report.overwrite_race = overwrite_race;
+        report.peak_in_flight = peak_in_flight;
+        report.connection_timing = connection_timing;
+        report.size_breakdown = args.size_distribution.is_some()
+            .then(|| statistics::calculate_size_statistics(&report.results));
+        report.bucket_breakdown = self.sharded
+            .then(|| statistics::calculate_bucket_statistics(&report.results));
+        report.labels = args.labels.iter().cloned().collect();
+        report.interrupted = interrupted;
+        report.set_requests(self.requests.snapshot(), args.cost_per_1k_requests);
+
+        info!("✅ Test completed in {}ms", total_duration.as_millis());
+        
+        Ok(report)
+    }
+
+    /// Times DNS, TCP and TLS setup for a connection to the bucket's endpoint
+    /// 
+    /// A failed diagnostic is logged and does not fail the run.
+    /// 
+    /// # Returns
+    /// 
+    /// The connection timing, or `None` if the diagnostic failed
+    async fn diagnose_connection_timing(&self) -> Option<ConnectionTiming> {
+        info!("🔌 Diagnosing connection timing...");
+        match diagnose_timing(&self.bucket.url()).await {
+            Ok(timing) => {
+                info!(
+                    "🔌 DNS {:.1}ms, TCP {:.1}ms, TLS {}",
+                    timing.dns_resolution_ms,
+                    timing.tcp_connect_ms,
+                    timing.tls_handshake_ms.map_or("n/a".to_string(), |tls| format!("{:.1}ms", tls))
+                );
+                Some(timing)
+            }
+            Err(e) => {
+                warn!("⚠️  Connection timing diagnostic failed: {}", e);
+                None
+            }
+        }
+    }
+    
+    /// Ramps write concurrency to find the consistency breaking point
+    /// 
+    /// Runs a batch of `args.test_count` files at concurrency 1, 2, 4, 8, …
+    /// up to `args.max_concurrency`, calculating statistics for each batch.
+    /// The search stops at the first level whose p95 propagation time exceeds
+    /// `args.breakpoint_p95_ms` or whose success rate falls below
+    /// `args.breakpoint_min_success_rate`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `args` - Command-line arguments specifying test parameters and thresholds
+    /// 
+    /// # Returns
+    /// 
+    /// A `BreakpointReport` with the measurements for every level tried
+    pub async fn find_breakpoint(&self, args: &Args) -> std::result::Result<BreakpointReport, ConsistencyTestError> {
+        let test_start = Utc::now();
+        let mut levels = Vec::new();
+        let mut breakpoint_concurrency = None;
+        let mut concurrency = 1;
+        
+        info!("🔬 Searching for the consistency breaking point (up to concurrency {})", args.max_concurrency);
+        info!("🎯 Thresholds: p95 <= {}ms, success rate >= {:.1}%",
+              args.breakpoint_p95_ms, args.breakpoint_min_success_rate);
+        log_large_file_handling(args);
+        if args.test_count < statistics::MIN_SAMPLES_P95 {
+            warn!("⚠️  p95 is not computed for fewer than {} files; only the success rate threshold applies",
+                  statistics::MIN_SAMPLES_P95);
+        }
+        self.probe_endpoints(args).await;
+        
+        while concurrency <= args.max_concurrency && !self.stop.is_cancelled() {
+            info!("🧪 Testing {} files at concurrency {}", args.test_count, concurrency);
+            
+            let results = self.run_concurrent(args, concurrency).await;
+            let statistics = statistics::calculate_filtered_statistics(&results, args.stats_min_ms, args.stats_max_ms);
+            
+            let degraded = statistics.success_rate < args.breakpoint_min_success_rate
+                || statistics.percentile_95_ms.is_some_and(|p95| p95 > args.breakpoint_p95_ms);
+            
+            info!(
+                "📊 Concurrency {}: p95 {}, success rate {:.1}%",
+                concurrency,
+                statistics.percentile_95_ms.map_or("n/a".to_string(), |p95| format!("{}ms", p95)),
+                statistics.success_rate
+            );
+            
+            levels.push(BreakpointLevel { concurrency, statistics, degraded });
+            
+            if degraded {
+                warn!("📉 Consistency degraded at concurrency {}", concurrency);
+                breakpoint_concurrency = Some(concurrency);
+                break;
+            }
+            
+            concurrency *= 2;
+        }
+        
+        self.cleanup_deferred_files(args).await;
+        self.cleanup_manager.final_cleanup_check().await;
+        
+        Ok(BreakpointReport {
+            test_start_time: test_start,
+            test_end_time: Utc::now(),
+            config: self.config.clone(),
+            test_parameters: self.test_parameters(args),
+            p95_threshold_ms: args.breakpoint_p95_ms,
+            min_success_rate: args.breakpoint_min_success_rate,
+            levels,
+            breakpoint_concurrency,
+        })
+    }
+    
+    /// Runs the tests with a fixed number of files in flight at once
+    /// 
+    /// Each file goes through the full upload and poll cycle, with up to
+    /// `concurrency` files being tested simultaneously.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `args` - Test arguments containing timing and size parameters
+    /// * `concurrency` - Maximum number of files tested at the same time
+    /// 
+    /// # Returns
+    /// 
+    /// The test results in the order the files were started, which stop
+    /// short of `args.test_count` if the run is stopped
+    async fn run_concurrent(&self, args: &Args, concurrency: usize) -> Vec<TestResult> {
+        stream::iter(0..args.test_count)
+            .take_while(|_| future::ready(!self.stop.is_cancelled()))
+            .map(|_| self.test_single_file(args, args.interval))
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
+    }
+    
+    /// Runs the tests one file at a time
+    /// 
+    /// Each file is uploaded and polled to consistency before the next
+    /// file is uploaded, so writes and reads are interleaved.
+    /// 
+    /// With `--adaptive-count`, `args.test_count` is a minimum: files keep
+    /// being tested until the 95% confidence interval on the average
+    /// propagation time is at most `args.target_ci_width` wide, or
+    /// `args.max_count` files have been tested.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `args` - Test arguments containing timing and size parameters
+    /// * `collector` - Collector each result is added to as it finishes
+    async fn run_sequential(&self, args: &Args, collector: &mut ResultCollector) {
+        let mut tuner = args.auto_interval.then(IntervalTuner::new);
+        let max_count = if args.adaptive_count {
+            args.max_count.max(args.test_count)
+        } else {
+            args.test_count
+        };
+
+        // Execute individual tests
+        for i in 0..max_count {
+            if self.stop.is_cancelled() {
+                warn!("🛑 Run stopped after {} files, skipping the rest", i);
+                break;
+            }
+            
+            if i < args.test_count {
+                info!("🧪 Testing file {}/{}", i + 1, args.test_count);
+            } else {
+                // Past the minimum, only keep going while the average is imprecise
+                let ci_width = collector.statistics().mean_ci_width_ms;
+                if let Some(width) = ci_width.filter(|&width| width <= args.target_ci_width) {
+                    info!("🎯 95% CI width of {:.1}ms reached the target after {} files", width, i);
+                    break;
+                }
+                info!(
+                    "🧪 Testing file {} (95% CI width {}, target {:.1}ms)",
+                    i + 1,
+                    ci_width.map_or("n/a".to_string(), |width| format!("{:.1}ms", width)),
+                    args.target_ci_width
+                );
+            }
+            
+            // Small delay between tests to avoid overwhelming the server
+            if i > 0 {
+                sleep(Duration::from_millis(100)).await;
+            }
+            
+            let interval_ms = tuner.as_ref().map_or(args.interval, IntervalTuner::interval_ms);
+            let mut test_result = self.test_single_file(args, interval_ms).await;
+            test_result.test_index = i;
+            
+            if let (Some(tuner), Some(duration)) = (tuner.as_mut(), test_result.propagation_duration_ms) {
+                tuner.observe(duration);
+                debug!("🔧 Auto-tuned check interval: {}ms", tuner.interval_ms());
+            }
+            collector.push(test_result);
+        }
+        
+        if args.adaptive_count && collector.count() == max_count {
+            let converged = collector.statistics().mean_ci_width_ms
+                .is_some_and(|width| width <= args.target_ci_width);
+            if !converged {
+                warn!("📏 Reached --max-count of {} files before the confidence interval narrowed", max_count);
+            }
+        }
+    }
+    
+    /// Runs the tests as a single write burst
+    /// 
+    /// All files are uploaded first, each recording its own upload time,
+    /// and only then are they polled for consistency concurrently. Files
+    /// that fail to upload are recorded as failures without being polled.
+    /// 
+    /// With `--auto-interval` there are no prior observations to tune from,
+    /// so every file is polled at the initial auto-tuned interval.
+    /// 
+    /// With `--list-completeness`, the bucket listing is polled alongside the
+    /// per-file reads until it contains the whole batch.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `args` - Test arguments containing timing and size parameters
+    /// 
+    /// # Returns
+    /// 
+    /// The test results in the order the files were uploaded, and the listing
+    /// completeness result when `--list-completeness` was used
+    async fn run_burst(&self, args: &Args) -> (Vec<TestResult>, Option<ListCompletenessResult>) {
+        let mut uploads = Vec::with_capacity(args.test_count);
+        
+        // Upload phase
+        for i in 0..args.test_count {
+            if self.stop.is_cancelled() {
+                warn!("🛑 Run stopped after uploading {} files, polling those only", i);
+                break;
+            }
+            
+            info!("📤 Uploading file {}/{}", i + 1, args.test_count);
+            uploads.push(self.upload_test_file(args).await);
+        }
+        
+        let uploaded_keys: Vec<String> = uploads.iter()
+            .filter_map(|u| u.as_ref().ok().map(|uploaded| uploaded.file_key.clone()))
+            .collect();
+        
+        // Poll phase - every successfully uploaded file is polled concurrently.
+        // When listing completeness is measured, cleanup waits until the
+        // listing check is done so deletes don't shrink the listing.
+        info!("🔍 Polling {} uploaded files for consistency", uploaded_keys.len());
+        let interval_ms = if args.auto_interval { AUTO_INTERVAL_INITIAL_MS } else { args.interval };
+        let cleanup_each = !args.list_completeness;
+        let polls = uploads.into_iter().enumerate().map(|(i, upload)| async move {
+            let mut result = match upload {
+                Ok(uploaded) => {
+                    let mut result = self.poll_uploaded_file(uploaded, args, interval_ms).await;
+                    if cleanup_each {
+                        self.cleanup_tested_file(&mut result, args).await;
+                    }
+                    result
+                }
+                Err(failure) => failure,
+            };
+            result.test_index = i;
+            result
+        });
+        
+        if !args.list_completeness {
+            return (join_all(polls).await, None);
+        }
+        
+        let (mut results, list_result) = tokio::join!(
+            join_all(polls),
+            self.check_list_completeness(&uploaded_keys, args),
+        );
+        
+        for result in results.iter_mut().filter(|r| uploaded_keys.contains(&r.file_key)) {
+            self.cleanup_tested_file(result, args).await;
+        }
+        
+        (results, Some(list_result))
+    }
+
+    /// Runs the tests as a write burst with bounded backpressure
+    /// 
+    /// Files are uploaded one after another as in `run_burst`, but each file
+    /// is polled as soon as its upload completes. At most `max_in_flight`
+    /// files are uploading or awaiting consistency at once, so the next
+    /// upload waits until an earlier file is consistent or times out.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `args` - Test arguments containing timing and size parameters
+    /// * `max_in_flight` - Maximum number of files awaiting consistency at once
+    /// * `collector` - Collector each result is added to as it finishes
+    /// 
+    /// # Returns
+    /// 
+    /// The highest number of files that were awaiting consistency at the same time
+    async fn run_bounded_burst(&self, args: &Args, max_in_flight: usize, collector: &mut ResultCollector) -> usize {
+        let interval_ms = if args.auto_interval { AUTO_INTERVAL_INITIAL_MS } else { args.interval };
+        let upload_lock = tokio::sync::Mutex::new(());
+        let in_flight = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+        
+        stream::iter(0..args.test_count)
+            .take_while(|_| future::ready(!self.stop.is_cancelled()))
+            .map(|i| {
+                let (upload_lock, in_flight, peak) = (&upload_lock, &in_flight, &peak);
+                async move {
+                    // Uploads stay sequential, as in an unbounded burst
+                    let upload = {
+                        let _upload_turn = upload_lock.lock().await;
+                        info!("📤 Uploading file {}/{}", i + 1, args.test_count);
+                        self.upload_test_file(args).await
+                    };
+                    
+                    let mut result = match upload {
+                        Ok(uploaded) => {
+                            let current = in_flight.fetch_add(1, Ordering::Relaxed) + 1;
+                            peak.fetch_max(current, Ordering::Relaxed);
+                            
+                            let mut result = self.poll_uploaded_file(uploaded, args, interval_ms).await;
+                            in_flight.fetch_sub(1, Ordering::Relaxed);
+                            self.cleanup_tested_file(&mut result, args).await;
+                            result
+                        }
+                        Err(failure) => failure,
+                    };
+                    result.test_index = i;
+                    result
+                }
+            })
+            .buffer_unordered(max_in_flight.max(1))
+            // Results are collected as they finish; the collector restores start order
+            .for_each(|result| {
+                collector.push(result);
+                future::ready(())
+            })
+            .await;
+        
+        if self.stop.is_cancelled() {
+            warn!("🛑 Run stopped after uploading {} files", collector.count());
+        }
+        
+        let peak = peak.load(Ordering::Relaxed);
+        info!("📦 At most {} files awaited consistency at the same time", peak);
+        peak
+    }
+    
+    /// Polls a bucket listing until it returns every uploaded key
+    /// 
+    /// Lists the test key prefix (following pagination) every `args.interval`
+    /// milliseconds and counts how many of `uploaded_keys` are present. Keys
+    /// left behind by other runs are ignored. Timing starts when this check
+    /// begins, which is immediately after the upload phase.
+    /// 
+    /// Besides the time to a listing that returns every key, it records
+    /// whether the first listing was already a consistent snapshot, and when
+    /// every key had been seen on at least one page of any listing.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `uploaded_keys` - Keys the listing is expected to contain
+    /// * `args` - Test arguments containing timeout and interval settings
+    /// 
+    /// # Returns
+    /// 
+    /// A `ListCompletenessResult` describing how long the listing took to
+    /// converge, or how far it got before `args.max_wait` elapsed
+    async fn check_list_completeness(&self, uploaded_keys: &[String], args: &Args) -> ListCompletenessResult {
+        let expected: HashSet<&str> = uploaded_keys.iter().map(String::as_str).collect();
+        let max_duration = Duration::from_secs(args.max_wait);
+        let check_interval = Duration::from_millis(args.interval);
+        let page_size = args.list_page_size.map(|size| size as usize);
+        let start_time = Instant::now();
+        let mut attempts = 0;
+        let mut observed_count = 0;
+        let mut page_count = 0;
+        let mut seen: HashSet<&str> = HashSet::new();
+        let mut keys_seen_ms = None;
+        
+        info!("📋 Polling bucket listing for {} uploaded files", expected.len());
+        
+        loop {
+            attempts += 1;
+            
+            let listing = list_pages(self.primary().reader_storage.as_ref(), &self.config.key_prefix(), page_size, &self.requests);
+            let last_error = match timeout(Duration::from_secs(30), listing).await {
+                Ok(Ok(pages)) => {
+                    page_count = pages.len();
+                    observed_count = 0;
+                    for key in pages.iter().flatten() {
+                        if let Some(&key) = expected.get(key.as_str()) {
+                            observed_count += 1;
+                            seen.insert(key);
+                        }
+                    }
+                    debug!("📋 Listing attempt {} returned {}/{} uploaded files across {} pages",
+                           attempts, observed_count, expected.len(), page_count);
+                    
+                    if keys_seen_ms.is_none() && seen.len() == expected.len() {
+                        keys_seen_ms = Some(start_time.elapsed().as_millis() as u64);
+                    }
+                    
+                    if observed_count == expected.len() {
+                        let elapsed_ms = start_time.elapsed().as_millis() as u64;
+                        info!("🎉 Listing returned all {} files after {} attempts in {}ms",
+                              expected.len(), attempts, elapsed_ms);
+                        
+                        return ListCompletenessResult {
+                            expected_count: expected.len(),
+                            observed_count,
+                            list_completeness_duration_ms: Some(elapsed_ms),
+                            total_attempts: attempts,
+                            list_snapshot_consistent: attempts == 1,
+                            keys_seen_duration_ms: keys_seen_ms,
+                            page_count,
+                            success: true,
+                            error_details: None,
+                        };
+                    }
+                    None
+                }
+                Ok(Err(e)) => {
+                    debug!("⚠️  Listing attempt {} failed: {}", attempts, e);
+                    Some(e.to_string())
+                }
+                Err(_) => {
+                    debug!("⏰ Listing attempt {} timed out", attempts);
+                    Some("Listing timed out".to_string())
+                }
+            };
+            
+            if start_time.elapsed() >= max_duration {
+                warn!("⏰ Listing returned only {}/{} files after {} attempts",
+                      observed_count, expected.len(), attempts);
+                
+                return ListCompletenessResult {
+                    expected_count: expected.len(),
+                    observed_count,
+                    list_completeness_duration_ms: None,
+                    total_attempts: attempts,
+                    list_snapshot_consistent: false,
+                    keys_seen_duration_ms: keys_seen_ms,
+                    page_count,
+                    success: false,
+                    error_details: last_error,
+                };
+            }
+            
+            sleep(check_interval).await;
+        }
+    }
+
+    /// Races concurrent overwrites of one key and polls until reads converge
+    /// 
+    /// Every writer uploads its own payload, labelled with its writer number
+    /// and padded with random data to `args.file_size` (capped at
+    /// `args.stream_threshold`), to the same new key at the same time through
+    /// the primary endpoint. Once every upload returned, the key is read every
+    /// `args.interval` milliseconds and each body is matched against the
+    /// payloads. The race is settled once `OVERWRITE_STABLE_READS` reads in
+    /// a row returned the same payload. Failed reads and bodies matching no
+    /// payload break the run without counting as a switch.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `args` - Test arguments containing the writer count, timeout and interval
+    /// 
+    /// # Returns
+    /// 
+    /// An `OverwriteRaceResult` naming the winner and how long reads took to
+    /// settle on it, or how far they got before `args.max_wait` elapsed
+    async fn run_overwrite_race(&self, args: &Args) -> OverwriteRaceResult {
+        let endpoint = self.primary();
+        let file_key = self.keys.next_key(args.key_pattern.as_ref(), args.key_style);
+        let writers = args.overwrite_writers as usize;
+        let payload_size = args.file_size.min(args.stream_threshold);
+        let payloads: Vec<Vec<u8>> = (1..=writers)
+            .map(|writer| {
+                let mut payload = format!("overwrite-race writer {}\n", writer).into_bytes();
+                payload.extend(self.generate_test_data(payload_size.saturating_sub(payload.len())));
+                payload
+            })
+            .collect();
+        let headers = (!self.sse_headers.is_empty()).then(|| self.sse_headers.clone());
+        
+        info!("🏁 Racing {} concurrent overwrites of {}", writers, file_key);
+        self.cleanup_manager.register_file(&file_key).await;
+        let key = file_key.as_str();
+        let writes = join_all(payloads.iter().map(|payload| {
+            let headers = headers.clone();
+            async move {
+                if let Some(throttle) = &self.upload_throttle {
+                    throttle.wait(payload.len()).await;
+                }
+                self.requests.record(RequestKind::Upload);
+                let written = endpoint.storage.put(key, payload, headers).await;
+                (written, Instant::now())
+            }
+        }))
+        .await;
+        let race_end = Instant::now();
+        
+        let successful_writes = writes.iter().filter(|(written, _)| written.is_ok()).count();
+        let last_completed_writer = writes.iter()
+            .enumerate()
+            .filter(|(_, (written, _))| written.is_ok())
+            .max_by_key(|(_, (_, completed))| *completed)
+            .map(|(writer, _)| writer + 1);
+        let mut result = OverwriteRaceResult {
+            file_key: file_key.clone(),
+            writers,
+            successful_writes,
+            last_completed_writer,
+            winner: None,
+            converged_value_stable_ms: None,
+            oscillated: false,
+            value_changes: 0,
+            total_reads: 0,
+            error_details: None,
+        };
+        
+        if successful_writes == 0 {
+            let error = writes.into_iter().find_map(|(written, _)| written.err());
+            warn!("❌ Every overwrite of {} failed", file_key);
+            result.error_details = error.map(|e| format!("Every upload failed: {}", e));
+        } else {
+            self.poll_overwrite_race(&file_key, &payloads, race_end, args, &mut result).await;
+        }
+        
+        if !args.defer_cleanup {
+            self.cleanup_manager.cleanup_file(&file_key).await;
+        }
+        result
+    }
+    
+    /// Reads a raced key until the same payload was returned several times in a row
+    /// 
+    /// # Arguments
+    /// 
+    /// * `file_key` - The key the writers overwrote
+    /// * `payloads` - Each writer's payload, in writer order
+    /// * `race_end` - When the last overwrite returned, the baseline for convergence
+    /// * `args` - Test arguments containing timeout and interval settings
+    /// * `result` - The race result the read outcomes are recorded in
+    async fn poll_overwrite_race(
+        &self,
+        file_key: &str,
+        payloads: &[Vec<u8>],
+        race_end: Instant,
+        args: &Args,
+        result: &mut OverwriteRaceResult,
+    ) {
+        let max_duration = Duration::from_secs(args.max_wait);
+        let check_interval = Duration::from_millis(args.interval);
+        let mut seen: Vec<usize> = Vec::new();
+        let mut streak = 0;
+        let mut streak_start = race_end;
+        
+        loop {
+            result.total_reads += 1;
+            self.requests.record(RequestKind::Read);
+            let read_at = Instant::now();
+            let writer = match timeout(Duration::from_secs(5), self.primary().reader_storage.get(file_key, None)).await {
+                Ok(Ok(response)) => payloads.iter().position(|payload| *payload == response.body),
+                Ok(Err(e)) => {
+                    debug!("⚠️  Read {} of raced key {} failed: {}", result.total_reads, file_key, e);
+                    None
+                }
+                Err(_) => {
+                    debug!("⏰ Read {} of raced key {} timed out", result.total_reads, file_key);
+                    None
+                }
+            };
+            
+            match writer {
+                Some(writer) => {
+                    if seen.last() != Some(&writer) {
+                        result.oscillated |= seen.contains(&writer);
+                        seen.push(writer);
+                        streak = 0;
+                    }
+                    if streak == 0 {
+                        streak_start = read_at;
+                    }
+                    streak += 1;
+                }
+                None => streak = 0,
+            }
+            result.value_changes = seen.len().saturating_sub(1);
+            
+            if streak >= OVERWRITE_STABLE_READS {
+                let stable_ms = streak_start.saturating_duration_since(race_end).as_millis() as u64;
+                result.winner = seen.last().map(|writer| writer + 1);
+                result.converged_value_stable_ms = Some(stable_ms);
+                info!(
+                    "🏁 Reads of {} settled on writer {} after {}ms ({} switches)",
+                    file_key, result.winner.unwrap_or_default(), stable_ms, result.value_changes
+                );
+                if result.oscillated {
+                    warn!("🔁 Reads of {} oscillated between payloads before settling", file_key);
+                }
+                return;
+            }
+            
+            if race_end.elapsed() >= max_duration || self.stop.is_cancelled() {
+                warn!("⏰ Reads of {} did not settle on one payload after {} reads", file_key, result.total_reads);
+                result.error_details = Some("Reads did not settle on one payload".to_string());
+                return;
+            }
+            
+            sleep(check_interval).await;
+        }
+    }
+    
+    /// Tests consistency for a single file
+    /// 
+    /// Performs the complete test cycle for one file: upload, consistency checking,
+    /// and cleanup. This is the core testing logic that measures propagation time.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `args` - Test arguments containing timing and size parameters
+    /// * `interval_ms` - Check interval to poll this file at
+    /// 
+    /// # Returns
+    /// 
+    /// A `TestResult` containing the outcome and timing data for this test
+    /// 
+    /// # Process
+    /// 
+    /// 1. Generate unique test file with random data
+    /// 2. Upload file to S3 and record timestamp
+    /// 3. Register file for cleanup tracking
+    /// 4. Perform consistency polling until readable or timeout
+    /// 5. Wait for `args.cleanup_delay` if the file became readable, then
+    ///    clean up the test file, unless cleanup is deferred
+    /// 6. Return result with timing information
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let result = tester.test_single_file(&args, args.interval).await;
+    /// if result.success {
+    ///     println!("Propagation time: {}ms", result.propagation_duration_ms.unwrap());
+    /// }
+    /// ```
+    async fn test_single_file(&self, args: &Args, interval_ms: u64) -> TestResult {
+        match self.upload_test_file(args).await {
+            Ok(uploaded) => {
+                let mut result = self.poll_uploaded_file(uploaded, args, interval_ms).await;
+                
+                // Always clean up the test file
+                self.cleanup_tested_file(&mut result, args).await;
+                result
+            }
+            Err(failure) => failure,
+        }
+    }
+    
+    /// Deletes a tested file, measuring delete-list propagation if requested
+    /// 
+    /// With `--recreate-after-delete`, a file that became readable is first
+    /// deleted and re-created under the same key, and the re-created object
+    /// is what gets cleaned up. With `--defer-cleanup`, the file is left registered for the batched
+    /// pass at the end of the run instead. With `--cleanup-delay`, the delete
+    /// of a file that became readable is postponed by that many milliseconds
+    /// to let replication settle. With
+    /// `--test-delete-list-consistency`, a successful delete is followed
+    /// by polling the listing until the key disappears, and the time taken is
+    /// recorded on the result. With `--detailed-timeline`, the start and end of
+    /// the delete are added to the result's timeline.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `result` - The result for the uploaded file to delete
+    /// * `args` - Test arguments containing the measurement settings
+    async fn cleanup_tested_file(&self, result: &mut TestResult, args: &Args) {
+        if result.success && args.recreate_after_delete {
+            self.test_recreate_after_delete(result, args).await;
+        }
+        
+        if args.defer_cleanup {
+            debug!("🗂️  Deferring cleanup of {}", result.file_key);
+            return;
+        }
+        
+        if result.success && args.cleanup_delay > 0 {
+            debug!("⏳ Waiting {}ms before deleting {}", args.cleanup_delay, result.file_key);
+            sleep(Duration::from_millis(args.cleanup_delay)).await;
+        }
+        
+        let cleanup_start = Utc::now();
+        let bucket = result.bucket.as_deref().unwrap_or(&self.config.bucket);
+        let deleted = self.cleanup_manager.for_bucket(bucket).cleanup_file(&result.file_key).await;
+        
+        if let Some(timeline) = &mut result.timeline {
+            timeline.cleanup_start = Some(cleanup_start);
+            timeline.cleanup_end = Some(Utc::now());
+        }
+        
+        if deleted && args.test_delete_list_consistency {
+            result.delete_list_propagation_duration_ms =
+                self.check_delete_list_consistency(&result.file_key, args).await;
+        }
+    }
     
-    /// Configuration used for this tester
-    config: S3Config,
+    /// Deletes a consistent file and measures how long re-creating its key takes
+    /// 
+    /// The file is deleted, then read until `TOMBSTONE_CONFIRMATIONS`
+    /// consecutive reads return 404. New content is uploaded to the same key
+    /// and read until it is returned. Timing starts when the re-upload
+    /// completed. 404s later than the file's first propagation time are
+    /// recorded as stale-tombstone reads, since a fresh key would have been
+    /// readable by then. The key stays registered for cleanup throughout.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `result` - The result of a file that became readable, updated with the outcome
+    /// * `args` - Test arguments containing timeout and interval settings
+    async fn test_recreate_after_delete(&self, result: &mut TestResult, args: &Args) {
+        let endpoint = result.bucket.as_deref()
+            .and_then(|bucket| self.shards().iter().find(|shard| shard.bucket == bucket))
+            .unwrap_or(self.primary());
+        let file_key = result.file_key.clone();
+        let max_duration = Duration::from_secs(args.max_wait);
+        let check_interval = Duration::from_millis(args.interval);
+        
+        // Delete the file and wait until reads agree it is gone
+        self.requests.record(RequestKind::Delete);
+        if let Err(e) = endpoint.storage.delete(&file_key).await {
+            warn!("⚠️  Failed to delete {} before re-creating it: {}", file_key, e);
+            result.recreate_error = Some(format!("Delete failed: {}", e));
+            return;
+        }
+        let delete_end = Instant::now();
+        let mut misses = 0;
+        while misses < TOMBSTONE_CONFIRMATIONS {
+            if delete_end.elapsed() >= max_duration || self.stop.is_cancelled() {
+                warn!("⏰ Deleted file {} was still readable after {}s", file_key, args.max_wait);
+                result.recreate_error = Some("Deleted file was still readable".to_string());
+                return;
+            }
+            self.requests.record(RequestKind::Read);
+            match timeout(Duration::from_secs(5), endpoint.reader_storage.get(&file_key, None)).await {
+                Ok(Err(S3Error::HttpFailWithBody(404, _))) => misses += 1,
+                _ => misses = 0,
+            }
+            sleep(check_interval).await;
+        }
+        debug!("🪦 {} is gone after {}ms, re-creating it", file_key, delete_end.elapsed().as_millis());
+        
+        // Upload new content to the same key
+        let payload = self.generate_test_data(result.file_size.unwrap_or(args.file_size).min(args.stream_threshold));
+        let headers = (!self.sse_headers.is_empty()).then(|| self.sse_headers.clone());
+        if let Some(throttle) = &self.upload_throttle {
+            throttle.wait(payload.len()).await;
+        }
+        self.requests.record(RequestKind::Upload);
+        if let Err(e) = endpoint.storage.put(&file_key, &payload, headers).await {
+            warn!("⚠️  Failed to re-create {}: {}", file_key, e);
+            result.recreate_error = Some(format!("Re-upload failed: {}", e));
+            return;
+        }
+        let upload_instant = Instant::now();
+        let fresh_propagation = result.propagation_duration_ms.map(Duration::from_millis);
+        
+        loop {
+            self.requests.record(RequestKind::Read);
+            let read = timeout(Duration::from_secs(5), endpoint.reader_storage.get(&file_key, None)).await;
+            let elapsed = upload_instant.elapsed();
+            match read {
+                Ok(Ok(response)) if response.body == payload => {
+                    let propagation_ms = elapsed.as_millis() as u64;
+                    result.recreate_after_delete_propagation_ms = Some(propagation_ms);
+                    if result.stale_tombstone_reads > 0 {
+                        warn!(
+                            "🪦 Re-created {} returned 404 {} times after a fresh key would have been readable",
+                            file_key, result.stale_tombstone_reads
+                        );
+                    }
+                    debug!("♻️  Re-created {} was readable after {}ms", file_key, propagation_ms);
+                    return;
+                }
+                Ok(Err(S3Error::HttpFailWithBody(404, _))) if fresh_propagation.is_some_and(|fresh| elapsed > fresh) => {
+                    result.stale_tombstone_reads += 1;
+                }
+                _ => {}
+            }
+            
+            if elapsed >= max_duration || self.stop.is_cancelled() {
+                warn!("⏰ Re-created file {} was not readable after {}s", file_key, args.max_wait);
+                result.recreate_error = Some("Re-created file was not readable".to_string());
+                return;
+            }
+            sleep(check_interval).await;
+        }
+    }
     
-    /// Cleanup manager for handling test file cleanup
-    cleanup_manager: Arc<CleanupManager>,
-}
-
-impl S3ConsistencyTester {
-    /// Creates a new S3 consistency tester
+    /// Deletes every file whose cleanup was deferred with `--defer-cleanup`
     /// 
-    /// Establishes connection to the S3 service using the provided configuration
-    /// and sets up the cleanup manager for handling test files.
+    /// Runs once after all measurements, using batch deletes where the
+    /// backend supports them. Does nothing without `--defer-cleanup`.
     /// 
     /// # Arguments
     /// 
-    /// * `config` - S3 configuration including endpoint, credentials, and bucket
+    /// * `args` - Test arguments containing the cleanup settings
+    async fn cleanup_deferred_files(&self, args: &Args) {
+        if !args.defer_cleanup {
+            return;
+        }
+        
+        for shard in self.shards() {
+            let cleanup_manager = self.cleanup_manager.for_bucket(&shard.bucket);
+            let deferred = cleanup_manager.get_active_files().await;
+            info!("🧹 Cleaning up {} deferred test files in {}...", deferred.len(), shard.bucket);
+            let deleted = cleanup_manager.cleanup_files(&deferred).await;
+            debug!("Deleted {} of {} deferred test files", deleted, deferred.len());
+        }
+    }
+    
+    /// Polls a listing of a deleted key until the key stops appearing
+    /// 
+    /// Lists the key as a prefix (following pagination) every `args.interval`
+    /// milliseconds. The key counts as gone once it is absent from
+    /// `DELETE_LIST_CONFIRMATIONS` consecutive listings; if it reappears in
+    /// between, the absence streak and its start time are reset. Timing starts
+    /// right after the delete completed.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `file_key` - The key that was just deleted
+    /// * `args` - Test arguments containing timeout and interval settings
     /// 
     /// # Returns
     /// 
-    /// A new `S3ConsistencyTester` instance ready for testing
+    /// The time from deletion until the start of the final absence streak in
+    /// milliseconds, or `None` if the key was still listed after `args.max_wait`
+    async fn check_delete_list_consistency(&self, file_key: &str, args: &Args) -> Option<u64> {
+        let max_duration = Duration::from_secs(args.max_wait);
+        let check_interval = Duration::from_millis(args.interval);
+        let start_time = Instant::now();
+        let mut gone_since: Option<Duration> = None;
+        let mut confirmations = 0;
+        let mut reappearances = 0;
+        
+        loop {
+            match timeout(Duration::from_secs(30), list_all_keys(self.primary().reader_storage.as_ref(), file_key, &self.requests)).await {
+                Ok(Ok(keys)) if keys.iter().any(|key| key == file_key) => {
+                    if gone_since.take().is_some() {
+                        reappearances += 1;
+                        debug!("👻 Deleted file {} reappeared in the listing", file_key);
+                    }
+                    confirmations = 0;
+                }
+                Ok(Ok(_)) => {
+                    let since = *gone_since.get_or_insert_with(|| start_time.elapsed());
+                    confirmations += 1;
+                    
+                    if confirmations >= DELETE_LIST_CONFIRMATIONS {
+                        let elapsed_ms = since.as_millis() as u64;
+                        debug!(
+                            "🗑️  Deleted file {} left the listing after {}ms ({} reappearances)",
+                            file_key, elapsed_ms, reappearances
+                        );
+                        return Some(elapsed_ms);
+                    }
+                }
+                Ok(Err(e)) => {
+                    debug!("⚠️  Listing deleted file {} failed: {}", file_key, e);
+                }
+                Err(_) => {
+                    debug!("⏰ Listing deleted file {} timed out", file_key);
+                }
+            }
+            
+            if start_time.elapsed() >= max_duration {
+                warn!(
+                    "⏰ Deleted file {} was still listed after {}ms ({} reappearances)",
+                    file_key, max_duration.as_millis(), reappearances
+                );
+                return None;
+            }
+            
+            sleep(check_interval).await;
+        }
+    }
+    
+    /// Uploads a single test file and registers it for cleanup
     /// 
-    /// # Errors
+    /// Generates a unique key (from `args.key_pattern` if set) filled with
+    /// random data, uploads it and records the upload completion time as the
+    /// baseline for the propagation measurement.
+    /// When `args.upload_jitter` is set, the upload is first delayed by a random
+    /// amount up to that many milliseconds. Files larger than `args.stream_threshold`
+    /// are streamed from generated chunks instead of being buffered in memory.
+    /// With `args.multipart`, files of at least `args.multipart_threshold` bytes
+    /// are uploaded with an explicit multipart upload instead, and the upload
+    /// time is taken when CompleteMultipartUpload returns.
+    /// With `args.check_collision`, the key is first checked for an existing
+    /// object and regenerated if one is found, so a patterned key never
+    /// overwrites an object and corrupts the measurement.
     /// 
-    /// Returns an error if:
-    /// - Credentials are invalid
-    /// - Region parsing fails
-    /// - Bucket connection cannot be established
+    /// Upload failures are infrastructure problems rather than consistency
+    /// behavior, so with `args.file_retries` a failed upload is retried up to
+    /// that many times, unless the error is non-retryable (e.g. access denied).
+    /// Once a file is uploaded, its polling outcome, including a consistency
+    /// timeout, is the measurement and is never retried.
     /// 
-    /// # Examples
+    /// # Arguments
     /// 
-    /// ```rust
-    /// let config = S3Config {
-    ///     endpoint: "https://s3.amazonaws.com".to_string(),
-    ///     region: "us-east-1".to_string(),
-    ///     bucket: "my-test-bucket".to_string(),
-    ///     access_key: "AKIAIO...".to_string(),
-    ///     secret_key: "wJalr...".to_string(),
-    ///     path_style: Some(false),
-    /// };
-    /// let tester = S3ConsistencyTester::new(config).await?;
-    /// ```
-    pub async fn new(config: S3Config) -> Result<Self> {
-        debug!("Creating S3 consistency tester for endpoint: {}", config.endpoint);
+    /// * `args` - Test arguments containing the file size and retry settings
+    /// 
+    /// # Returns
+    /// 
+    /// - `Ok(UploadedFile)` - The uploaded key and its upload time
+    /// - `Err(TestResult)` - A failed result if the upload did not succeed
+    async fn upload_test_file(&self, args: &Args) -> std::result::Result<UploadedFile, TestResult> {
+        // With --verbose-failures, this file's debug logs are held until it has passed or failed
+        let span = if args.verbose_failures {
+            debug_span!(FILE_SPAN, key = field::Empty, failed = field::Empty)
+        } else {
+            Span::none()
+        };
+        
+        let upload = self.upload_file(args).instrument(span.clone()).await;
+        if upload.is_err() {
+            span.record(FAILED_FIELD, true);
+        }
+        upload
+    }
+    
+    /// Uploads a single test file inside its `--verbose-failures` span
+    /// 
+    /// See `upload_test_file`.
+    async fn upload_file(&self, args: &Args) -> std::result::Result<UploadedFile, TestResult> {
+        // Pick this file's size from the distribution, if one was given
+        let file_size = match &args.size_distribution {
+            Some(distribution) => distribution.sample(&mut rand::thread_rng()),
+            None => args.file_size,
+        };
+        
+        // Pick the endpoints this file is written to and read from
+        let (upload_endpoint, read_endpoint) = self.select_endpoints(args);
+        let endpoint = &self.endpoints[upload_endpoint];
         
-        // Create S3 credentials
-        let credentials = Credentials::new(
-            Some(&config.access_key),
-            Some(&config.secret_key),
-            None, // session_token
-            None, // profile
-            None, // role_arn
-        ).context("Failed to create S3 credentials")?;
-
-        // Determine the appropriate region configuration
-        let region = if config.is_aws_s3() {
-            // For AWS S3, parse the region string
-            Region::from_str(&config.region)
-                .with_context(|| format!("Invalid AWS region: {}", config.region))?
+        // Generate unique test file
+        let file_key = self.keys.next_key(args.key_pattern.as_ref(), args.key_style);
+        let (file_key, key_collisions) = if args.check_collision {
+            self.find_unused_key(file_key, endpoint, args).await?
         } else {
-            // For custom S3 services, use custom region with endpoint
-            Region::Custom {
-                region: config.region.clone(),
-                endpoint: config.endpoint.clone(),
+            (file_key, 0)
+        };
+        Span::current().record(KEY_FIELD, file_key.as_str());
+        let multipart = args.multipart && file_size >= args.multipart_threshold;
+        let test_data = (file_size <= args.stream_threshold && !multipart)
+            .then(|| self.generate_test_data(file_size));
+        let self_verify = args.self_verify && test_data.is_some();
+        let checksum = args.checksum.filter(|_| test_data.is_some());
+        
+        // Stagger the upload start to avoid a thundering herd
+        if args.upload_jitter > 0 {
+            let jitter_ms = rand::thread_rng().gen_range(0..=args.upload_jitter);
+            debug!("⏳ Delaying upload of {} by {}ms of jitter", file_key, jitter_ms);
+            sleep(Duration::from_millis(jitter_ms)).await;
+        }
+        
+        debug!("📤 Uploading test file: {}", file_key);
+        let upload_start = Utc::now();
+        
+        // Attempt to upload the file, retrying transient failures
+        let mut retries = 0;
+        let mut upload_errors = HashMap::new();
+        let mut upload_error_code = None;
+        let upload = loop {
+            let upload = if multipart {
+                self.upload_multipart(&endpoint.upload_bucket, &file_key, file_size, args).await
+            } else {
+                self.requests.record(RequestKind::Upload);
+                match &test_data {
+                    Some(data) => {
+                        let mut headers = self.sse_headers.clone();
+                        if self_verify {
+                            headers.extend(content_hash_headers(data));
+                        }
+                        if let Some(algorithm) = checksum {
+                            headers.extend(algorithm.upload_headers(data));
+                        }
+                        if let Some(throttle) = &self.upload_throttle {
+                            throttle.wait(data.len()).await;
+                        }
+                        endpoint.storage.put(&file_key, data, (!headers.is_empty()).then_some(headers)).await
+                    }
+                    None => {
+                        let mut reader = RandomDataReader::new(file_size).with_throttle(self.upload_throttle.clone());
+                        endpoint.upload_bucket.put_object_stream(&mut reader, &file_key).await.map(|_| ())
+                    }
+                }
+            };
+            
+            if let Err(e) = &upload {
+                count_error(&mut upload_errors, error_category(e));
+                upload_error_code = s3_error_code(e).or(upload_error_code);
+                if self.claim_first_failure(args) {
+                    let hash_headers = test_data.as_deref().filter(|_| self_verify).map(content_hash_headers);
+                    self.log_failed_upload(endpoint, &file_key, hash_headers, e);
+                }
+            }
+            
+            match upload {
+                Err(e) if retries < args.file_retries && non_retryable_reason(&e).is_none() => {
+                    retries += 1;
+                    warn!(
+                        "🔁 Upload of {} failed: {}, retrying ({}/{})...",
+                        file_key, e, retries, args.file_retries
+                    );
+                    sleep(FILE_RETRY_DELAY).await;
+                }
+                upload => break upload,
+            }
+        };
+        match upload {
+            Ok(()) => {
+                // Record upload completion - the instant is the baseline for consistency measurement
+                let upload_instant = Instant::now();
+                let upload_time = Utc::now();
+                debug!("✅ Successfully uploaded {}", file_key);
+                
+                // Register file for cleanup tracking in the bucket it went to
+                self.cleanup_manager.for_bucket(&endpoint.bucket).register_file(&file_key).await;
+                
+                Ok(UploadedFile {
+                    file_key,
+                    upload_time,
+                    upload_instant,
+                    upload_start,
+                    retries,
+                    upload_errors,
+                    upload_error_code,
+                    multipart,
+                    self_verify,
+                    checksum,
+                    key_collisions,
+                    file_size,
+                    upload_endpoint,
+                    read_endpoint,
+                    span: Span::current(),
+                })
+            }
+            Err(e) => {
+                let upload_time = Utc::now(); // For error cases, use current time
+                error!("❌ Failed to upload test file {}: {}", file_key, e);
+                let mut failure = TestResult::failure(
+                    file_key,
+                    upload_time,
+                    ConsistencyTestError::Upload(e).to_string(),
+                );
+                failure.file_retries = retries;
+                failure.permanent_errors = upload_errors;
+                failure.last_error_code = upload_error_code;
+                failure.was_multipart = multipart;
+                failure.key_collisions = key_collisions;
+                failure.file_size = Some(file_size);
+                failure.upload_endpoint = self.endpoint_url(upload_endpoint);
+                failure.bucket = self.bucket_name(upload_endpoint);
+                failure.timeline = args.detailed_timeline.then(|| FileTimeline::new(upload_start));
+                if let Some(dashboard) = &self.dashboard {
+                    dashboard.record(&failure);
+                }
+                Err(failure)
+            }
+        }
+    }
+    
+    /// Claims the single failed request that `--debug-first-failure` logs
+    /// 
+    /// # Arguments
+    /// 
+    /// * `args` - Test arguments containing the debug flag
+    /// 
+    /// # Returns
+    /// 
+    /// `true` for the first failure of the run if `args.debug_first_failure`
+    /// is set, `false` otherwise
+    fn claim_first_failure(&self, args: &Args) -> bool {
+        args.debug_first_failure && !self.first_failure_logged.swap(true, Ordering::Relaxed)
+    }
+    
+    /// Logs the details of a failed upload for `--debug-first-failure`
+    /// 
+    /// # Arguments
+    /// 
+    /// * `endpoint` - The endpoint the file was uploaded through
+    /// * `file_key` - The key that was uploaded
+    /// * `hash_headers` - The content hash headers, if the upload stored them
+    /// * `error` - The error the upload failed with
+    fn log_failed_upload(&self, endpoint: &EndpointClients, file_key: &str, hash_headers: Option<HeaderMap>, error: &S3Error) {
+        let mut headers = upload_headers(&self.config).unwrap_or_default();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static(UPLOAD_CONTENT_TYPE));
+        headers.extend(self.sse_headers.clone());
+        headers.extend(hash_headers.unwrap_or_default());
+        
+        let url = format!("{}/{}", endpoint.upload_bucket.url(), file_key);
+        error!("🐞 First failed request of the run:\n{}", describe_failed_request("PUT", &url, &headers, error));
+    }
+    
+    /// Logs the details of a failed read for `--debug-first-failure`
+    /// 
+    /// # Arguments
+    /// 
+    /// * `endpoint` - The endpoint the file was read through
+    /// * `file_key` - The key that was read
+    /// * `ranged` - Whether only the first byte was requested
+    /// * `error` - The error the read failed with
+    fn log_failed_read(&self, endpoint: &EndpointClients, file_key: &str, ranged: bool, error: &S3Error) {
+        let mut headers = HeaderMap::new();
+        if ranged {
+            headers.insert(RANGE, HeaderValue::from_static("bytes=0-0"));
+        }
+        
+        // Reads may go to a separately configured reader endpoint
+        let config = endpoint.config(&self.config);
+        let reader_config = config.reader_config().unwrap_or(config);
+        let url = format!("{}/{}", reader_config.bucket_url(), file_key);
+        error!("🐞 First failed request of the run:\n{}", describe_failed_request("GET", &url, &headers, error));
+    }
+    
+    /// Finds a key with no existing object for a file about to be uploaded
+    /// 
+    /// Sends a HEAD request for the key and, if an object already exists,
+    /// generates a new key, up to `MAX_KEY_REGENERATIONS` times. Each HEAD
+    /// request counts as a read.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `file_key` - The generated key to check first
+    /// * `endpoint` - The endpoint the file will be uploaded through
+    /// * `args` - Test arguments containing the key pattern
+    /// 
+    /// # Returns
+    /// 
+    /// - `Ok((String, u32))` - An unused key and the number of collisions found
+    /// - `Err(TestResult)` - A failed result if every key collided or a HEAD
+    ///   request failed
+    async fn find_unused_key(&self, mut file_key: String, endpoint: &EndpointClients, args: &Args) -> std::result::Result<(String, u32), TestResult> {
+        let mut collisions = 0;
+        
+        let error = loop {
+            self.requests.record(RequestKind::Read);
+            match endpoint.storage.head(&file_key).await {
+                Ok(false) => return Ok((file_key, collisions)),
+                Ok(true) => {
+                    collisions += 1;
+                    if collisions > MAX_KEY_REGENERATIONS {
+                        break format!(
+                            "Key collision: {} already exists, as did {} regenerated keys",
+                            file_key, MAX_KEY_REGENERATIONS
+                        );
+                    }
+                    
+                    let next_key = self.keys.next_key(args.key_pattern.as_ref(), args.key_style);
+                    warn!("🔑 Key {} already exists, using {} instead", file_key, next_key);
+                    file_key = next_key;
+                }
+                Err(e) => break format!("Collision check failed: {}", e),
             }
         };
         
-        debug!("Using S3 region: {:?}", region);
-
-        // Create bucket handle
-        let mut bucket = Bucket::new(&config.bucket, region, credentials)
-            .with_context(|| format!("Failed to create S3 bucket handle for: {}", config.bucket))?;
+        error!("❌ Not uploading test file {}: {}", file_key, error);
+        let mut failure = TestResult::failure(file_key, Utc::now(), error);
+        failure.key_collisions = collisions;
+        if let Some(dashboard) = &self.dashboard {
+            dashboard.record(&failure);
+        }
+        Err(failure)
+    }
+    
+    /// Uploads a file with an explicit multipart upload
+    /// 
+    /// Initiates the upload, sends `args.multipart_part_size` parts of random
+    /// data generated one part at a time, and commits them with
+    /// CompleteMultipartUpload. If any step fails the upload is aborted so no
+    /// orphaned parts are left in the bucket. Every request counts as an upload.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `bucket` - The upload bucket handle of the chosen endpoint
+    /// * `file_key` - The key to upload to
+    /// * `file_size` - Size of the file in bytes
+    /// * `args` - Test arguments containing the part size
+    /// 
+    /// # Returns
+    /// 
+    /// - `Ok(())` once CompleteMultipartUpload succeeded
+    /// - `Err(S3Error)` if initiating, a part or the completion failed
+    async fn upload_multipart(&self, bucket: &Bucket, file_key: &str, file_size: usize, args: &Args) -> std::result::Result<(), S3Error> {
+        self.requests.record(RequestKind::Upload);
+        let upload = bucket
+            .initiate_multipart_upload(file_key, UPLOAD_CONTENT_TYPE)
+            .await?;
         
-        // Configure path style if needed
-        if config.get_path_style() {
-            debug!("Using path-style URLs");
-            bucket = bucket.with_path_style();
-        } else {
-            debug!("Using virtual-hosted-style URLs");
+        let completed = self.upload_parts_and_complete(bucket, file_key, &upload.upload_id, file_size, args).await;
+        if completed.is_err() {
+            self.requests.record(RequestKind::Upload);
+            if let Err(e) = bucket.abort_upload(file_key, &upload.upload_id).await {
+                warn!("⚠️  Failed to abort multipart upload of {}: {}", file_key, e);
+            }
         }
-
-        // Create cleanup manager
-        let cleanup_manager = Arc::new(CleanupManager::new(bucket.clone()));
-
-        info!("Successfully connected to S3 bucket: {}", config.bucket);
+        completed
+    }
+    
+    /// Sends the parts of a multipart upload and completes it
+    /// 
+    /// # Arguments
+    /// 
+    /// * `bucket` - The bucket handle the upload was initiated with
+    /// * `file_key` - The key being uploaded
+    /// * `upload_id` - The ID returned when the upload was initiated
+    /// * `file_size` - Size of the file in bytes
+    /// * `args` - Test arguments containing the part size
+    /// 
+    /// # Returns
+    /// 
+    /// - `Ok(())` once CompleteMultipartUpload succeeded
+    /// - `Err(S3Error)` if a part or the completion failed
+    async fn upload_parts_and_complete(&self, bucket: &Bucket, file_key: &str, upload_id: &str, file_size: usize, args: &Args) -> std::result::Result<(), S3Error> {
+        let mut parts = Vec::new();
+        let mut uploaded = 0;
         
-        Ok(Self {
-            bucket,
-            config,
-            cleanup_manager,
-        })
+        // Always send at least one part, even for an empty file
+        loop {
+            let part_size = (file_size - uploaded).min(args.multipart_part_size);
+            let part_number = parts.len() as u32 + 1;
+            
+            if let Some(throttle) = &self.upload_throttle {
+                throttle.wait(part_size).await;
+            }
+            self.requests.record(RequestKind::Upload);
+            let part = bucket
+                .put_multipart_chunk(
+                    self.generate_test_data(part_size),
+                    file_key,
+                    part_number,
+                    upload_id,
+                    UPLOAD_CONTENT_TYPE,
+                )
+                .await?;
+            parts.push(part);
+            
+            uploaded += part_size;
+            if uploaded >= file_size {
+                break;
+            }
+        }
+        
+        debug!("📦 Uploaded {} parts of {}, completing multipart upload", parts.len(), file_key);
+        self.requests.record(RequestKind::Upload);
+        bucket.complete_multipart_upload(file_key, upload_id, parts).await?;
+        Ok(())
     }
     
-    /// Gets a reference to the cleanup manager
+    /// Polls an uploaded file until it is consistent
     /// 
-    /// This allows external code to set up signal handlers and perform
-    /// emergency cleanup operations.
+    /// With `args.writer_read_your_writes`, the writer's read-back check runs
+    /// alongside the first poll so it does not delay the reader. In burst mode
+    /// polling starts after every upload, so the check is less immediate.
+    /// With `args.test_replication`, the replication target is polled
+    /// concurrently with the source bucket. With `args.metadata_skew`, the
+    /// file's metadata is polled with HEAD alongside the reads.
+    /// The caller is responsible for cleaning up the file afterwards.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `uploaded` - The file returned by `upload_test_file`
+    /// * `args` - Test arguments containing timeout settings
+    /// * `interval_ms` - Check interval to poll this file at
     /// 
     /// # Returns
     /// 
-    /// An `Arc` reference to the cleanup manager
-    pub fn cleanup_manager(&self) -> Arc<CleanupManager> {
-        self.cleanup_manager.clone()
+    /// A `TestResult` containing the outcome and timing data for this file
+    async fn poll_uploaded_file(&self, uploaded: UploadedFile, args: &Args, interval_ms: u64) -> TestResult {
+        let mut observed = ReadObservations::default();
+        
+        // Test for consistency by repeatedly trying to read the file
+        let organic_delay = sample_organic_delay(
+            &mut rand::thread_rng(),
+            args.organic_read_fraction,
+            args.organic_read_mean_ms,
+            Duration::from_secs(args.max_wait),
+        );
+        let span = uploaded.span.clone();
+        let (consistency_result, writer_read_your_writes, replication, organic_read, metadata_consistent) = async {
+            tokio::join!(
+                self.test_read_consistency(&uploaded, args, interval_ms, &mut observed),
+                async {
+                    if args.writer_read_your_writes {
+                        let endpoint = &self.endpoints[uploaded.upload_endpoint];
+                        Some(self.check_writer_read_your_writes(endpoint, &uploaded.file_key, uploaded.file_size, args).await)
+                    } else {
+                        None
+                    }
+                },
+                async {
+                    match &self.replica_storage {
+                        Some(replica) if args.test_replication => {
+                            self.check_replication(replica.as_ref(), &uploaded, args, interval_ms).await
+                        }
+                        _ => None,
+                    }
+                },
+                async {
+                    match organic_delay {
+                        Some(delay) => Some(self.check_organic_read(&uploaded, delay, args).await),
+                        None => None,
+                    }
+                },
+                async {
+                    if args.metadata_skew {
+                        self.check_metadata(&uploaded, args, interval_ms).await
+                    } else {
+                        None
+                    }
+                }
+            )
+        }
+        .instrument(span.clone())
+        .await;
+        let UploadedFile { file_key, upload_time, upload_instant, upload_start, retries, upload_errors, upload_error_code, multipart, key_collisions, file_size, upload_endpoint, read_endpoint, .. } = uploaded;
+        
+        // Process the consistency test result
+        let mut result = match consistency_result {
+            Ok(read) => {
+                debug!("🎯 Consistency achieved for {} after {} attempts", file_key, read.attempts);
+                let mut result = TestResult::success(
+                    file_key,
+                    upload_time,
+                    read.success_time,
+                    read.attempts,
+                );
+                let propagation = read.success_instant.duration_since(upload_instant);
+                warn_on_clock_change(&result, propagation);
+                result.set_propagation(propagation);
+                result.elapsed_ms_at_success = Some(read.elapsed_ms);
+                result.cumulative_sleep_ms = Some(read.sleep_ms);
+                result.content_self_verified = read.content_self_verified;
+                result.checksum_verified = read.checksum_verified;
+                result.captured_headers = read.captured_headers;
+                result
+            }
+            Err(e) => {
+                error!("❌ Consistency test failed for {}: {}", file_key, e);
+                let mut result = TestResult::failure(
+                    file_key,
+                    upload_time,
+                    e.to_string(),
+                );
+                if let ConsistencyTestError::Read { attempts, early_abort_reason, .. } = e {
+                    result.total_attempts = attempts;
+                    result.early_abort_reason = early_abort_reason;
+                }
+                result
+            }
+        };
+        
+        result.check_interval_ms = Some(interval_ms);
+        result.writer_read_your_writes = writer_read_your_writes;
+        result.replication_propagation_duration_ms = replication;
+        result.metadata_consistent_duration_ms = metadata_consistent;
+        result.metadata_body_skew_ms = metadata_consistent
+            .zip(result.propagation_duration_ms)
+            .map(|(metadata, body)| metadata as i64 - body as i64);
+        result.organic_read_delay_ms = organic_delay.map(|delay| delay.as_millis() as u64);
+        result.organic_read_consistent = organic_read;
+        result.size_mismatch_events = observed.size_mismatches;
+        result.flapping_detected = observed.flapping;
+        if args.expect_404_first {
+            result.status_sequence = Some(format_status_sequence(&observed.statuses));
+            result.status_anomaly = find_status_anomaly(&observed.statuses, args.status_anomaly_grace);
+            if let Some(anomaly) = &result.status_anomaly {
+                warn!("🚦 Reads of {} deviated from 404s followed by the object: {}", result.file_key, anomaly);
+            }
+        }
+        result.file_retries = retries;
+        result.recovered_errors = upload_errors;
+        // Read errors were recovered from if polling eventually saw the object
+        let read_errors = if result.success { &mut result.recovered_errors } else { &mut result.permanent_errors };
+        for (category, count) in observed.errors {
+            *read_errors.entry(category).or_default() += count;
+        }
+        result.last_error_code = observed.last_error_code.or(upload_error_code);
+        result.was_multipart = multipart;
+        result.key_collisions = key_collisions;
+        result.file_size = Some(file_size);
+        result.upload_endpoint = self.endpoint_url(upload_endpoint);
+        result.read_endpoint = self.endpoint_url(read_endpoint);
+        result.bucket = self.bucket_name(upload_endpoint);
+        result.timeline = args.detailed_timeline.then(|| FileTimeline {
+            upload_end: Some(upload_time),
+            polls: observed.poll_times,
+            success: result.first_read_success_time,
+            ..FileTimeline::new(upload_start)
+        });
+        result.sla_met = args.sla_ms.map(|target| {
+            result.success && result.propagation_duration_ms.is_some_and(|duration| duration <= target)
+        });
+        
+        if let Some(dashboard) = &self.dashboard {
+            dashboard.record(&result);
+        }
+        span.record(FAILED_FIELD, !result.success);
+        result
     }
     
-    /// Runs the complete consistency test suite
+    /// Checks whether the uploading client can read a file it just wrote
     /// 
-    /// Executes the full testing process including file uploads, consistency checks,
-    /// statistics calculation, and report generation. This is the main entry point
-    /// for running consistency tests.
+    /// Makes a single read through the same storage, and so the same
+    /// endpoint and connection pool, that uploaded the file. Unlike the
+    /// reader's polling this is not retried.
     /// 
     /// # Arguments
     /// 
-    /// * `args` - Command-line arguments specifying test parameters
+    /// * `endpoint` - The endpoint the file was uploaded through
+    /// * `file_key` - The key that was just uploaded
+    /// * `file_size` - Size of the uploaded file in bytes
+    /// * `args` - Test arguments containing the stream threshold
     /// 
     /// # Returns
     /// 
-    /// A `ConsistencyReport` containing all test results and statistics
+    /// `true` if the read succeeded, `false` if it failed or timed out
+    async fn check_writer_read_your_writes(&self, endpoint: &EndpointClients, file_key: &str, file_size: usize, args: &Args) -> bool {
+        self.requests.record(RequestKind::Read);
+        let range = (file_size > args.stream_threshold).then_some((0, 0));
+        
+        match timeout(Duration::from_secs(5), endpoint.storage.get(file_key, range)).await {
+            Ok(Ok(_)) => {
+                debug!("✍️  Writer read its own write of {} immediately", file_key);
+                true
+            }
+            Ok(Err(e)) => {
+                debug!("✍️  Writer could not read its own write of {}: {}", file_key, e);
+                false
+            }
+            Err(_) => {
+                debug!("✍️  Writer read-back of {} timed out", file_key);
+                false
+            }
+        }
+    }
+
+    /// Polls an uploaded file's metadata until it is correct
     /// 
-    /// # Process
+    /// Sends a HEAD through the endpoint the file is polled through every
+    /// `interval_ms` milliseconds until the response has the file's content
+    /// length and content type, and the `--self-verify` metadata header if
+    /// one was stored, or `args.max_wait` elapses. Any error counts as not
+    /// yet correct.
     /// 
-    /// 1. Log test configuration and start timing
-    /// 2. Execute individual file tests sequentially
-    /// 3. Calculate comprehensive statistics
-    /// 4. Perform final cleanup check
-    /// 5. Generate and return complete report
+    /// # Arguments
     /// 
-    /// # Examples
+    /// * `uploaded` - The uploaded file
+    /// * `args` - Test arguments containing timeout settings
+    /// * `interval_ms` - Time to wait between HEAD requests in milliseconds
     /// 
-    /// ```rust
-    /// let report = tester.run_consistency_test(&args).await?;
-    /// println!("Success rate: {:.1}%", report.statistics.success_rate);
-    /// ```
-    pub async fn run_consistency_test(&self, args: &Args) -> Result<ConsistencyReport> {
-        let test_start = Utc::now();
-        let start_instant = Instant::now();
-        
-        // Log test configuration
-        info!("🚀 Starting S3 consistency test");
-        info!("📍 S3 endpoint: {}", self.config.endpoint);
-        info!("🪣 Bucket: {}", self.config.bucket);
-        info!("📊 Test files: {}", args.test_count);
-        info!("📁 File size: {} bytes", args.file_size);
-        info!("⏰ Max wait time: {} seconds", args.max_wait);
-        info!("🔄 Check interval: {} ms", args.interval);
+    /// # Returns
+    /// 
+    /// The time from upload completion until the metadata was correct in
+    /// milliseconds, or `None` if it was still wrong after `args.max_wait`
+    async fn check_metadata(&self, uploaded: &UploadedFile, args: &Args, interval_ms: u64) -> Option<u64> {
+        let file_key = uploaded.file_key.as_str();
+        let reader = &self.endpoints[uploaded.read_endpoint].reader_storage;
+        let max_duration = Duration::from_secs(args.max_wait);
+        let check_interval = Duration::from_millis(interval_ms);
+        let start_time = Instant::now();
+        let expected_length = uploaded.file_size.to_string();
         
-        let mut results = Vec::with_capacity(args.test_count);
-
-        // Execute individual tests
-        for i in 0..args.test_count {
-            info!("🧪 Testing file {}/{}", i + 1, args.test_count);
-            
-            let test_result = self.test_single_file(args).await;
-            results.push(test_result);
+        loop {
+            self.requests.record(RequestKind::Read);
+            match timeout(Duration::from_secs(5), reader.metadata(file_key)).await {
+                Ok(Ok(Some(headers)))
+                    if headers.get("content-length") == Some(&expected_length)
+                        && headers.get("content-type").is_none_or(|content_type| content_type == UPLOAD_CONTENT_TYPE)
+                        && (!uploaded.self_verify || headers.contains_key(CONTENT_HASH_HEADER)) =>
+                {
+                    let metadata_ms = uploaded.upload_instant.elapsed().as_millis() as u64;
+                    debug!("🏷️  Metadata of {} correct after {}ms", file_key, metadata_ms);
+                    return Some(metadata_ms);
+                }
+                Ok(Ok(Some(headers))) => {
+                    debug!("🏷️  Metadata of {} not yet correct: {:?}", file_key, headers);
+                }
+                Ok(Ok(None)) => {
+                    debug!("🏷️  HEAD of {} found no object yet", file_key);
+                }
+                Ok(Err(e)) => {
+                    debug!("🏷️  HEAD of {} failed: {}", file_key, e);
+                }
+                Err(_) => {
+                    debug!("⏰ HEAD of {} timed out", file_key);
+                }
+            }
             
-            // Small delay between tests to avoid overwhelming the server
-            if i < args.test_count - 1 {
-                sleep(Duration::from_millis(100)).await;
+            if start_time.elapsed() >= max_duration {
+                warn!(
+                    "⏰ Metadata of {} was still not correct after {}ms",
+                    file_key, max_duration.as_millis()
+                );
+                return None;
             }
+            
+            sleep(check_interval).await;
         }
-
-        let test_end = Utc::now();
-        let total_duration = start_instant.elapsed();
-
-        // Calculate statistics
-        info!("📈 Calculating test statistics...");
-        let statistics = statistics::calculate_statistics(&results);
-        
-        // Perform final cleanup check
-        info!("🧹 Performing final cleanup check...");
-        self.cleanup_manager.final_cleanup_check().await;
-        
-        // Create comprehensive report
-        let report = ConsistencyReport::new(
-            test_start,
-            test_end,
-            self.config.clone(),
-            TestParameters::from(args),
-            results,
-            statistics,
-        );
-
-        info!("✅ Test completed in {}ms", total_duration.as_millis());
-        
-        Ok(report)
     }
-
-    /// Tests consistency for a single file
+    
+    /// Reads an uploaded file once, as a reader arriving after `delay` would
     /// 
-    /// Performs the complete test cycle for one file: upload, consistency checking,
-    /// and cleanup. This is the core testing logic that measures propagation time.
+    /// Waits until `delay` after the upload completed, then makes a single
+    /// read through the same endpoint the file is polled through. The read
+    /// is consistent if it returns the full object, or its first byte for
+    /// files larger than `args.stream_threshold`.
     /// 
     /// # Arguments
     /// 
-    /// * `args` - Test arguments containing timing and size parameters
+    /// * `uploaded` - The uploaded file
+    /// * `delay` - Time after upload completion to read at
+    /// * `args` - Test arguments containing the stream threshold
     /// 
     /// # Returns
     /// 
-    /// A `TestResult` containing the outcome and timing data for this test
+    /// `true` if the read returned the uploaded object, `false` if it failed,
+    /// timed out or returned the wrong number of bytes
+    async fn check_organic_read(&self, uploaded: &UploadedFile, delay: Duration, args: &Args) -> bool {
+        let file_key = uploaded.file_key.as_str();
+        let endpoint = &self.endpoints[uploaded.read_endpoint];
+        let ranged = uploaded.file_size > args.stream_threshold;
+        let expected_len = if ranged { 1 } else { uploaded.file_size };
+        
+        tokio::time::sleep_until((uploaded.upload_instant + delay).into()).await;
+        
+        self.requests.record(RequestKind::Read);
+        match timeout(Duration::from_secs(5), endpoint.reader_storage.get(file_key, ranged.then_some((0, 0)))).await {
+            Ok(Ok(response)) if response.body.len() == expected_len => {
+                debug!("👤 Organic read of {} after {}ms was consistent", file_key, delay.as_millis());
+                true
+            }
+            Ok(Ok(response)) => {
+                debug!(
+                    "👤 Organic read of {} after {}ms returned {} bytes instead of {}",
+                    file_key, delay.as_millis(), response.body.len(), expected_len
+                );
+                false
+            }
+            Ok(Err(e)) => {
+                debug!("👤 Organic read of {} after {}ms failed: {}", file_key, delay.as_millis(), e);
+                false
+            }
+            Err(_) => {
+                debug!("👤 Organic read of {} after {}ms timed out", file_key, delay.as_millis());
+                false
+            }
+        }
+    }
+    
+    /// Polls the replication target until an uploaded file can be read there
     /// 
-    /// # Process
+    /// Reads the same key from the destination bucket every `interval_ms`
+    /// milliseconds until a read succeeds or `args.max_wait` elapses. Any
+    /// error counts as not yet replicated. Files larger than
+    /// `args.stream_threshold` are read with a one-byte range.
     /// 
-    /// 1. Generate unique test file with random data
-    /// 2. Upload file to S3 and record timestamp
-    /// 3. Register file for cleanup tracking
-    /// 4. Perform consistency polling until readable or timeout
-    /// 5. Clean up test file
-    /// 6. Return result with timing information
+    /// # Arguments
     /// 
-    /// # Examples
+    /// * `replica` - Storage for the destination bucket
+    /// * `uploaded` - The file uploaded to the source bucket
+    /// * `args` - Test arguments containing timeout settings
+    /// * `interval_ms` - Time to wait between read attempts in milliseconds
     /// 
-    /// ```rust
-    /// let result = tester.test_single_file(&args).await;
-    /// if result.success {
-    ///     println!("Propagation time: {}ms", result.propagation_duration_ms.unwrap());
-    /// }
-    /// ```
-    async fn test_single_file(&self, args: &Args) -> TestResult {
-        // Generate unique test file
-        let file_key = format!("consistency-test-{}", Uuid::new_v4());
-        let test_data = self.generate_test_data(args.file_size);
-        
-        debug!("📤 Uploading test file: {}", file_key);
+    /// # Returns
+    /// 
+    /// The time from upload completion until the first successful read in
+    /// milliseconds, or `None` if the file had not replicated after `args.max_wait`
+    async fn check_replication(&self, replica: &dyn ObjectStore, uploaded: &UploadedFile, args: &Args, interval_ms: u64) -> Option<u64> {
+        let file_key = uploaded.file_key.as_str();
+        let max_duration = Duration::from_secs(args.max_wait);
+        let check_interval = Duration::from_millis(interval_ms);
+        let start_time = Instant::now();
+        let range = (uploaded.file_size > args.stream_threshold).then_some((0, 0));
         
-        // Attempt to upload the file
-        match self.bucket.put_object(&file_key, &test_data).await {
-            Ok(_) => {
-                // Record upload completion time - this is the baseline for consistency measurement
-                let upload_time = Utc::now();
-                debug!("✅ Successfully uploaded {}", file_key);
-                
-                // Register file for cleanup tracking
-                self.cleanup_manager.register_file(&file_key).await;
-                
-                // Test for consistency by repeatedly trying to read the file
-                let consistency_result = self.test_read_consistency(&file_key, args).await;
-                
-                // Always clean up the test file
-                self.cleanup_manager.cleanup_file(&file_key).await;
-                
-                // Process the consistency test result
-                match consistency_result {
-                    Ok((first_success_time, attempts)) => {
-                        debug!("🎯 Consistency achieved for {} after {} attempts", file_key, attempts);
-                        TestResult::success(
-                            file_key,
-                            upload_time,
-                            first_success_time,
-                            attempts,
-                        )
-                    }
-                    Err(e) => {
-                        error!("❌ Consistency test failed for {}: {}", file_key, e);
-                        TestResult::failure(
-                            file_key,
-                            upload_time,
-                            e.to_string(),
-                        )
-                    }
+        loop {
+            self.requests.record(RequestKind::Read);
+            match timeout(Duration::from_secs(5), replica.get(file_key, range)).await {
+                Ok(Ok(_)) => {
+                    let replication_ms = uploaded.upload_instant.elapsed().as_millis() as u64;
+                    debug!("🪞 {} replicated after {}ms", file_key, replication_ms);
+                    return Some(replication_ms);
+                }
+                Ok(Err(e)) => {
+                    debug!("🪞 {} not yet readable in the replication target: {}", file_key, e);
+                }
+                Err(_) => {
+                    debug!("⏰ Replication target read of {} timed out", file_key);
                 }
             }
-            Err(e) => {
-                let upload_time = Utc::now(); // For error cases, use current time
-                error!("❌ Failed to upload test file {}: {}", file_key, e);
-                TestResult::failure(
-                    file_key,
-                    upload_time,
-                    format!("Upload failed: {}", e),
-                )
+            
+            if start_time.elapsed() >= max_duration {
+                warn!(
+                    "⏰ {} had not replicated to the replication target after {}ms",
+                    file_key, max_duration.as_millis()
+                );
+                return None;
             }
+            
+            sleep(check_interval).await;
         }
     }
-
+    
     /// Tests read consistency for an uploaded file
     /// 
     /// Repeatedly attempts to read a file until it becomes available or timeout occurs.
@@ -316,83 +3318,272 @@ impl S3ConsistencyTester {
     /// 
     /// # Arguments
     /// 
-    /// * `file_key` - The S3 object key to test for consistency
-    /// * `args` - Test arguments containing timeout and interval settings
+    /// * `uploaded` - The uploaded file, its size and whether its hash was stored
+    /// * `args` - Test arguments containing timeout settings
+    /// * `interval_ms` - Time to wait between read attempts in milliseconds
+    /// * `observed` - Receives size mismatches, flapping, and with
+    ///   `args.detailed_timeline` and `args.expect_404_first` the send time and
+    ///   status of each attempt
     /// 
     /// # Returns
     /// 
-    /// - `Ok((DateTime, u32))` - Success time and attempt count if file becomes readable
-    /// - `Err(anyhow::Error)` - If timeout occurs or other error happens
+    /// - `Ok(ReadSuccess)` - Success time, attempt count, elapsed polling time and
+    ///   total sleep time of the first read of the final streak once the file
+    ///   has been read `args.consecutive_reads` times in a row
+    /// - `Err(ConsistencyTestError::Read)` - If timeout occurs or polling is aborted early
+    /// - `Err(ConsistencyTestError::Connection)` - If a fresh reader client cannot be created
     /// 
     /// # Behavior
     /// 
-    /// - Polls the file at regular intervals (specified by `args.interval`)
+    /// - Polls the file at regular intervals (specified by `interval_ms`)
     /// - Each read attempt has a 5-second timeout to prevent hanging
-    /// - Continues until file is readable or `args.max_wait` seconds elapse
+    /// - Continues until file is readable or `args.max_wait` seconds elapse;
+    ///   a `max_wait` of 0 makes exactly one attempt
+    /// - Treats a successful read whose body is not the uploaded size (or one
+    ///   byte for ranged reads) as not yet consistent, since some gateways
+    ///   return empty or truncated bodies with a success status
+    /// - With `args.consecutive_reads` above 1, keeps polling after the first
+    ///   successful read until that many reads in a row returned the same
+    ///   content; a failed read or different content restarts the streak and
+    ///   sets `flapping`
+    /// - Aborts early with a `ConsistencyTestError::Read` carrying the reason
+    ///   after `args.abort_after` consecutive non-retryable errors (403,
+    ///   credential errors); retryable errors such as 404, 5xx and timeouts
+    ///   keep polling
+    /// - With `args.fresh_connection`, builds a new client for every attempt so
+    ///   no read reuses a pooled connection
+    /// - Captures the `args.capture_header` headers of the successful read
+    /// - With `args.detailed_timeline`, appends the time each attempt was sent
+    ///   to `observed.poll_times`
+    /// - With `args.expect_404_first`, appends each attempt's status to
+    ///   `observed.statuses`
+    /// - Counts failed attempts other than 404s by error category in
+    ///   `observed.errors`
     /// - Records precise timing and attempt counts
     /// 
     /// # Examples
     /// 
     /// ```rust
-    /// match tester.test_read_consistency("test-file", &args).await {
-    ///     Ok((success_time, attempts)) => {
-    ///         println!("File readable after {} attempts", attempts);
+    /// let mut observed = ReadObservations::default();
+    /// match tester.test_read_consistency(&uploaded, &args, 100, &mut observed).await {
+    ///     Ok(read) => {
+    ///         println!("File readable after {} attempts in {}ms", read.attempts, read.elapsed_ms);
     ///     }
     ///     Err(e) => {
     ///         println!("Consistency test failed: {}", e);
     ///     }
     /// }
     /// ```
-    async fn test_read_consistency(&self, file_key: &str, args: &Args) -> Result<(chrono::DateTime<Utc>, u32)> {
+    async fn test_read_consistency(
+        &self,
+        uploaded: &UploadedFile,
+        args: &Args,
+        interval_ms: u64,
+        observed: &mut ReadObservations,
+    ) -> std::result::Result<ReadSuccess, ConsistencyTestError> {
+        let file_key = uploaded.file_key.as_str();
+        let endpoint = &self.endpoints[uploaded.read_endpoint];
         let max_duration = Duration::from_secs(args.max_wait);
-        let check_interval = Duration::from_millis(args.interval);
+        let check_interval = Duration::from_millis(interval_ms);
         let start_time = Instant::now();
         let mut attempts = 0;
+        let mut sleep_time = Duration::ZERO;
 
         debug!("🔍 Starting consistency check for {}", file_key);
-        debug!("⏱️  Max wait: {}s, Check interval: {}ms", args.max_wait, args.interval);
+        debug!("⏱️  Max wait: {}s, Check interval: {}ms", args.max_wait, interval_ms);
+
+        let mut consecutive_non_retryable = 0;
+        let mut streak: Option<ReadStreak> = None;
+        
+        // Only fetch the first byte of large files so reads don't buffer them whole
+        let ranged = uploaded.file_size > args.stream_threshold;
+        let expected_len = if ranged { 1 } else { uploaded.file_size };
 
         loop {
             attempts += 1;
             
+            // Use a new client, and so a new connection, if connection reuse is disabled
+            let fresh_reader = if args.fresh_connection {
+                Some(self.fresh_reader(endpoint)?)
+            } else {
+                None
+            };
+            let reader: &dyn ObjectStore = match &fresh_reader {
+                Some(bucket) => &**bucket,
+                None => endpoint.reader_storage.as_ref(),
+            };
+            
             // Attempt to read the file with a timeout to prevent hanging
-            match timeout(Duration::from_secs(5), self.bucket.get_object(file_key)).await {
+            self.requests.record(RequestKind::Read);
+            if args.detailed_timeline {
+                observed.poll_times.push(Utc::now());
+            }
+            let read = timeout(Duration::from_secs(5), reader.get(file_key, ranged.then_some((0, 0)))).await;
+            if args.expect_404_first {
+                observed.statuses.push(match &read {
+                    Ok(Ok(response)) if response.body.len() != expected_len => ReadStatus::Placeholder(response.status_code),
+                    Ok(Ok(response)) => ReadStatus::Object(response.status_code),
+                    Ok(Err(S3Error::HttpFailWithBody(status, _))) => ReadStatus::Http(*status),
+                    Ok(Err(_)) => ReadStatus::Error,
+                    Err(_) => ReadStatus::Timeout,
+                });
+            }
+            let read_succeeded = match read {
+                Ok(Ok(response)) if response.body.len() != expected_len => {
+                    observed.size_mismatches += 1;
+                    warn!(
+                        "📏 Attempt {} for {} returned {} bytes instead of {}, not yet consistent",
+                        attempts, file_key, response.body.len(), expected_len
+                    );
+                    consecutive_non_retryable = 0;
+                    false
+                }
                 Ok(Ok(response)) => {
+                    let success_instant = Instant::now();
                     let success_time = Utc::now();
                     let elapsed = start_time.elapsed();
+                    consecutive_non_retryable = 0;
                     
-                    info!(
-                        "🎉 File {} became consistent after {} attempts in {}ms",
-                        file_key,
-                        attempts,
-                        elapsed.as_millis()
-                    );
+                    // Only hash the body when later reads of a streak must match it
+                    let body_hash = (args.consecutive_reads > 1).then(|| content_hash(&response.body));
+                    
+                    // Extend the current streak, or start a new one from this read
+                    streak = match streak.take() {
+                        Some(mut current) if current.body_hash == body_hash => {
+                            current.reads += 1;
+                            Some(current)
+                        }
+                        previous => {
+                            if previous.is_some() {
+                                observed.flapping = true;
+                                warn!(
+                                    "🔁 Attempt {} for {} returned different content, restarting the streak",
+                                    attempts, file_key
+                                );
+                            }
+                            
+                            debug!("📊 Response status: {}", response.status_code);
+                            
+                            // Check the body against the hash the upload stored in its metadata
+                            let content_self_verified = uploaded.self_verify.then(|| {
+                                let expected = response.headers.get(CONTENT_HASH_HEADER).cloned();
+                                let verified = expected.as_deref() == Some(content_hash(&response.body).as_str());
+                                if !verified {
+                                    warn!(
+                                        "🧬 Content of {} does not match its metadata hash ({})",
+                                        file_key, expected.as_deref().unwrap_or("missing")
+                                    );
+                                }
+                                verified
+                            });
+                            
+                            // Check the body against the checksum the backend returned with it
+                            let checksum_verified = uploaded.checksum.map(|algorithm| {
+                                let verified = algorithm.verify(&response.body, &response.headers);
+                                if !verified {
+                                    warn!(
+                                        "🔏 Content of {} does not match the {} checksum returned with it ({})",
+                                        file_key,
+                                        algorithm.header_name(),
+                                        response.headers.get(algorithm.header_name()).map_or("missing", String::as_str)
+                                    );
+                                }
+                                verified
+                            });
+                            
+                            Some(ReadStreak {
+                                first: ReadSuccess {
+                                    success_time,
+                                    success_instant,
+                                    attempts,
+                                    elapsed_ms: elapsed.as_millis() as u64,
+                                    sleep_ms: sleep_time.as_millis() as u64,
+                                    content_self_verified,
+                                    checksum_verified,
+                                    captured_headers: capture_headers(&response.headers, &args.capture_header),
+                                },
+                                body_hash,
+                                reads: 1,
+                            })
+                        }
+                    };
                     
-                    debug!("📊 Response status: {}", response.status_code());
-                    return Ok((success_time, attempts));
+                    if let Some(current) = streak.take_if(|current| current.reads >= args.consecutive_reads) {
+                        info!(
+                            "🎉 File {} became consistent after {} attempts in {}ms",
+                            file_key,
+                            current.first.attempts,
+                            current.first.elapsed_ms
+                        );
+                        return Ok(current.first);
+                    }
+                    true
                 }
                 Ok(Err(e)) => {
                     debug!("⚠️  Attempt {} failed for {}: {}", attempts, file_key, e);
+                    
+                    // A missing object is expected until the file propagates
+                    if !matches!(e, S3Error::HttpFailWithBody(404, _)) {
+                        count_error(&mut observed.errors, error_category(&e));
+                        observed.last_error_code = s3_error_code(&e).or(observed.last_error_code.take());
+                        if self.claim_first_failure(args) {
+                            self.log_failed_read(endpoint, file_key, ranged, &e);
+                        }
+                    }
+                    
+                    // Abort early if the backend keeps rejecting us for reasons
+                    // that waiting will not fix (e.g. authorization failures)
+                    match non_retryable_reason(&e) {
+                        Some(reason) => {
+                            consecutive_non_retryable += 1;
+                            if args.abort_after > 0 && consecutive_non_retryable >= args.abort_after {
+                                warn!(
+                                    "🛑 Aborting consistency test for {} after {} attempts: {}",
+                                    file_key, attempts, reason
+                                );
+                                return Err(ConsistencyTestError::Read {
+                                    attempts,
+                                    message: format!("Consistency test aborted early after {} attempts: {}", attempts, reason),
+                                    early_abort_reason: Some(reason),
+                                });
+                            }
+                        }
+                        None => consecutive_non_retryable = 0,
+                    }
+                    false
                 }
                 Err(_) => {
                     debug!("⏰ Attempt {} timed out for {}", attempts, file_key);
+                    count_error(&mut observed.errors, TIMEOUT_ERROR_CATEGORY.to_string());
+                    consecutive_non_retryable = 0;
+                    false
                 }
+            };
+            
+            // A failed read after a successful one means the backend is flapping
+            if !read_succeeded && streak.take().is_some() {
+                observed.flapping = true;
+                warn!(
+                    "🔁 Attempt {} for {} failed after a successful read, restarting the streak",
+                    attempts, file_key
+                );
             }
 
             // Check if we've exceeded the maximum wait time
             if start_time.elapsed() >= max_duration {
-                let error_msg = format!(
-                    "Consistency test timed out after {} attempts in {}ms (max: {}ms)",
-                    attempts,
-                    start_time.elapsed().as_millis(),
-                    max_duration.as_millis()
-                );
+                let error_msg = read_timeout_message(attempts, start_time.elapsed(), max_duration);
                 warn!("⏰ {}", error_msg);
-                return Err(anyhow::anyhow!(error_msg));
+                return Err(ConsistencyTestError::Read {
+                    attempts,
+                    early_abort_reason: None,
+                    message: error_msg,
+                });
             }
 
-            // Wait before the next attempt
+            // Wait before the next attempt, recording how long we actually slept
+            let sleep_start = Instant::now();
             sleep(check_interval).await;
+            sleep_time += sleep_start.elapsed();
         }
     }
 
@@ -428,6 +3619,8 @@ impl S3ConsistencyTester {
 mod tests {
     use super::*;
     use crate::config::S3Config;
+    use crate::keys::TEST_KEY_PREFIX;
+    use s3::serde_types::Object;
 
     fn create_test_config() -> S3Config {
         S3Config {
@@ -437,12 +3630,33 @@ mod tests {
             access_key: "minioadmin".to_string(),
             secret_key: "minioadmin".to_string(),
             path_style: Some(true),
+            ..Default::default()
         }
     }
 
+    #[test]
+    fn test_sample_organic_delay() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let max_delay = Duration::from_secs(10);
+        
+        assert!(sample_organic_delay(&mut rng, 0.0, 1000, max_delay).is_none());
+        
+        let delays: Vec<Duration> = (0..1000)
+            .map(|_| sample_organic_delay(&mut rng, 1.0, 1000, max_delay).unwrap())
+            .collect();
+        assert!(delays.iter().all(|delay| *delay <= max_delay));
+        
+        // The sample mean of an exponential distribution converges on its mean
+        let mean_ms = delays.iter().map(|delay| delay.as_secs_f64() * 1000.0).sum::<f64>() / delays.len() as f64;
+        assert!((800.0..1200.0).contains(&mean_ms), "mean was {}ms", mean_ms);
+        
+        let capped = sample_organic_delay(&mut rng, 1.0, 1_000_000, Duration::from_millis(5)).unwrap();
+        assert!(capped <= Duration::from_millis(5));
+    }
+    
     #[test]
     fn test_generate_test_data() {
-        let config = create_test_config();
+        let _config = create_test_config();
         // We can't easily test the async new() method without a real S3 service,
         // but we can test data generation logic by creating a mock tester
         
@@ -452,6 +3666,126 @@ mod tests {
         assert_eq!(test_data.len(), 1024);
     }
 
+    #[tokio::test]
+    async fn test_random_data_reader() {
+        use tokio::io::AsyncReadExt;
+        
+        // Larger than a single read buffer, so several chunks are produced
+        let mut reader = RandomDataReader::new(100_000);
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data).await.unwrap();
+        assert_eq!(data.len(), 100_000);
+    }
+    
+    #[test]
+    fn test_interval_tuner() {
+        let mut tuner = IntervalTuner::new();
+        assert_eq!(tuner.interval_ms(), AUTO_INTERVAL_INITIAL_MS);
+        
+        tuner.observe(2000);
+        assert_eq!(tuner.interval_ms(), 100);
+        
+        // Very fast propagation is clamped to the minimum interval
+        for _ in 0..AUTO_INTERVAL_WINDOW {
+            tuner.observe(0);
+        }
+        assert_eq!(tuner.interval_ms(), AUTO_INTERVAL_MIN_MS);
+        
+        // Very slow propagation is clamped to the maximum interval
+        for _ in 0..AUTO_INTERVAL_WINDOW {
+            tuner.observe(10_000_000);
+        }
+        assert_eq!(tuner.interval_ms(), AUTO_INTERVAL_MAX_MS);
+    }
+
+    fn create_list_page(is_truncated: bool, next_continuation_token: Option<&str>, keys: &[&str]) -> ListBucketResult {
+        ListBucketResult {
+            name: "test-bucket".to_string(),
+            delimiter: None,
+            max_keys: None,
+            prefix: Some(TEST_KEY_PREFIX.to_string()),
+            continuation_token: None,
+            encoding_type: None,
+            is_truncated,
+            next_continuation_token: next_continuation_token.map(str::to_string),
+            contents: keys.iter().map(|key| Object {
+                last_modified: String::new(),
+                e_tag: None,
+                storage_class: None,
+                key: key.to_string(),
+                owner: None,
+                size: 0,
+            }).collect(),
+            common_prefixes: None,
+        }
+    }
+
+    #[test]
+    fn test_next_list_marker() {
+        // Complete listing
+        assert_eq!(next_list_marker(&create_list_page(false, None, &["a", "b"])), None);
+        
+        // ListObjectsV2 continuation token
+        assert_eq!(
+            next_list_marker(&create_list_page(true, Some("token"), &["a", "b"])),
+            Some("token".to_string())
+        );
+        
+        // ListObjects v1 without NextMarker continues from the last key
+        assert_eq!(
+            next_list_marker(&create_list_page(true, None, &["a", "b"])),
+            Some("b".to_string())
+        );
+    }
+
+    #[test]
+    fn test_content_hash() {
+        // SHA-256 of "hello" starts with 2cf24dba5fb0a30e
+        assert_eq!(content_hash(b"hello"), "2cf24dba5fb0a30e");
+        
+        let headers = content_hash_headers(b"hello");
+        assert_eq!(headers.get(CONTENT_HASH_HEADER).unwrap(), "2cf24dba5fb0a30e");
+    }
+    
+    #[test]
+    fn test_read_timeout_message() {
+        // --max-wait 0 allows a single attempt and is not reported as a timeout
+        let message = read_timeout_message(1, Duration::from_millis(3), Duration::ZERO);
+        assert_eq!(message, "File was not readable on the single attempt allowed by --max-wait 0");
+        
+        let message = read_timeout_message(12, Duration::from_millis(1203), Duration::from_secs(1));
+        assert_eq!(message, "Consistency test timed out after 12 attempts in 1203ms (max: 1000ms)");
+    }
+    
+    #[test]
+    fn test_capture_headers() {
+        let headers = HashMap::from([
+            ("x-cache".to_string(), "Hit from cloudfront".to_string()),
+            ("x-amz-request-id".to_string(), "4442587FB7D0A2F9".to_string()),
+        ]);
+        let names = vec!["X-Cache".to_string(), "age".to_string()];
+        
+        let captured = capture_headers(&headers, &names);
+        assert_eq!(captured.len(), 1);
+        assert_eq!(captured["x-cache"], "Hit from cloudfront");
+    }
+    
+    #[test]
+    fn test_non_retryable_reason() {
+        assert!(non_retryable_reason(&S3Error::HttpFailWithBody(403, String::new())).is_some());
+        assert!(non_retryable_reason(&S3Error::HttpFailWithBody(401, String::new())).is_some());
+        assert!(non_retryable_reason(&S3Error::HttpFailWithBody(404, String::new())).is_none());
+        assert!(non_retryable_reason(&S3Error::HttpFailWithBody(503, String::new())).is_none());
+    }
+
+    #[test]
+    fn test_s3_error_code() {
+        let body = "<?xml version=\"1.0\"?>\n<Error><Code>SlowDown</Code><Message>Reduce your request rate.</Message></Error>";
+        assert_eq!(s3_error_code(&S3Error::HttpFailWithBody(503, body.to_string())).as_deref(), Some("SlowDown"));
+        assert_eq!(s3_error_code(&S3Error::HttpFailWithBody(404, String::new())), None);
+        assert_eq!(s3_error_code(&S3Error::HttpFailWithBody(500, "Internal Server Error".to_string())), None);
+    }
+    
     #[test]
     fn test_s3_config_path_style_detection() {
         let aws_config = S3Config {
@@ -461,6 +3795,7 @@ mod tests {
             access_key: "test".to_string(),
             secret_key: "test".to_string(),
             path_style: None,
+            ..Default::default()
         };
         
         assert!(!aws_config.get_path_style()); // AWS should default to false
@@ -472,8 +3807,206 @@ mod tests {
             access_key: "test".to_string(),
             secret_key: "test".to_string(),
             path_style: None,
+            ..Default::default()
         };
         
         assert!(minio_config.get_path_style()); // Non-AWS should default to true
     }
+    
+    #[tokio::test]
+    async fn test_single_file_against_mock() {
+        use crate::mock::MockStorage;
+        use clap::Parser;
+        
+        let storage = Arc::new(MockStorage::new(Duration::from_millis(100), 0.0));
+        let tester = S3ConsistencyTester::new(create_test_config()).await.unwrap()
+            .with_storage(storage.clone());
+        let args = Args::parse_from([
+            "s3-consistency-test", "--config", "config.toml", "--interval", "10", "--self-verify",
+            "--checksum", "crc32c",
+        ]);
+        
+        let result = tester.test_single_file(&args, args.interval).await;
+        
+        assert!(result.success, "{:?}", result.error_details);
+        assert!(result.propagation_duration_ms.unwrap() >= 100);
+        assert_eq!(result.content_self_verified, Some(true));
+        assert_eq!(result.checksum_verified, Some(true));
+        assert_eq!(storage.object_count(), 0);
+    }
+    
+    #[tokio::test]
+    async fn test_recreate_after_delete_against_mock() {
+        use crate::mock::MockStorage;
+        use clap::Parser;
+        
+        let storage = Arc::new(MockStorage::new(Duration::from_millis(100), 0.0));
+        let tester = S3ConsistencyTester::new(create_test_config()).await.unwrap()
+            .with_storage(storage.clone());
+        let args = Args::parse_from([
+            "s3-consistency-test", "--config", "config.toml", "--interval", "10", "--file-size", "64",
+            "--recreate-after-delete",
+        ]);
+        
+        let result = tester.test_single_file(&args, args.interval).await;
+        
+        assert!(result.success, "{:?}", result.error_details);
+        assert_eq!(result.recreate_error, None);
+        assert!(result.recreate_after_delete_propagation_ms.unwrap() >= 90);
+        assert_eq!(storage.object_count(), 0);
+    }
+    
+    #[tokio::test]
+    async fn test_consecutive_reads_against_mock() {
+        use crate::mock::MockStorage;
+        use clap::Parser;
+        
+        let storage = Arc::new(MockStorage::new(Duration::from_millis(50), 0.0));
+        let tester = S3ConsistencyTester::new(create_test_config()).await.unwrap()
+            .with_storage(storage);
+        let args = Args::parse_from([
+            "s3-consistency-test", "--config", "config.toml", "--interval", "10", "--consecutive-reads", "3",
+        ]);
+        
+        let result = tester.test_single_file(&args, args.interval).await;
+        
+        assert!(result.success, "{:?}", result.error_details);
+        assert!(!result.flapping_detected);
+        assert!(result.propagation_duration_ms.unwrap() >= 50);
+    }
+    
+    #[tokio::test]
+    async fn test_connect_with_retry_against_mock() {
+        use crate::mock::MockStorage;
+        
+        let tester = S3ConsistencyTester::new(create_test_config()).await.unwrap()
+            .with_storage(Arc::new(MockStorage::new(Duration::ZERO, 0.0)));
+        assert!(tester.connect_with_retry(Duration::from_secs(1), 0).await.is_ok());
+        
+        let tester = S3ConsistencyTester::new(create_test_config()).await.unwrap()
+            .with_storage(Arc::new(MockStorage::new(Duration::ZERO, 1.0)));
+        let error = tester.connect_with_retry(Duration::from_secs(1), 0).await.unwrap_err();
+        assert!(matches!(error, ConsistencyTestError::Connection { .. }));
+    }
+    
+    #[tokio::test]
+    async fn test_paginated_list_completeness_against_mock() {
+        use crate::mock::MockStorage;
+        use clap::Parser;
+        
+        let storage = Arc::new(MockStorage::new(Duration::from_millis(100), 0.0));
+        let tester = S3ConsistencyTester::new(create_test_config()).await.unwrap()
+            .with_storage(storage.clone());
+        let args = Args::parse_from([
+            "s3-consistency-test", "--config", "config.toml", "--interval", "10", "--test-count", "5",
+            "--list-completeness", "--list-page-size", "2",
+        ]);
+        
+        let (results, list_result) = tester.run_burst(&args).await;
+        let list_result = list_result.unwrap();
+        
+        assert!(results.iter().all(|result| result.success));
+        assert!(list_result.success, "{:?}", list_result.error_details);
+        assert_eq!(list_result.page_count, 3);
+        assert!(!list_result.list_snapshot_consistent);
+        assert!(list_result.keys_seen_duration_ms <= list_result.list_completeness_duration_ms);
+        assert_eq!(storage.object_count(), 0);
+    }
+    
+    #[tokio::test]
+    async fn test_concurrent_results_are_in_start_order() {
+        use crate::mock::MockStorage;
+        use clap::Parser;
+        
+        let tester = S3ConsistencyTester::new(create_test_config()).await.unwrap()
+            .with_storage(Arc::new(MockStorage::new(Duration::from_millis(50), 0.0)));
+        let args = Args::parse_from([
+            "s3-consistency-test", "--config", "config.toml", "--interval", "10", "--file-size", "64",
+            "--test-count", "8", "--burst", "--max-in-flight", "4",
+        ]);
+        
+        let report = tester.run_consistency_test(&args).await.unwrap();
+        
+        let indices: Vec<usize> = report.results.iter().map(|result| result.test_index).collect();
+        assert_eq!(indices, (0..8).collect::<Vec<_>>());
+    }
+    
+    #[tokio::test]
+    async fn test_status_sequence_against_mock() {
+        use crate::mock::MockStorage;
+        use clap::Parser;
+        
+        let tester = S3ConsistencyTester::new(create_test_config()).await.unwrap()
+            .with_storage(Arc::new(MockStorage::new(Duration::from_millis(50), 0.0)));
+        let args = Args::parse_from([
+            "s3-consistency-test", "--config", "config.toml", "--interval", "10", "--test-count", "1",
+            "--expect-404-first",
+        ]);
+        
+        let uploaded = tester.upload_test_file(&args).await.unwrap();
+        let result = tester.poll_uploaded_file(uploaded, &args, 10).await;
+        
+        assert!(result.success);
+        let sequence = result.status_sequence.unwrap();
+        assert!(sequence.starts_with("404"), "{}", sequence);
+        assert!(sequence.ends_with("200"), "{}", sequence);
+        assert_eq!(result.status_anomaly, None);
+    }
+    
+    #[tokio::test]
+    async fn test_metadata_skew_against_mock() {
+        use crate::mock::MockStorage;
+        use clap::Parser;
+        
+        let tester = S3ConsistencyTester::new(create_test_config()).await.unwrap()
+            .with_storage(Arc::new(MockStorage::new(Duration::from_millis(50), 0.0)));
+        let args = Args::parse_from([
+            "s3-consistency-test", "--config", "config.toml", "--interval", "10", "--test-count", "1",
+            "--metadata-skew", "--self-verify",
+        ]);
+        
+        let uploaded = tester.upload_test_file(&args).await.unwrap();
+        let result = tester.poll_uploaded_file(uploaded, &args, 10).await;
+        
+        assert!(result.success);
+        assert!(result.metadata_consistent_duration_ms.is_some());
+        assert!(result.metadata_body_skew_ms.unwrap().abs() < 1000);
+    }
+    
+    #[tokio::test]
+    async fn test_overwrite_race_against_mock() {
+        use crate::mock::MockStorage;
+        use clap::Parser;
+        
+        let tester = S3ConsistencyTester::new(create_test_config()).await.unwrap()
+            .with_storage(Arc::new(MockStorage::new(Duration::from_millis(50), 0.0)));
+        let args = Args::parse_from([
+            "s3-consistency-test", "--config", "config.toml", "--interval", "10", "--file-size", "64",
+            "--overwrite-race", "--overwrite-writers", "3",
+        ]);
+        
+        let race = tester.run_overwrite_race(&args).await;
+        
+        assert_eq!(race.successful_writes, 3);
+        assert!(race.winner.is_some_and(|winner| (1..=3).contains(&winner)));
+        assert!(race.converged_value_stable_ms.is_some());
+        assert!(!race.oscillated);
+        assert_eq!(race.value_changes, 0);
+    }
+    
+    #[tokio::test]
+    async fn test_round_robin_reads_from_another_endpoint() {
+        use clap::Parser;
+        
+        let config = S3Config {
+            endpoints: Some(vec!["http://localhost:9001".to_string()]),
+            ..create_test_config()
+        };
+        let tester = S3ConsistencyTester::new(config).await.unwrap();
+        let args = Args::parse_from(["s3-consistency-test", "--config", "config.toml"]);
+        
+        assert_eq!(tester.select_endpoints(&args), (0, 1));
+        assert_eq!(tester.select_endpoints(&args), (0, 1));
+        assert_eq!(tester.endpoint_url(1).as_deref(), Some("http://localhost:9001"));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,99 @@
+//! Upload bandwidth limiting for `--max-upload-bps`
+//! 
+//! Uploading many large test files at full speed can saturate a shared or
+//! metered link. `UploadThrottle` paces every upload of the run against one
+//! shared byte budget: each chunk of data reserves the next free slot on a
+//! timeline advancing by `bytes / bytes_per_second`, and is only sent once
+//! its slot has started. Buffered uploads and multipart parts reserve their
+//! whole payload before being sent, while streamed uploads reserve their data
+//! a `THROTTLE_CHUNK_SIZE` chunk at a time, so throughput averages out to the
+//! limit without holding more data in memory.
+
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::time::{Instant, sleep_until};
+
+/// Amount of streamed data paced at a time
+pub const THROTTLE_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Shared upload rate limit
+#[derive(Debug)]
+pub struct UploadThrottle {
+    /// Maximum upload throughput in bytes per second
+    bytes_per_second: u64,
+    
+    /// When the bandwidth reserved so far has been used up
+    next_free: Mutex<Instant>,
+}
+
+impl UploadThrottle {
+    /// Creates a throttle for a rate limit
+    /// 
+    /// # Arguments
+    /// 
+    /// * `bytes_per_second` - Maximum upload throughput, at least 1
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let throttle = UploadThrottle::new(10 * 1024 * 1024);
+    /// ```
+    pub fn new(bytes_per_second: u64) -> Self {
+        Self {
+            bytes_per_second: bytes_per_second.max(1),
+            next_free: Mutex::new(Instant::now()),
+        }
+    }
+    
+    /// Reserves bandwidth for sending some bytes
+    /// 
+    /// # Arguments
+    /// 
+    /// * `bytes` - Number of bytes about to be sent
+    /// 
+    /// # Returns
+    /// 
+    /// When the bytes may be sent without exceeding the limit
+    pub fn reserve(&self, bytes: usize) -> Instant {
+        let duration = Duration::from_secs_f64(bytes as f64 / self.bytes_per_second as f64);
+        let mut next_free = self.next_free.lock().unwrap();
+        let start = (*next_free).max(Instant::now());
+        *next_free = start + duration;
+        start
+    }
+    
+    /// Waits until some bytes may be sent without exceeding the limit
+    /// 
+    /// # Arguments
+    /// 
+    /// * `bytes` - Number of bytes about to be sent
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// throttle.wait(data.len()).await;
+    /// bucket.put_object(key, data).await?;
+    /// ```
+    pub async fn wait(&self, bytes: usize) {
+        sleep_until(self.reserve(bytes)).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[tokio::test]
+    async fn test_reservations_are_paced() {
+        let throttle = UploadThrottle::new(1000);
+        let now = Instant::now();
+        
+        let first = throttle.reserve(500);
+        let second = throttle.reserve(500);
+        let third = throttle.reserve(100);
+        
+        assert!(first - now < Duration::from_millis(50));
+        assert!(second - first >= Duration::from_millis(499));
+        assert!(third - first >= Duration::from_millis(999));
+    }
+}
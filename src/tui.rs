@@ -0,0 +1,413 @@
+//! Live terminal dashboard for consistency testing
+//! 
+//! With `--tui`, a full-screen dashboard replaces the regular log output
+//! while the test runs. It shows how many files have completed, the current
+//! success rate, a histogram of the propagation times seen so far and a
+//! scrolling log of recent failures.
+//! 
+//! The terminal is put into raw mode, where Ctrl+C arrives as a key press
+//! instead of a signal. The dashboard therefore triggers the cleanup
+//! handler's interrupt itself: the first press stops the run gracefully
+//! while the dashboard stays up, and a second press restores the terminal
+//! before the handler cleans up and exits. The terminal is also restored if
+//! the program panics.
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use ratatui::DefaultTerminal;
+use ratatui::Frame;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Style};
+use ratatui::widgets::{BarChart, Block, Gauge, List, ListItem, Paragraph};
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+use tokio::sync::Notify;
+
+use crate::types::TestResult;
+
+/// How often the dashboard is redrawn
+const REFRESH_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Number of bars in the propagation time histogram
+const HISTOGRAM_BINS: usize = 10;
+
+/// Maximum number of failures kept for the failure log
+const MAX_RECENT_FAILURES: usize = 100;
+
+/// Whether the terminal is currently in dashboard mode
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Progress of a run as shown on the dashboard
+#[derive(Debug, Clone)]
+struct DashboardState {
+    /// When the run started
+    started: Instant,
+    
+    /// Number of files the run is expected to test
+    total: usize,
+    
+    /// Number of files whose test has finished
+    completed: usize,
+    
+    /// Number of files that became consistent
+    successful: usize,
+    
+    /// Propagation times of the successful files (milliseconds)
+    durations_ms: Vec<u64>,
+    
+    /// Most recent failures, oldest first
+    recent_failures: VecDeque<String>,
+}
+
+/// Live progress shared between the tester and the dashboard
+/// 
+/// Designed to be shared across async tasks and the drawing thread
+/// using `Arc`.
+#[derive(Debug)]
+pub struct Dashboard {
+    /// Progress recorded so far
+    state: Mutex<DashboardState>,
+}
+
+impl Dashboard {
+    /// Creates an empty dashboard
+    /// 
+    /// # Arguments
+    /// 
+    /// * `total` - Number of files the run is expected to test
+    /// 
+    /// # Returns
+    /// 
+    /// A new `Dashboard` with no results recorded
+    pub fn new(total: usize) -> Self {
+        Self {
+            state: Mutex::new(DashboardState {
+                started: Instant::now(),
+                total,
+                completed: 0,
+                successful: 0,
+                durations_ms: Vec::new(),
+                recent_failures: VecDeque::new(),
+            }),
+        }
+    }
+    
+    /// Records a finished test
+    /// 
+    /// # Arguments
+    /// 
+    /// * `result` - The result of the test
+    pub fn record(&self, result: &TestResult) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.completed += 1;
+        
+        match result.propagation_duration_ms.filter(|_| result.success) {
+            Some(duration) => {
+                state.successful += 1;
+                state.durations_ms.push(duration);
+            }
+            None => {
+                state.recent_failures.push_back(format!(
+                    "{} {}: {}",
+                    Utc::now().format("%H:%M:%S"),
+                    result.file_key,
+                    result.error_details.as_deref().unwrap_or("Unknown error")
+                ));
+                if state.recent_failures.len() > MAX_RECENT_FAILURES {
+                    state.recent_failures.pop_front();
+                }
+            }
+        }
+    }
+    
+    /// Gets a copy of the current progress for drawing
+    fn snapshot(&self) -> DashboardState {
+        self.state.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+}
+
+/// A running dashboard that owns the terminal
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// let dashboard = Arc::new(Dashboard::new(args.test_count));
+/// let handle = DashboardHandle::start(dashboard.clone(), interrupt)?;
+/// // ... run the test, recording results in `dashboard` ...
+/// handle.stop();
+/// ```
+#[derive(Debug)]
+pub struct DashboardHandle {
+    /// Set to ask the drawing thread to exit
+    stop: Arc<AtomicBool>,
+    
+    /// The drawing thread
+    thread: JoinHandle<()>,
+}
+
+impl DashboardHandle {
+    /// Takes over the terminal and starts drawing the dashboard
+    /// 
+    /// Pressing `q` or Ctrl+C notifies `interrupt`, which the cleanup handler
+    /// treats like an interrupt signal. A second press also restores the
+    /// terminal, since the handler then exits.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `dashboard` - The progress to display
+    /// * `interrupt` - Notified when the user asks to stop the run
+    /// 
+    /// # Returns
+    /// 
+    /// - `Ok(DashboardHandle)` once the terminal is in dashboard mode
+    /// - `Err(anyhow::Error)` if the terminal could not be initialized
+    pub fn start(dashboard: Arc<Dashboard>, interrupt: Arc<Notify>) -> Result<Self> {
+        // Also installs a panic hook that restores the terminal
+        let mut terminal = ratatui::try_init().context("Failed to initialize the terminal dashboard")?;
+        ACTIVE.store(true, Ordering::SeqCst);
+        
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = stop.clone();
+        let thread = thread::spawn(move || {
+            if draw_until_stopped(&mut terminal, &dashboard, &thread_stop, &interrupt).is_err() {
+                restore_terminal();
+            }
+        });
+        
+        Ok(Self { stop, thread })
+    }
+    
+    /// Stops drawing and restores the terminal
+    pub fn stop(self) {
+        self.stop.store(true, Ordering::Relaxed);
+        let _ = self.thread.join();
+        restore_terminal();
+    }
+}
+
+/// Determines whether the dashboard currently owns the terminal
+/// 
+/// Log output is suppressed while this is `true` so it does not corrupt
+/// the display.
+/// 
+/// # Returns
+/// 
+/// `true` between starting the dashboard and restoring the terminal
+pub fn is_active() -> bool {
+    ACTIVE.load(Ordering::SeqCst)
+}
+
+/// Restores the terminal to its normal mode if the dashboard owns it
+/// 
+/// Safe to call at any time and more than once; does nothing when the
+/// dashboard is not active.
+pub fn restore_terminal() {
+    if ACTIVE.swap(false, Ordering::SeqCst) {
+        let _ = ratatui::try_restore();
+    }
+}
+
+/// Redraws the dashboard until asked to stop or the user interrupts the run
+/// 
+/// # Arguments
+/// 
+/// * `terminal` - The terminal to draw on
+/// * `dashboard` - The progress to display
+/// * `stop` - Set when the run has finished
+/// * `interrupt` - Notified when the user presses `q` or Ctrl+C
+/// 
+/// # Returns
+/// 
+/// - `Ok(())` when stopped or interrupted
+/// - `Err(std::io::Error)` if drawing or reading input failed
+fn draw_until_stopped(
+    terminal: &mut DefaultTerminal,
+    dashboard: &Dashboard,
+    stop: &AtomicBool,
+    interrupt: &Notify,
+) -> std::io::Result<()> {
+    let mut stopping = false;
+    
+    while !stop.load(Ordering::Relaxed) {
+        let state = dashboard.snapshot();
+        terminal.draw(|frame| render(frame, &state, stopping))?;
+        
+        // Waiting for input doubles as the refresh delay
+        if !event::poll(REFRESH_INTERVAL)? {
+            continue;
+        }
+        if let Event::Key(key) = event::read()? {
+            let ctrl_c = key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL);
+            if key.kind == KeyEventKind::Press && (ctrl_c || key.code == KeyCode::Char('q')) {
+                // The second press makes the cleanup handler exit
+                if stopping {
+                    restore_terminal();
+                    interrupt.notify_one();
+                    return Ok(());
+                }
+                stopping = true;
+                interrupt.notify_one();
+            }
+        }
+    }
+    
+    Ok(())
+}
+
+/// Draws one frame of the dashboard
+/// 
+/// # Arguments
+/// 
+/// * `frame` - The frame to draw into
+/// * `state` - The progress to display
+/// * `stopping` - Whether the user asked to stop the run
+fn render(frame: &mut Frame, state: &DashboardState, stopping: bool) {
+    let areas = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Length(3),
+        Constraint::Length(3),
+        Constraint::Min(8),
+        Constraint::Length(10),
+    ]).split(frame.area());
+    
+    let hint = if stopping {
+        "finishing files in flight - press q or Ctrl+C again to abort"
+    } else {
+        "press q or Ctrl+C to stop and report"
+    };
+    frame.render_widget(
+        Paragraph::new(format!(
+            " S3 Consistency Test - {}s elapsed - {}",
+            state.started.elapsed().as_secs(), hint
+        )),
+        areas[0],
+    );
+    
+    let completed_ratio = if state.total > 0 {
+        (state.completed as f64 / state.total as f64).min(1.0)
+    } else {
+        0.0
+    };
+    frame.render_widget(
+        Gauge::default()
+            .block(Block::bordered().title("Files completed"))
+            .gauge_style(Style::default().fg(Color::Cyan))
+            .ratio(completed_ratio)
+            .label(format!("{}/{}", state.completed, state.total)),
+        areas[1],
+    );
+    
+    let success_ratio = if state.completed > 0 {
+        state.successful as f64 / state.completed as f64
+    } else {
+        0.0
+    };
+    frame.render_widget(
+        Gauge::default()
+            .block(Block::bordered().title("Success rate"))
+            .gauge_style(Style::default().fg(Color::Green))
+            .ratio(success_ratio)
+            .label(format!("{:.1}% ({} failed)", success_ratio * 100.0, state.completed - state.successful)),
+        areas[2],
+    );
+    
+    let histogram = calculate_histogram(&state.durations_ms, HISTOGRAM_BINS);
+    let bars: Vec<(&str, u64)> = histogram.iter()
+        .map(|(label, count)| (label.as_str(), *count))
+        .collect();
+    frame.render_widget(
+        BarChart::default()
+            .block(Block::bordered().title("Propagation time histogram"))
+            .data(bars.as_slice())
+            .bar_width(7)
+            .bar_gap(1)
+            .bar_style(Style::default().fg(Color::Yellow)),
+        areas[3],
+    );
+    
+    let failures: Vec<ListItem> = state.recent_failures.iter()
+        .rev()
+        .map(|failure| ListItem::new(failure.as_str()))
+        .collect();
+    frame.render_widget(
+        List::new(failures)
+            .block(Block::bordered().title("Recent failures (newest first)"))
+            .style(Style::default().fg(Color::Red)),
+        areas[4],
+    );
+}
+
+/// Groups propagation times into equal-width bins
+/// 
+/// # Arguments
+/// 
+/// * `durations` - Propagation times in milliseconds
+/// * `bins` - Number of bins
+/// 
+/// # Returns
+/// 
+/// One (lower bound label, count) pair per bin, or an empty vector if there
+/// are no durations
+fn calculate_histogram(durations: &[u64], bins: usize) -> Vec<(String, u64)> {
+    let (Some(&min), Some(&max)) = (durations.iter().min(), durations.iter().max()) else {
+        return Vec::new();
+    };
+    
+    let width = (max - min) / bins as u64 + 1;
+    let mut counts = vec![0u64; bins];
+    for &duration in durations {
+        counts[((duration - min) / width) as usize] += 1;
+    }
+    
+    counts.into_iter()
+        .enumerate()
+        .map(|(i, count)| (format_bin_label(min + width * i as u64), count))
+        .collect()
+}
+
+/// Formats a histogram bin bound compactly enough to fit under a bar
+fn format_bin_label(ms: u64) -> String {
+    if ms >= 1000 {
+        format!("{:.1}s", ms as f64 / 1000.0)
+    } else {
+        format!("{}ms", ms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_calculate_histogram() {
+        assert!(calculate_histogram(&[], 10).is_empty());
+        
+        let histogram = calculate_histogram(&[100, 150, 190, 1000], 2);
+        assert_eq!(histogram, vec![
+            ("100ms".to_string(), 3),
+            ("551ms".to_string(), 1),
+        ]);
+        
+        let single = calculate_histogram(&[250, 250], 3);
+        assert_eq!(single[0], ("250ms".to_string(), 2));
+        assert_eq!(single.iter().map(|(_, count)| count).sum::<u64>(), 2);
+    }
+    
+    #[test]
+    fn test_dashboard_record() {
+        let now = Utc::now();
+        let dashboard = Dashboard::new(3);
+        dashboard.record(&TestResult::success("a".to_string(), now, now + chrono::Duration::milliseconds(120), 2));
+        dashboard.record(&TestResult::failure("b".to_string(), now, "Upload failed".to_string()));
+        
+        let state = dashboard.snapshot();
+        assert_eq!(state.completed, 2);
+        assert_eq!(state.successful, 1);
+        assert_eq!(state.durations_ms, vec![120]);
+        assert!(state.recent_failures[0].ends_with("b: Upload failed"));
+    }
+}
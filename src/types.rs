@@ -4,11 +4,16 @@
 //! testing application, including test results, reports, statistics, and CLI arguments.
 
 use chrono::{DateTime, Utc};
-use clap::Parser;
+use clap::{Parser, Subcommand, ValueEnum};
 use serde::Serialize;
+use std::collections::HashMap;
 use std::path::PathBuf;
 
+use crate::checksum::ChecksumAlgorithm;
 use crate::config::S3Config;
+use crate::keys::{KeyPattern, KeyStyle};
+use crate::sizes::SizeDistribution;
+use crate::version;
 
 /// Command-line arguments for the S3 consistency tester
 /// 
@@ -32,6 +37,7 @@ use crate::config::S3Config;
 /// ```
 #[derive(Parser, Debug)]
 #[command(name = "s3-consistency-test")]
+#[command(version)]
 #[command(about = "Test S3 eventual consistency propagation times")]
 #[command(long_about = r#"
 S3 Consistency Tester
@@ -51,13 +57,58 @@ The tool supports any S3-compatible storage service including:
 
 Results are displayed in real-time and saved to a detailed JSON report.
 "#)]
+#[command(subcommand_negates_reqs = true)]
 pub struct Args {
-    /// Path to the TOML configuration file
+    /// Subcommand to run instead of a consistency test
+    #[command(subcommand)]
+    pub command: Option<Command>,
+    
+    /// Paths to the TOML configuration files
+    /// 
+    /// The configuration must contain S3 connection details including
+    /// endpoint, credentials, and bucket information. Use `-` to read the
+    /// configuration from stdin. May be given multiple times, e.g. a shared
+    /// base file followed by per-environment overrides; the files are merged
+    /// in order with later files taking precedence.
+    #[arg(short, long, required_unless_present_any = ["config_inline", "version_verbose"], help = "Path to configuration file (- for stdin); repeat to merge overrides")]
+    pub config: Vec<PathBuf>,
+    
+    /// TOML configuration passed directly on the command line
+    /// 
+    /// An alternative to `--config` for environments where the configuration
+    /// is injected as a string, such as CI secrets or Kubernetes configmaps.
+    #[arg(long, value_name = "TOML", conflicts_with = "config", help = "Inline TOML configuration instead of a file")]
+    pub config_inline: Option<String>,
+    
+    /// Path to a workload profile with test settings
+    /// 
+    /// A TOML file describing the test itself (file count and sizes, timing,
+    /// thresholds) separately from the S3 configuration, so standard test
+    /// definitions can be kept in version control. Flags given on the
+    /// command line override the profile.
+    #[arg(long, value_name = "PATH", help = "Workload profile with test settings; command-line flags take precedence")]
+    pub workload: Option<PathBuf>,
+    
+    /// Bucket names that may be tested without confirmation
+    /// 
+    /// When given, any other bucket requires confirmation before the run
+    /// starts. May be given multiple times.
+    #[arg(long, value_name = "BUCKET", help = "Bucket that may be tested without confirmation (repeatable)")]
+    pub allow_bucket: Vec<String>,
+    
+    /// Bucket name patterns that require confirmation before testing
+    /// 
+    /// `*` matches any characters, e.g. `prod-*`. May be given multiple times.
+    /// Buckets listed with `--allow-bucket` are exempt.
+    #[arg(long, value_name = "PATTERN", help = "Require confirmation for buckets matching this pattern (repeatable)")]
+    pub deny_pattern: Vec<String>,
+    
+    /// Skip the confirmation prompt for protected buckets
     /// 
-    /// The configuration file must contain S3 connection details including
-    /// endpoint, credentials, and bucket information.
-    #[arg(short, long, help = "Path to configuration file")]
-    pub config: PathBuf,
+    /// Needed to test a protected bucket when no interactive terminal is
+    /// available, e.g. in CI.
+    #[arg(long, help = "Test protected buckets without asking for confirmation")]
+    pub yes: bool,
     
     /// Number of test files to upload and test
     /// 
@@ -73,10 +124,48 @@ pub struct Args {
     #[arg(short, long, default_value = "1024", help = "Size of test files in bytes")]
     pub file_size: usize,
     
+    /// Weighted distribution to sample each test file's size from
+    /// 
+    /// Overrides `--file-size` to model a real workload, e.g.
+    /// `80%:10KB,15%:1MB,5%:100MB`. Weights are relative; sizes accept `B`,
+    /// `KB`, `MB` and `GB` suffixes (powers of 1024). Propagation is also
+    /// reported per size.
+    #[arg(long, value_name = "WEIGHT:SIZE,...", value_parser = SizeDistribution::parse, help = "Weighted file sizes, e.g. 80%:10KB,15%:1MB,5%:100MB")]
+    pub size_distribution: Option<SizeDistribution>,
+    
+    /// Largest file size in bytes to generate in memory
+    /// 
+    /// Larger files are streamed to S3 from chunks of generated data instead
+    /// of being buffered whole, and are polled with ranged reads of their first
+    /// byte, so neither uploads nor reads hold the whole object in memory.
+    #[arg(long, value_name = "BYTES", default_value = "67108864", help = "Stream files larger than this many bytes instead of buffering them")]
+    pub stream_threshold: usize,
+    
+    /// Upload large files with an explicit multipart upload
+    /// 
+    /// Files of at least `--multipart-threshold` bytes are uploaded part by
+    /// part and committed with CompleteMultipartUpload, and propagation is
+    /// measured from the moment the completion returns. Multipart objects
+    /// take a different commit path than a single PUT.
+    #[arg(long, help = "Upload files of at least --multipart-threshold bytes with multipart uploads")]
+    pub multipart: bool,
+    
+    /// Smallest file size in bytes uploaded with `--multipart`
+    #[arg(long, value_name = "BYTES", default_value = "8388608", requires = "multipart", help = "Smallest file size uploaded with --multipart")]
+    pub multipart_threshold: usize,
+    
+    /// Size of each part of a multipart upload in bytes
+    /// 
+    /// Every part but the last must be at least 5 MiB.
+    #[arg(long, value_name = "BYTES", default_value = "5242880", value_parser = parse_part_size, requires = "multipart", help = "Part size in bytes for --multipart uploads")]
+    pub multipart_part_size: usize,
+    
     /// Maximum time to wait for consistency in seconds
     /// 
     /// If a file hasn't become consistent within this time,
-    /// the test for that file is marked as failed.
+    /// the test for that file is marked as failed. `0` makes exactly one
+    /// read attempt per file, which fails the file unless the very first
+    /// read already returns it.
     #[arg(short, long, default_value = "300", help = "Maximum time to wait for consistency (seconds)")]
     pub max_wait: u64,
     
@@ -87,215 +176,2386 @@ pub struct Args {
     #[arg(short, long, default_value = "100", help = "Check interval in milliseconds")]
     pub interval: u64,
     
+    /// Make every read attempt over a new connection
+    /// 
+    /// Some gateways serve cached results on a kept-alive connection, hiding
+    /// how propagation looks across connections. With this flag each poll
+    /// builds a new client, so every read pays for a fresh TCP (and TLS)
+    /// handshake. This adds latency to each attempt and load on the endpoint.
+    #[arg(long, alias = "no-keepalive", help = "Use a new connection for every read attempt")]
+    pub fresh_connection: bool,
+    
+    /// How uploads and reads are spread across the configured endpoints
+    /// 
+    /// Only applies when the config lists several equivalent `endpoints`.
+    /// `round-robin` rotates through them so that each file is usually read
+    /// from a different node than it was written to; `lowest-latency` probes
+    /// every endpoint once and sends all requests to the fastest.
+    #[arg(long, value_enum, default_value = "round-robin", help = "How to pick among several configured endpoints")]
+    pub endpoint_selection: EndpointSelection,
+    
+    /// Wait for the endpoints to become reachable before testing
+    /// 
+    /// Each endpoint is checked with a one-key listing that must answer
+    /// within this many seconds. Failed checks are retried with exponential
+    /// backoff up to `--connect-retries` times, so a server that is still
+    /// starting up (e.g. MinIO in CI) does not fail the first files of the
+    /// run. Without this flag, connection problems surface as failed uploads.
+    #[arg(long, value_name = "SECONDS", value_parser = clap::value_parser!(u64).range(1..), help = "Check that endpoints answer within this many seconds before testing")]
+    pub connect_timeout: Option<u64>,
+    
+    /// Number of times a failed `--connect-timeout` check is retried
+    #[arg(long, value_name = "N", default_value = "5", requires = "connect_timeout", help = "Retries for a failed --connect-timeout check")]
+    pub connect_retries: u32,
+    
+    /// Check whether the writer sees its own write immediately
+    /// 
+    /// Right after each upload, the client that uploaded the file reads it
+    /// once, alongside the regular reader polling. This isolates
+    /// same-connection (read-your-writes) consistency from the
+    /// cross-connection propagation measured by the reader.
+    #[arg(long, help = "Check whether the writer can read its own write immediately")]
+    pub writer_read_your_writes: bool,
+    
+    /// Verify read content against a hash stored in the object's metadata
+    /// 
+    /// Each upload stores a short SHA-256 of its payload as object metadata,
+    /// and the successful read is checked against it, so the reader does not
+    /// need the original bytes. Only applies to files uploaded with a single
+    /// buffered PUT and read back whole, i.e. not streamed or multipart files.
+    #[arg(long, help = "Verify read content against a payload hash stored in object metadata")]
+    pub self_verify: bool,
+    
+    /// Upload with a backend-verified checksum and check it on read
+    /// 
+    /// Each upload carries an `x-amz-checksum-*` header that the backend
+    /// verifies and stores with the object. Reads ask for the stored checksum
+    /// with `x-amz-checksum-mode: ENABLED`, and the successful read is checked
+    /// against it, which also shows whether checksum metadata propagates with
+    /// the object. Like `--self-verify`, only applies to files uploaded with a
+    /// single buffered PUT.
+    #[arg(long, value_enum, value_name = "ALGORITHM", help = "Upload with a crc32c or sha256 checksum and verify the one returned on read")]
+    pub checksum: Option<ChecksumAlgorithm>,
+    
+    /// Check that reads follow the 404-then-object propagation pattern
+    /// 
+    /// Records each file's read statuses as a compact sequence such as
+    /// `404x3,200` and flags files that deviate from 404s followed by the
+    /// object: a success status with placeholder content, a 4xx other than
+    /// 404, or a 404 after the object was readable. Timeouts and 5xx
+    /// responses count as transient.
+    #[arg(long, help = "Record read statuses and flag files that deviate from 404s followed by the object")]
+    pub expect_404_first: bool,
+    
+    /// Anomalous read statuses tolerated per file by `--expect-404-first`
+    #[arg(long, value_name = "N", default_value = "0", requires = "expect_404_first", help = "Anomalous read statuses tolerated per file by --expect-404-first")]
+    pub status_anomaly_grace: u32,
+    
+    /// Response headers to record from each file's successful read
+    /// 
+    /// Useful for diagnosing caching, e.g. `x-cache` or `age` showing a CDN
+    /// or proxy hit behind an unexpectedly instant read, or `x-amz-request-id`
+    /// for support tickets. Names are case-insensitive. May be given multiple
+    /// times.
+    #[arg(long, value_name = "NAME", help = "Response header to record from the successful read (repeatable)")]
+    pub capture_header: Vec<String>,
+    
+    /// Show a live terminal dashboard while the test runs
+    /// 
+    /// Replaces the log output with gauges for completed files and the
+    /// success rate, a propagation time histogram and a log of recent
+    /// failures. The regular summary is printed once the run finishes.
+    /// Press `q` or Ctrl+C to stop the run and report the files completed so
+    /// far; press it again to abort. Test files are still cleaned up.
+    #[arg(long, conflicts_with = "find_breakpoint", help = "Show a live terminal dashboard")]
+    pub tui: bool,
+    
+    /// Automatically tune the check interval from observed propagation times
+    /// 
+    /// Starts with a short interval and, after each file, adjusts it so that
+    /// a file with the recently observed propagation time would need roughly
+    /// 20 read attempts. `--interval` is ignored when this is enabled.
+    #[arg(long, help = "Automatically tune the check interval")]
+    pub auto_interval: bool,
+    
+    /// Keep testing files until the propagation time estimate is precise enough
+    /// 
+    /// Tests `--test-count` files first, then keeps adding files one at a time
+    /// until the 95% confidence interval on the average propagation time is
+    /// narrower than `--target-ci-width`, or `--max-count` files have been tested.
+    /// Stable backends finish early while noisy ones get more samples.
+    #[arg(long, conflicts_with_all = ["burst", "list_completeness", "find_breakpoint"], help = "Test more files until the average is known precisely enough")]
+    pub adaptive_count: bool,
+    
+    /// Maximum number of files tested by `--adaptive-count`
+    #[arg(long, value_name = "N", default_value = "100", help = "Maximum number of files for --adaptive-count")]
+    pub max_count: usize,
+    
+    /// Target width of the 95% confidence interval on the average (milliseconds)
+    #[arg(long, value_name = "MS", default_value = "100", help = "Target confidence interval width in ms for --adaptive-count")]
+    pub target_ci_width: f64,
+    
+    /// Measure how long a bucket listing takes to show the whole batch
+    /// 
+    /// Uploads all files as a batch (as with `--burst`) and polls a listing of
+    /// the test prefix, following pagination, until every uploaded key is
+    /// returned. This measures list-index convergence, which is distinct from
+    /// per-object read consistency.
+    #[arg(long, help = "Measure how long listing takes to return the whole uploaded batch")]
+    pub list_completeness: bool,
+    
+    /// Maximum keys per listing page for `--list-completeness`
+    /// 
+    /// Backends return up to 1000 keys per page, so a smaller page size
+    /// makes a modest batch span several pages and exercises pagination.
+    #[arg(long, value_name = "N", requires = "list_completeness", value_parser = clap::value_parser!(u64).range(1..=1000), help = "Keys per listing page for --list-completeness (1-1000)")]
+    pub list_page_size: Option<u64>,
+    
+    /// Measure how long a deleted file keeps appearing in bucket listings
+    /// 
+    /// After each test file is deleted, a listing of its key is polled until
+    /// the key is absent from several consecutive listings. If the key
+    /// reappears in between, the measurement restarts from that point.
+    #[arg(long, help = "Measure how long deleted files keep appearing in listings")]
+    pub test_delete_list_consistency: bool,
+    
+    /// Measure how long a key takes to become readable again after a delete
+    /// 
+    /// After each test file became consistent, it is deleted, reads confirm
+    /// it is gone, and new content is uploaded to the same key and polled
+    /// until it is readable. 404s for the re-created file later than its
+    /// first propagation time are counted as stale-tombstone reads.
+    #[arg(long, help = "Delete each consistent file, re-create it under the same key and measure its propagation")]
+    pub recreate_after_delete: bool,
+    
+    /// Measure how long uploads take to replicate to another bucket
+    /// 
+    /// Requires `replication_target` in the configuration. After each upload
+    /// to the source bucket, the same key is polled in the destination bucket
+    /// until it can be read, alongside the regular consistency reads.
+    #[arg(long, help = "Measure how long files take to appear in the configured replication_target bucket")]
+    pub test_replication: bool,
+    
+    /// Measure how far object metadata lags or leads the object body
+    /// 
+    /// Alongside the regular GET polling, each file is polled with HEAD until
+    /// its metadata is correct: the right content length and content type,
+    /// plus the `--self-verify` metadata header if one was stored. The gap
+    /// between the body becoming readable and the metadata becoming correct
+    /// is recorded as `metadata_body_skew_ms`.
+    #[arg(long, help = "Poll HEAD alongside GET and measure the skew between object body and metadata")]
+    pub metadata_skew: bool,
+    
+    /// Race concurrent overwrites of one key after the regular tests
+    /// 
+    /// Uploads `--overwrite-writers` distinguishable payloads to the same new
+    /// key at once, then reads the key every `--interval` until several reads
+    /// in a row return the same payload. Records which writer's payload won,
+    /// how long after the last overwrite completed the reads settled, and
+    /// whether they oscillated between payloads on the way, which tests
+    /// last-writer-wins convergence.
+    #[arg(long, help = "Race concurrent overwrites of one key and measure how reads converge on a value")]
+    pub overwrite_race: bool,
+    
+    /// Number of writers racing to overwrite the key with `--overwrite-race`
+    #[arg(long, value_name = "N", default_value = "2", requires = "overwrite_race", value_parser = clap::value_parser!(u64).range(2..=100), help = "Concurrent writers for --overwrite-race (2-100)")]
+    pub overwrite_writers: u64,
+    
+    /// Upload every test file before polling any of them
+    /// 
+    /// Instead of interleaving upload and read for each file, all files are
+    /// uploaded first as a single write burst and then polled concurrently.
+    /// This is closer to how batch jobs write data.
+    #[arg(long, help = "Upload all files before polling any of them")]
+    pub burst: bool,
+    
+    /// Cap on files uploaded but not yet done polling in `--burst` mode
+    /// 
+    /// Without a cap, a burst of thousands of files is polled all at once,
+    /// which can exhaust memory and overwhelm the backend. With a cap, files
+    /// are still uploaded one after another, but polling starts as soon as
+    /// each upload completes, and a new upload waits while this many files
+    /// are still being polled. The highest number observed is reported.
+    #[arg(long, value_name = "N", conflicts_with = "list_completeness", help = "In --burst mode, pause uploads while this many files await consistency")]
+    pub max_in_flight: Option<usize>,
+    
+    /// Maximum random delay before each upload in milliseconds
+    /// 
+    /// Each upload is delayed by a random amount between zero and this value,
+    /// staggering upload start times into a more realistic arrival pattern
+    /// instead of an instantaneous spike.
+    #[arg(long, value_name = "MS", default_value = "0", help = "Random delay of up to this many ms before each upload")]
+    pub upload_jitter: u64,
+    
+    /// Maximum combined upload throughput in bytes per second
+    /// 
+    /// Paces every upload of the run against one shared budget, so large
+    /// files or bursts do not saturate a shared or metered link. Streamed
+    /// uploads are paced 64 KiB at a time; buffered uploads and multipart
+    /// parts wait for their whole payload's share before being sent.
+    #[arg(long, value_name = "BYTES_PER_SEC", value_parser = clap::value_parser!(u64).range(1..), help = "Limit the combined upload throughput to this many bytes per second")]
+    pub max_upload_bps: Option<u64>,
+    
+    /// Fraction of files that also get a simulated organic read (0.0 to 1.0)
+    /// 
+    /// Real readers do not poll; they first touch an object some random time
+    /// after it was written. For this fraction of files, one extra read is
+    /// made after a delay drawn from the exponential distribution of the
+    /// time to the first arrival of a Poisson process with mean
+    /// `--organic-read-mean-ms`, capped at `--max-wait`. Whether that read
+    /// saw the uploaded object is reported as user-visible inconsistency.
+    /// The regular polling is unaffected.
+    #[arg(long, value_name = "FRACTION", default_value = "0", value_parser = parse_fraction, help = "Fraction of files that also get one read after a Poisson-distributed delay")]
+    pub organic_read_fraction: f64,
+    
+    /// Mean delay before a simulated organic read in milliseconds
+    #[arg(long, value_name = "MS", default_value = "1000", requires = "organic_read_fraction", help = "Mean delay before a simulated organic read in ms")]
+    pub organic_read_mean_ms: u64,
+    
+    /// Template for test file keys
+    /// 
+    /// Reproduces an application's key layout instead of random UUID keys,
+    /// e.g. `users/{seq}/avatar.jpg` or `{date}/{uuid}`. Supports `{seq}`,
+    /// `{uuid}` and `{date}`, and must contain `{seq}` or `{uuid}`. Keys are
+    /// still created under the `consistency-test-` prefix.
+    #[arg(long, value_name = "PATTERN", value_parser = KeyPattern::parse, help = "Key template, e.g. users/{seq}/avatar.jpg or {date}/{uuid}")]
+    pub key_pattern: Option<KeyPattern>,
+    
+    /// Form of test file keys when no `--key-pattern` is given
+    /// 
+    /// `uuid` gives `consistency-test-<uuid>`. `short` uses an 8-digit hex
+    /// suffix that is never repeated within the run, and `counter` numbers
+    /// the files as `consistency-test-<run id>-1`, `-2` and so on. The
+    /// shorter keys keep listings, logs and `--table` output readable.
+    #[arg(long, value_enum, default_value = "uuid", conflicts_with = "key_pattern", help = "Form of generated keys: uuid, short or counter")]
+    pub key_style: KeyStyle,
+    
+    /// Check that each generated key is unused before uploading to it
+    /// 
+    /// Sends a HEAD request per key and generates a new key if an object
+    /// already exists, so deterministic `--key-pattern` keys never overwrite
+    /// an existing object. A file fails if several regenerated keys also
+    /// exist or the check itself fails.
+    #[arg(long, help = "HEAD each key before upload and regenerate it if the object already exists")]
+    pub check_collision: bool,
+    
+    /// Canned ACL to apply to uploaded test files
+    /// 
+    /// Overrides the `acl` setting in the configuration file,
+    /// e.g. `private` or `public-read`.
+    #[arg(long, value_name = "ACL", help = "Canned ACL for uploaded files (overrides config)")]
+    pub acl: Option<String>,
+    
+    /// Bucket to test instead of the one in the configuration
+    /// 
+    /// Overrides the `bucket` setting, so a working configuration can be
+    /// pointed at another bucket without editing it. Cannot be combined
+    /// with a configuration that spreads files across several `buckets`.
+    #[arg(long, value_name = "BUCKET", help = "Bucket to test (overrides config)")]
+    pub bucket: Option<String>,
+    
+    /// Key prefix to place test files under
+    /// 
+    /// Overrides the `prefix` setting in the configuration file, e.g.
+    /// `scratch/`.
+    #[arg(long, value_name = "PREFIX", help = "Key prefix for test files (overrides config)")]
+    pub prefix: Option<String>,
+    
+    /// Only include failed tests in the saved report and console summary
+    /// 
+    /// Statistics are still computed over every test, and the SQLite
+    /// history, InfluxDB export and webhook still receive every result.
+    /// Useful for keeping reports small and focused when investigating a
+    /// failing backend.
+    #[arg(long, help = "Only include failed tests in the report and summary")]
+    pub failures_only: bool,
+    
+    /// Append every result to this file as one JSON line as soon as it finishes
+    /// 
+    /// Keeps the full per-file data of runs too large for the report to hold
+    /// every result (see `--max-results-in-memory`), and survives a crash
+    /// partway through a long run. With `--failures-only`, only failed
+    /// results are written.
+    #[arg(long, value_name = "PATH", conflicts_with = "find_breakpoint", help = "Write every result to PATH as JSON lines as soon as it finishes")]
+    pub results_jsonl: Option<PathBuf>,
+    
+    /// Most results kept in memory and in the report
+    /// 
+    /// Beyond this many files, statistics are accumulated incrementally and
+    /// the report keeps a uniform random sample of this many results. Counts,
+    /// rates, minimum, maximum and average stay exact; the median,
+    /// percentiles and outlier threshold are estimated from the sample.
+    #[arg(long, value_name = "N", default_value = "100000", value_parser = clap::value_parser!(u64).range(1..), help = "Above this many files, keep a random sample of N results and accumulate statistics incrementally")]
+    pub max_results_in_memory: u64,
+    
+    /// Record a per-file timeline of phase timestamps in the report
+    /// 
+    /// Each result gets the absolute times of its upload start and end,
+    /// every read attempt, the successful read and cleanup, so waterfall
+    /// charts can be drawn externally and overlap between concurrent files
+    /// inspected. Adds a timestamp per read attempt, which makes reports of
+    /// large runs considerably bigger.
+    #[arg(long, help = "Record per-file phase timestamps (upload, each poll, success, cleanup) in the report")]
+    pub detailed_timeline: bool,
+    
+    /// Fail the run if any test files remain in the bucket after cleanup
+    /// 
+    /// After the final cleanup check, the test key prefix is listed and the
+    /// run exits with an error naming every leftover `consistency-test-*`
+    /// object. Without this, cleanup failures are only logged as warnings.
+    #[arg(long, help = "Fail if any test files remain in the bucket after cleanup")]
+    pub verify_cleanup: bool,
+    
+    /// Wait this long after a file became readable before deleting it
+    /// 
+    /// Deleting an object right after it first became readable can race with
+    /// the backend's internal replication, and some backends then resurrect
+    /// the deleted object. The delay applies to every successful file, so
+    /// sequential runs take up to `test_count` × this value longer.
+    #[arg(long, value_name = "MS", default_value = "0", help = "Delay in ms between a successful read and deleting the file")]
+    pub cleanup_delay: u64,
+    
+    /// Delete all test files in one batched pass after every measurement
+    /// 
+    /// Keeps delete requests from adding load while other files are still
+    /// being polled. Every uploaded file stays in the bucket until the end of
+    /// the run, so the peak object count grows to `test_count` files.
+    #[arg(long, conflicts_with = "test_delete_list_consistency", help = "Defer cleanup to one batched pass after all measurements")]
+    pub defer_cleanup: bool,
+    
+    /// Fail the run if the backend denied deleting any test file
+    /// 
+    /// A prominent warning listing the affected keys is always printed when
+    /// deletes are persistently denied; this flag also makes the run exit
+    /// with an error.
+    #[arg(long, help = "Fail if deleting test files was denied by the backend")]
+    pub fail_on_cleanup_denied: bool,
+    
+    /// Path to an SQLite database to append results to
+    /// 
+    /// The database and its schema are created on first use. Each run adds
+    /// its metadata and per-file results so trends can be queried with SQL.
+    #[arg(long, value_name = "PATH", help = "Append results to an SQLite database")]
+    pub sqlite: Option<PathBuf>,
+    
+    /// Print propagation times by hour of day across all runs in the database
+    /// 
+    /// Uses every successful result stored in the `--sqlite` database, so
+    /// scheduled runs build up a picture of how consistency varies with
+    /// backend load over the day. Hours are in UTC.
+    #[arg(long, requires = "sqlite", conflicts_with = "find_breakpoint", help = "Print avg/p95 propagation by hour of day from the SQLite history")]
+    pub time_of_day_report: bool,
+    
+    /// Export the run's statistics in InfluxDB line protocol
+    /// 
+    /// An `http://` or `https://` target is treated as an InfluxDB write URL
+    /// (e.g. `http://host:8086/api/v2/write?org=ops&bucket=s3` or
+    /// `http://host:8086/write?db=s3`) and the point is posted to it. Any
+    /// other target is a file path the point is appended to.
+    #[arg(long, value_name = "URL|PATH", help = "Send run statistics as InfluxDB line protocol to a write URL or file")]
+    pub influx: Option<String>,
+    
+    /// API token for `--influx` write URLs
+    #[arg(long, value_name = "TOKEN", requires = "influx", help = "InfluxDB API token")]
+    pub influx_token: Option<String>,
+    
+    /// Post a JSON summary of the run to this URL when it finishes
+    /// 
+    /// The summary holds the success rate, percentiles and SLA status, plus
+    /// a human-readable `text` message that Slack-compatible incoming
+    /// webhooks display directly.
+    #[arg(long, value_name = "URL", value_parser = parse_webhook_url, help = "POST a JSON run summary to this URL when the run finishes")]
+    pub webhook: Option<String>,
+    
+    /// Number of times a failed `--webhook` delivery is retried
+    #[arg(long, value_name = "N", default_value = "3", requires = "webhook", help = "Retries for a failed --webhook delivery")]
+    pub webhook_retries: u32,
+    
+    /// Arbitrary metadata to attach to the run, as `key=value`
+    /// 
+    /// May be repeated, e.g. `--label env=staging --label sha=3f2c1a9`.
+    /// Labels are stored in the JSON report and the SQLite database so
+    /// reports describe themselves when analyzed later.
+    #[arg(long = "label", value_name = "KEY=VALUE", value_parser = parse_label, help = "Attach a key=value label to the run (repeatable)")]
+    pub labels: Vec<(String, String)>,
+    
+    /// Path to write a small machine-readable status file to
+    /// 
+    /// The file contains only the success rate, number of failed tests, p95
+    /// propagation time and the process exit code, giving automation a stable
+    /// contract that does not change with the full report schema.
+    #[arg(long, value_name = "PATH", conflicts_with = "find_breakpoint", help = "Write a minimal JSON status file")]
+    pub status_file: Option<PathBuf>,
+    
+    /// Price per 1,000 S3 requests, used to estimate the cost of the run
+    /// 
+    /// The estimate covers every upload, read, listing and delete request
+    /// and is reported in whatever currency the price is given in.
+    #[arg(long, value_name = "PRICE", help = "Price per 1,000 requests, to estimate the run's cost")]
+    pub cost_per_1k_requests: Option<f64>,
+    
+    /// Propagation time target in milliseconds for SLA reporting
+    /// 
+    /// Each polled file is classified as meeting the SLA if it became
+    /// consistent within this many milliseconds, or as a breach otherwise,
+    /// including files that never became consistent. Files whose upload
+    /// failed are not classified.
+    #[arg(long, value_name = "MS", help = "Propagation time target in ms; report SLA compliance against it")]
+    pub sla_ms: Option<u64>,
+    
+    /// Shortest propagation time in milliseconds admitted to the statistics
+    /// 
+    /// Successful results measured below this are left out of the summary
+    /// statistics but kept in the saved results.
+    #[arg(long, value_name = "MS", help = "Leave propagation times below this many ms out of the statistics")]
+    pub stats_min_ms: Option<u64>,
+    
+    /// Longest propagation time in milliseconds admitted to the statistics
+    /// 
+    /// Successful results measured above this, e.g. a single 280000ms
+    /// measurement caused by a network blip, are left out of the summary
+    /// statistics but kept in the saved results.
+    #[arg(long, value_name = "MS", help = "Leave propagation times above this many ms out of the statistics")]
+    pub stats_max_ms: Option<u64>,
+    
+    /// Time the network setup of a connection to the endpoint
+    /// 
+    /// Once per run, makes a direct connection to the endpoint and reports DNS
+    /// resolution, TCP connect and TLS handshake durations separately, to
+    /// distinguish setup overhead from S3 propagation.
+    #[arg(long, help = "Time DNS, TCP and TLS setup for the endpoint once per run")]
+    pub diagnose_timing: bool,
+    
+    /// Probe backend availability in the background during the test
+    /// 
+    /// Periodically lists a single key from the bucket and records success and
+    /// latency into an availability timeline included in the report.
+    #[arg(long, help = "Record backend availability during the test")]
+    pub availability_probe: bool,
+    
+    /// Time between availability probes in milliseconds
+    #[arg(long, value_name = "MS", default_value = "1000", help = "Availability probe interval in milliseconds")]
+    pub probe_interval: u64,
+    
+    /// Abort a file's test after this many consecutive non-retryable errors
+    /// 
+    /// Errors such as 403 (access denied) or credential failures will not
+    /// resolve by waiting, so polling stops early instead of burning the full
+    /// `--max-wait`. Retryable errors (404, 5xx, timeouts) reset the count.
+    /// Set to 0 to always wait the full `--max-wait`.
+    #[arg(long, value_name = "N", default_value = "3", help = "Abort after N consecutive non-retryable read errors (0 disables)")]
+    pub abort_after: u32,
+    
+    /// Consecutive matching reads required before a file counts as consistent
+    /// 
+    /// A single successful read can be a fluke on a backend that flaps
+    /// between old and new state during propagation. With N > 1, polling
+    /// continues until N reads in a row return the full object with the same
+    /// content, and the propagation time is measured to the first read of
+    /// that final streak. A streak broken by a failed read is recorded as
+    /// flapping.
+    #[arg(long, value_name = "N", default_value = "1", value_parser = parse_consecutive_reads, help = "Require N consecutive matching reads before declaring consistency")]
+    pub consecutive_reads: u32,
+    
+    /// Retry a file's upload up to this many times on transient failures
+    /// 
+    /// A failed upload (e.g. HTTP 503) says nothing about consistency, so
+    /// instead of counting it as a failed test the upload is retried and
+    /// only the final outcome is recorded. Consistency timeouts are the
+    /// measurement itself and are never retried.
+    #[arg(long, value_name = "N", default_value = "0", help = "Retry failed uploads up to N times per file")]
+    pub file_retries: u32,
+    
+    /// Find the write concurrency at which consistency starts degrading
+    /// 
+    /// Runs successive batches of `--test-count` files at concurrency 1, 2, 4, 8, …
+    /// up to `--max-concurrency`, stopping once p95 propagation exceeds
+    /// `--breakpoint-p95-ms` or the success rate drops below
+    /// `--breakpoint-min-success-rate`. Produces a concurrency-vs-p95 table
+    /// instead of the regular report.
+    #[arg(long, help = "Ramp concurrency to find the consistency breaking point")]
+    pub find_breakpoint: bool,
+    
+    /// Highest concurrency level tried by `--find-breakpoint`
+    #[arg(long, value_name = "N", default_value = "64", help = "Maximum concurrency for --find-breakpoint")]
+    pub max_concurrency: usize,
+    
+    /// p95 propagation time (milliseconds) considered a breakdown by `--find-breakpoint`
+    #[arg(long, value_name = "MS", default_value = "1000", help = "p95 threshold in ms for --find-breakpoint")]
+    pub breakpoint_p95_ms: u64,
+    
+    /// Success rate (percent) below which `--find-breakpoint` considers consistency broken
+    #[arg(long, value_name = "PERCENT", default_value = "95.0", help = "Minimum success rate for --find-breakpoint")]
+    pub breakpoint_min_success_rate: f64,
+    
+    /// Number of tokio worker threads the tool runs on
+    /// 
+    /// Defaults to one per CPU core. Pinning it makes runs on different
+    /// machines comparable. Worker threads only execute the async tasks;
+    /// how many files are in flight at once is still decided by `--burst`,
+    /// `--max-in-flight` and `--find-breakpoint`, so a low thread count can
+    /// delay polling when many files are in flight.
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u64).range(1..), help = "Number of async runtime worker threads (default: one per CPU core)")]
+    pub worker_threads: Option<u64>,
+    
+    /// Serve async task states to `tokio-console` while the test runs
+    /// 
+    /// Only available when built with the `tokio-console` feature (and
+    /// `RUSTFLAGS="--cfg tokio_unstable"` for task instrumentation). Attach
+    /// with `tokio-console` to inspect tasks when a run appears stalled.
+    #[cfg(feature = "tokio-console")]
+    #[arg(long, help = "Serve task states to tokio-console")]
+    pub tokio_console: bool,
+    
+    /// Run the test pipeline against a built-in in-memory mock instead of S3
+    /// 
+    /// Only available when built with the `self-test` feature. No config
+    /// file or credentials are needed; the run succeeds only if the measured
+    /// median propagation time matches `--self-test-delay-ms`.
+    #[cfg(feature = "self-test")]
+    #[arg(long, help = "Run against an in-memory mock instead of S3")]
+    pub self_test: bool,
+    
+    /// Simulated propagation delay of the `--self-test` mock, in milliseconds
+    #[cfg(feature = "self-test")]
+    #[arg(long, value_name = "MS", default_value = "200", help = "Simulated propagation delay for --self-test")]
+    pub self_test_delay_ms: u64,
+    
+    /// Probability (0.0 to 1.0) that a `--self-test` mock request fails with 503
+    #[cfg(feature = "self-test")]
+    #[arg(long, value_name = "RATE", default_value = "0.0", value_parser = parse_failure_rate, help = "Simulated request failure rate for --self-test")]
+    pub self_test_failure_rate: f64,
+    
+    /// Time unit used for durations in the console summary
+    /// 
+    /// `auto` picks µs, ms or s per value based on its magnitude. The JSON
+    /// report always records raw milliseconds regardless of this setting.
+    #[arg(long, value_enum, default_value = "auto", global = true, help = "Time unit for the console summary")]
+    pub time_unit: TimeUnit,
+    
+    /// Number of decimal places for durations in the console summary
+    #[arg(long, value_name = "N", default_value = "1", global = true, help = "Decimal places for durations in the summary")]
+    pub precision: usize,
+    
+    /// Number of slowest files listed in the console summary
+    /// 
+    /// Lists each file's key, propagation time and read attempts so the
+    /// slowest objects can be followed up on. Use 0 to hide the section.
+    #[arg(long, value_name = "N", default_value = "5", help = "Number of slowest files to list in the summary (0 to hide)")]
+    pub top_slow: usize,
+    
+    /// Show the individual results as an aligned table
+    /// 
+    /// Renders one row per file with columns for the test number, shortened
+    /// key, status, duration, attempts and error. Long keys and errors are
+    /// truncated so the columns stay aligned.
+    #[arg(long, help = "Show individual results as an aligned table")]
+    pub table: bool,
+    
+    /// Order of the individual results in the console summary
+    /// 
+    /// `duration` lists the slowest files first, followed by files without
+    /// a propagation time. `status` lists failed files before successful
+    /// ones. Test numbers still refer to the execution order, and the JSON
+    /// report always keeps results in execution order.
+    #[arg(long, value_enum, default_value = "order", help = "Order of the individual results in the summary")]
+    pub sort_results: ResultOrder,
+    
     /// Enable verbose logging
     /// 
     /// Shows detailed debug information including individual read attempts,
     /// upload progress, and cleanup operations.
     #[arg(short, long, help = "Enable verbose logging")]
     pub verbose: bool,
+    
+    /// Print the tool version with dependency and build details, then exit
+    /// 
+    /// Lists the `rust-s3` crate version, the Rust compiler version and the
+    /// build time in addition to the tool version shown by `--version`.
+    #[arg(long, help = "Print tool, rust-s3 and rustc versions and the build time, then exit")]
+    pub version_verbose: bool,
+    
+    /// Show debug logs only for files that fail
+    /// 
+    /// Buffers each file's debug logs from upload to the end of polling and
+    /// writes them to stderr only if the file ultimately fails, so a large
+    /// run stays quiet while every failure can still be diagnosed. Logs for
+    /// files that pass are discarded.
+    #[arg(long, conflicts_with_all = ["verbose", "tui"], help = "Print buffered debug logs to stderr only for files that fail")]
+    pub verbose_failures: bool,
+    
+    /// Log the full details of the first failed request
+    /// 
+    /// The first failed upload or read of the run is logged at error level
+    /// with its URL, request headers, response status and response body,
+    /// with credentials redacted. Later failures stay summarized. Reads that
+    /// find no object yet are expected while a file propagates and are not
+    /// counted as failures.
+    #[arg(long, help = "Log URL, headers, status and body of the first failed upload or read")]
+    pub debug_first_failure: bool,
+}
+
+impl Args {
+    /// Gets the largest test file size the run can upload
+    /// 
+    /// # Returns
+    /// 
+    /// The largest size of `--size-distribution` if given, otherwise `--file-size`
+    pub fn max_file_size(&self) -> usize {
+        self.size_distribution.as_ref().map_or(self.file_size, SizeDistribution::max_size)
+    }
+}
+
+/// Parses a `key=value` run label
+/// 
+/// # Arguments
+/// 
+/// * `label` - The label as given on the command line
+/// 
+/// # Returns
+/// 
+/// - `Ok((key, value))` if the label has a non-empty key
+/// - `Err(String)` describing the problem otherwise
+fn parse_label(label: &str) -> Result<(String, String), String> {
+    match label.split_once('=') {
+        Some((key, value)) if !key.trim().is_empty() => Ok((key.trim().to_string(), value.to_string())),
+        _ => Err(format!("invalid label '{}' (expected key=value)", label)),
+    }
+}
+
+/// Smallest part size S3 accepts for all but the last part of a multipart upload
+const MIN_MULTIPART_PART_SIZE: usize = 5 * 1024 * 1024;
+
+/// Parses a multipart upload part size
+/// 
+/// # Arguments
+/// 
+/// * `size` - The part size in bytes as given on the command line
+/// 
+/// # Returns
+/// 
+/// - `Ok(size)` if the size is a number of at least `MIN_MULTIPART_PART_SIZE`
+/// - `Err(String)` describing the problem otherwise
+fn parse_part_size(size: &str) -> Result<usize, String> {
+    match size.parse::<usize>() {
+        Ok(size) if size >= MIN_MULTIPART_PART_SIZE => Ok(size),
+        Ok(_) => Err(format!("part size must be at least {} bytes", MIN_MULTIPART_PART_SIZE)),
+        Err(_) => Err(format!("invalid part size '{}'", size)),
+    }
+}
+
+/// Parses the streak length for `--consecutive-reads`
+/// 
+/// # Arguments
+/// 
+/// * `count` - The number of reads as given on the command line
+/// 
+/// # Returns
+/// 
+/// - `Ok(count)` if the count is a number of at least 1
+/// - `Err(String)` describing the problem otherwise
+fn parse_consecutive_reads(count: &str) -> Result<u32, String> {
+    match count.parse::<u32>() {
+        Ok(0) => Err("at least one read is required".to_string()),
+        Ok(count) => Ok(count),
+        Err(_) => Err(format!("invalid read count '{}'", count)),
+    }
+}
+
+/// Parses a fraction of files for `--organic-read-fraction`
+/// 
+/// # Arguments
+/// 
+/// * `fraction` - The fraction as given on the command line
+/// 
+/// # Returns
+/// 
+/// - `Ok(fraction)` if the fraction is a number between 0.0 and 1.0
+/// - `Err(String)` describing the problem otherwise
+fn parse_fraction(fraction: &str) -> Result<f64, String> {
+    match fraction.parse::<f64>() {
+        Ok(value) if (0.0..=1.0).contains(&value) => Ok(value),
+        Ok(_) => Err("fraction must be between 0.0 and 1.0".to_string()),
+        Err(_) => Err(format!("invalid fraction '{}'", fraction)),
+    }
+}
+
+/// Parses the URL for `--webhook`
+/// 
+/// # Arguments
+/// 
+/// * `url` - The URL as given on the command line
+/// 
+/// # Returns
+/// 
+/// - `Ok(url)` if the URL is an http or https URL
+/// - `Err(String)` describing the problem otherwise
+fn parse_webhook_url(url: &str) -> Result<String, String> {
+    if url.starts_with("http://") || url.starts_with("https://") {
+        Ok(url.to_string())
+    } else {
+        Err(format!("webhook URL '{}' must start with http:// or https://", url))
+    }
+}
+
+/// Parses a simulated failure rate for `--self-test-failure-rate`
+/// 
+/// # Arguments
+/// 
+/// * `rate` - The failure rate as given on the command line
+/// 
+/// # Returns
+/// 
+/// - `Ok(rate)` if the rate is a number between 0.0 and 1.0
+/// - `Err(String)` describing the problem otherwise
+#[cfg(feature = "self-test")]
+fn parse_failure_rate(rate: &str) -> Result<f64, String> {
+    match rate.parse::<f64>() {
+        Ok(value) if (0.0..=1.0).contains(&value) => Ok(value),
+        Ok(_) => Err("failure rate must be between 0.0 and 1.0".to_string()),
+        Err(_) => Err(format!("invalid failure rate '{}'", rate)),
+    }
+}
+
+/// Subcommands that run instead of a consistency test
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Calculate statistics for propagation times measured elsewhere
+    /// 
+    /// Reads durations in milliseconds, one per line, and prints the summary
+    /// and saves the full statistics without connecting to S3.
+    Stats {
+        /// File of durations in milliseconds, one per line (`-` for stdin)
+        #[arg(value_name = "FILE")]
+        durations: PathBuf,
+    },
+    
+    /// Run the same workload against several configurations and rank them
+    /// 
+    /// Each configuration is tested in turn with the test settings given
+    /// before the subcommand, and the results are compared in one matrix
+    /// ranked by success rate and p95 propagation time.
+    Benchmark {
+        /// Configuration file to compare, or a directory of `.toml` files; repeat for each provider
+        #[arg(short, long = "config", value_name = "FILE_OR_DIR", required = true)]
+        configs: Vec<PathBuf>,
+    },
+}
+
+/// Time unit for durations printed in the console summary
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum TimeUnit {
+    /// Scale each value to µs, ms or s based on its magnitude
+    Auto,
+    
+    /// Microseconds
+    Us,
+    
+    /// Milliseconds
+    Ms,
+    
+    /// Seconds
+    S,
+}
+
+/// Order of the individual results in the console summary
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ResultOrder {
+    /// Execution order
+    Order,
+    
+    /// Slowest propagation first
+    Duration,
+    
+    /// Failed files first, each group in execution order
+    Status,
+}
+
+/// Strategy for picking among several equivalent endpoints
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum EndpointSelection {
+    /// Rotate through the endpoints for every upload and read
+    RoundRobin,
+    
+    /// Send every request to the endpoint that answered a probe fastest
+    LowestLatency,
+}
+
+/// Options controlling how the console summary is rendered
+/// 
+/// These only affect human-readable output; saved reports always
+/// contain raw values.
+#[derive(Debug, Clone)]
+pub struct DisplayOptions {
+    /// Time unit for durations
+    pub time_unit: TimeUnit,
+    
+    /// Number of decimal places for durations
+    pub precision: usize,
+    
+    /// Number of slowest files listed in the run summary
+    pub top_slow: usize,
+    
+    /// Whether individual results are shown as an aligned table
+    pub table: bool,
+    
+    /// Order of the individual results
+    pub sort_results: ResultOrder,
+}
+
+impl Default for DisplayOptions {
+    fn default() -> Self {
+        Self {
+            time_unit: TimeUnit::Auto,
+            precision: 1,
+            top_slow: 5,
+            table: false,
+            sort_results: ResultOrder::Order,
+        }
+    }
+}
+
+impl From<&Args> for DisplayOptions {
+    fn from(args: &Args) -> Self {
+        Self {
+            time_unit: args.time_unit,
+            precision: args.precision,
+            top_slow: args.top_slow,
+            table: args.table,
+            sort_results: args.sort_results,
+        }
+    }
+}
+
+impl DisplayOptions {
+    /// Formats a duration given in milliseconds for display
+    /// 
+    /// # Arguments
+    /// 
+    /// * `ms` - Duration in milliseconds
+    /// 
+    /// # Returns
+    /// 
+    /// The duration in the configured unit with the configured number of
+    /// decimal places, e.g. `"1.9s"` or `"250.0ms"`
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let display = DisplayOptions::default();
+    /// assert_eq!(display.format_ms(1_850_000.0), "1850.0s");
+    /// ```
+    pub fn format_ms(&self, ms: f64) -> String {
+        let unit = match self.time_unit {
+            TimeUnit::Auto if ms >= 1000.0 => TimeUnit::S,
+            TimeUnit::Auto if ms > 0.0 && ms < 1.0 => TimeUnit::Us,
+            TimeUnit::Auto => TimeUnit::Ms,
+            unit => unit,
+        };
+        
+        let (value, suffix) = match unit {
+            TimeUnit::Us => (ms * 1000.0, "µs"),
+            TimeUnit::S => (ms / 1000.0, "s"),
+            _ => (ms, "ms"),
+        };
+        
+        format!("{:.*}{}", self.precision, value, suffix)
+    }
+    
+    /// Formats a duration given in microseconds for display
+    /// 
+    /// The same as `format_ms`, for values measured at microsecond resolution.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `us` - Duration in microseconds
+    /// 
+    /// # Returns
+    /// 
+    /// The duration in the configured unit, e.g. `"420.0µs"`
+    pub fn format_us(&self, us: f64) -> String {
+        self.format_ms(us / 1000.0)
+    }
+}
+
+/// Test parameters used during the consistency test
+/// 
+/// This structure captures the key parameters used during testing
+/// for inclusion in the final report. It provides context for
+/// interpreting the test results.
+#[derive(Debug, Clone, Serialize)]
+pub struct TestParameters {
+    /// Workload profile the settings were loaded from
+    /// 
+    /// `None` unless `--workload` was used. Command-line flags may have
+    /// overridden some of its settings.
+    pub workload: Option<String>,
+    
+    /// Number of test files that were uploaded
+    pub test_count: usize,
+    
+    /// Size of each test file in bytes
+    pub file_size: usize,
+    
+    /// Weighted distribution test file sizes were sampled from
+    /// 
+    /// `None` unless `--size-distribution` was used, in which case it
+    /// overrides `file_size`.
+    pub size_distribution: Option<String>,
+    
+    /// Whether files were streamed on upload and read back with ranged reads
+    /// 
+    /// Set when the largest file size exceeded the stream threshold.
+    pub streamed: bool,
+    
+    /// Smallest file size uploaded with a multipart upload
+    /// 
+    /// `None` unless `--multipart` was used.
+    pub multipart_threshold: Option<usize>,
+    
+    /// Part size of multipart uploads in bytes
+    /// 
+    /// `None` unless `--multipart` was used.
+    pub multipart_part_size: Option<usize>,
+    
+    /// Maximum wait time in seconds
+    pub max_wait_seconds: u64,
+    
+    /// Check interval in milliseconds
+    pub check_interval_ms: u64,
+    
+    /// Whether the check interval was tuned automatically per file
+    /// 
+    /// When `true`, each result records its own effective interval.
+    pub auto_interval: bool,
+    
+    /// Whether more files were tested until the confidence interval narrowed
+    /// 
+    /// When `true`, `test_count` is the number of files actually tested.
+    pub adaptive_count: bool,
+    
+    /// Target width of the 95% confidence interval on the average (milliseconds)
+    /// 
+    /// `None` unless `--adaptive-count` was used.
+    pub target_ci_width_ms: Option<f64>,
+    
+    /// Whether all files were uploaded before any polling began
+    pub burst_mode: bool,
+    
+    /// Cap on files awaiting consistency at once in burst mode
+    /// 
+    /// `None` unless `--max-in-flight` was used with `--burst`.
+    pub max_in_flight: Option<usize>,
+    
+    /// Server-side encryption applied to uploads, e.g. `aws:kms`
+    /// 
+    /// `None` if no `sse` was configured or it was skipped as unsupported.
+    pub sse: Option<String>,
+    
+    /// KMS key used for server-side encryption
+    /// 
+    /// `None` unless `sse_kms_key_id` was configured and applied.
+    pub sse_kms_key_id: Option<String>,
+    
+    /// Whether the configured `sse` was skipped because the backend rejected it
+    pub sse_skipped: bool,
+    
+    /// Maximum random delay applied before each upload in milliseconds
+    pub upload_jitter_ms: u64,
+    
+    /// Upload bandwidth limit in bytes per second, if `--max-upload-bps` was set
+    pub max_upload_bps: Option<u64>,
+    
+    /// Number of async runtime worker threads, if `--worker-threads` was set
+    pub worker_threads: Option<u64>,
+    
+    /// Fraction of files that got a simulated organic read
+    /// 
+    /// `None` unless `--organic-read-fraction` was above zero.
+    pub organic_read_fraction: Option<f64>,
+    
+    /// Mean delay before a simulated organic read in milliseconds
+    /// 
+    /// `None` unless `--organic-read-fraction` was above zero.
+    pub organic_read_mean_ms: Option<u64>,
+    
+    /// Delay between a successful read and deleting the file in milliseconds
+    pub cleanup_delay_ms: u64,
+    
+    /// Whether cleanup was deferred until all measurements completed
+    pub defer_cleanup: bool,
+    
+    /// Whether every read attempt used a new connection
+    pub fresh_connection: bool,
+    
+    /// Consecutive matching reads required before a file counted as consistent
+    pub consecutive_reads: u32,
+    
+    /// How requests were spread across several configured endpoints
+    pub endpoint_selection: EndpointSelection,
+    
+    /// Maximum number of upload retries per file
+    pub file_retries: u32,
+    
+    /// Whether the writer read each file back immediately after uploading it
+    pub writer_read_your_writes: bool,
+    
+    /// Whether reads were verified against a payload hash in object metadata
+    pub self_verify: bool,
+    
+    /// Checksum algorithm uploads were sent with and reads verified against
+    pub checksum: Option<ChecksumAlgorithm>,
+    
+    /// Whether read statuses were checked against the 404-then-object pattern
+    pub expect_404_first: bool,
+    
+    /// Template used for test file keys
+    /// 
+    /// `None` if keys were generated in `key_style`.
+    pub key_pattern: Option<String>,
+    
+    /// Form of generated keys when no key pattern was given
+    pub key_style: KeyStyle,
+    
+    /// Whether each key was checked for an existing object before upload
+    pub check_collision: bool,
+    
+    /// Whether bucket listing completeness was measured for the batch
+    pub list_completeness: bool,
+    
+    /// Keys per listing page requested for the completeness check, if limited
+    pub list_page_size: Option<u64>,
+    
+    /// Whether the time for deleted files to leave listings was measured
+    pub delete_list_consistency: bool,
+    
+    /// Whether files were deleted and re-created under the same key
+    pub recreate_after_delete: bool,
+    
+    /// Number of writers racing to overwrite one key
+    /// 
+    /// `None` unless `--overwrite-race` was used.
+    pub overwrite_writers: Option<u64>,
+    
+    /// Whether replication to the `replication_target` bucket was measured
+    pub replication: bool,
+    
+    /// Whether the skew between object body and metadata was measured
+    pub metadata_skew: bool,
+    
+    /// Whether the report's results were limited to failed tests
+    pub failures_only: bool,
+    
+    /// Whether each result records a timeline of phase timestamps
+    pub detailed_timeline: bool,
+    
+    /// Propagation time target for SLA reporting in milliseconds
+    /// 
+    /// `None` unless `--sla-ms` was given.
+    pub sla_ms: Option<u64>,
+    
+    /// Shortest propagation time admitted to the statistics in milliseconds
+    /// 
+    /// `None` unless `--stats-min-ms` was given.
+    pub stats_min_ms: Option<u64>,
+    
+    /// Longest propagation time admitted to the statistics in milliseconds
+    /// 
+    /// `None` unless `--stats-max-ms` was given.
+    pub stats_max_ms: Option<u64>,
+    
+    /// Interval between availability probes in milliseconds
+    /// 
+    /// `None` if availability probing was disabled.
+    pub availability_probe_interval_ms: Option<u64>,
+}
+
+impl From<&Args> for TestParameters {
+    fn from(args: &Args) -> Self {
+        Self {
+            workload: args.workload.as_ref().map(|path| path.display().to_string()),
+            test_count: args.test_count,
+            file_size: args.file_size,
+            size_distribution: args.size_distribution.as_ref().map(|distribution| distribution.to_string()),
+            streamed: args.max_file_size() > args.stream_threshold,
+            multipart_threshold: args.multipart.then_some(args.multipart_threshold),
+            multipart_part_size: args.multipart.then_some(args.multipart_part_size),
+            max_wait_seconds: args.max_wait,
+            check_interval_ms: args.interval,
+            auto_interval: args.auto_interval,
+            adaptive_count: args.adaptive_count,
+            target_ci_width_ms: args.adaptive_count.then_some(args.target_ci_width),
+            burst_mode: args.burst,
+            max_in_flight: args.max_in_flight.filter(|_| args.burst),
+            sse: None,
+            sse_kms_key_id: None,
+            sse_skipped: false,
+            upload_jitter_ms: args.upload_jitter,
+            max_upload_bps: args.max_upload_bps,
+            worker_threads: args.worker_threads,
+            organic_read_fraction: (args.organic_read_fraction > 0.0).then_some(args.organic_read_fraction),
+            organic_read_mean_ms: (args.organic_read_fraction > 0.0).then_some(args.organic_read_mean_ms),
+            cleanup_delay_ms: args.cleanup_delay,
+            defer_cleanup: args.defer_cleanup,
+            fresh_connection: args.fresh_connection,
+            consecutive_reads: args.consecutive_reads,
+            endpoint_selection: args.endpoint_selection,
+            file_retries: args.file_retries,
+            writer_read_your_writes: args.writer_read_your_writes,
+            self_verify: args.self_verify,
+            checksum: args.checksum,
+            expect_404_first: args.expect_404_first,
+            key_pattern: args.key_pattern.as_ref().map(|pattern| pattern.to_string()),
+            key_style: args.key_style,
+            check_collision: args.check_collision,
+            list_completeness: args.list_completeness,
+            list_page_size: args.list_page_size,
+            delete_list_consistency: args.test_delete_list_consistency,
+            recreate_after_delete: args.recreate_after_delete,
+            overwrite_writers: args.overwrite_race.then_some(args.overwrite_writers),
+            replication: args.test_replication,
+            metadata_skew: args.metadata_skew,
+            failures_only: args.failures_only,
+            detailed_timeline: args.detailed_timeline,
+            sla_ms: args.sla_ms,
+            stats_min_ms: args.stats_min_ms,
+            stats_max_ms: args.stats_max_ms,
+            availability_probe_interval_ms: args.availability_probe.then_some(args.probe_interval),
+        }
+    }
+}
+
+/// Result of testing a single file for consistency
+/// 
+/// Contains detailed information about one consistency test,
+/// including timing data, success status, and error details.
+/// 
+/// # Fields
+/// 
+/// - `file_key`: The S3 object key that was tested
+/// - `upload_time`: When the file was successfully uploaded
+/// - `first_read_success_time`: When the file first became readable (if successful)
+/// - `propagation_duration_ms`: How long it took to become consistent in milliseconds
+/// - `propagation_duration_us`: The same duration in microseconds
+/// - `total_attempts`: Number of read attempts made
+/// - `elapsed_ms_at_success`: Polling time until the successful read in milliseconds
+/// - `cumulative_sleep_ms`: Time spent sleeping between read attempts in milliseconds
+/// - `success`: Whether the consistency test succeeded
+/// - `error_details`: Error message if the test failed
+#[derive(Debug, Clone, Serialize)]
+pub struct TestResult {
+    /// The S3 object key that was tested
+    /// 
+    /// Generated as "consistency-test-{uuid}" to ensure uniqueness
+    pub file_key: String,
+    
+    /// Position of the file in the order the tests were started, from 0
+    /// 
+    /// Concurrent files finish in an unpredictable order, so the report's
+    /// results are sorted by this index to make runs comparable.
+    pub test_index: usize,
+    
+    /// Timestamp when the file upload completed successfully
+    /// 
+    /// This is the baseline time used to calculate propagation duration
+    pub upload_time: DateTime<Utc>,
+    
+    /// Timestamp when the file first became readable
+    /// 
+    /// `None` if the file never became consistently readable within the timeout
+    pub first_read_success_time: Option<DateTime<Utc>>,
+    
+    /// Time in milliseconds from upload to first successful read
+    /// 
+    /// Measured with a monotonic clock between upload completion and the
+    /// first successful read, so it is unaffected by NTP adjustments to the
+    /// wall-clock timestamps above. `None` if the consistency test failed or
+    /// timed out.
+    pub propagation_duration_ms: Option<u64>,
+    
+    /// Time in microseconds from upload to first successful read
+    /// 
+    /// The same measurement as `propagation_duration_ms` at a resolution that
+    /// can tell sub-millisecond propagation apart from zero.
+    pub propagation_duration_us: Option<u64>,
+    
+    /// Check interval used while polling this file (milliseconds)
+    /// 
+    /// Differs between files when `--auto-interval` is used.
+    /// `None` if the file was never polled (e.g. the upload failed).
+    pub check_interval_ms: Option<u64>,
+    
+    /// Total number of read attempts made during the test
+    /// 
+    /// Includes both failed and the final successful attempt.
+    /// Helps understand the load generated during testing.
+    pub total_attempts: u32,
+    
+    /// Time from the start of polling until the successful read (milliseconds)
+    /// 
+    /// Together with `total_attempts` this shows whether, say, attempt 50
+    /// came after 5s or 50s. `None` if the file never became readable.
+    pub elapsed_ms_at_success: Option<u64>,
+    
+    /// Total time actually spent sleeping between read attempts (milliseconds)
+    /// 
+    /// The remainder of `elapsed_ms_at_success` was spent in read requests.
+    /// `None` if the file never became readable.
+    pub cumulative_sleep_ms: Option<u64>,
+    
+    /// Whether the consistency test completed successfully
+    /// 
+    /// `true` if the file became readable within the timeout,
+    /// `false` if it failed or timed out.
+    pub success: bool,
+    
+    /// Error message if the test failed
+    /// 
+    /// Contains details about what went wrong, such as:
+    /// - Upload failures
+    /// - Timeout errors  
+    /// - Network errors
+    /// - Authentication failures
+    pub error_details: Option<String>,
+    
+    /// Why polling was abandoned before `max_wait`, if it was
+    /// 
+    /// Set when the read attempts kept failing with non-retryable errors
+    /// such as access denied, e.g. "HTTP 403 (access denied)".
+    pub early_abort_reason: Option<String>,
+    
+    /// Time from deletion until the file stopped appearing in listings (milliseconds)
+    /// 
+    /// Only measured with `--test-delete-list-consistency`. `None` if not
+    /// measured, if the delete failed, or if the key was still listed after `max_wait`.
+    pub delete_list_propagation_duration_ms: Option<u64>,
+    
+    /// Time from re-uploading a deleted key until the new object was readable (milliseconds)
+    /// 
+    /// Only measured with `--recreate-after-delete`. `None` if not measured
+    /// or if the re-created object was not readable after `max_wait`.
+    pub recreate_after_delete_propagation_ms: Option<u64>,
+    
+    /// Reads of the re-created object that still returned 404
+    /// 
+    /// Only 404s later than `propagation_duration_ms` after the re-upload
+    /// are counted, i.e. while a fresh key would already have been readable.
+    pub stale_tombstone_reads: u32,
+    
+    /// Why the delete and re-create test failed, if it did
+    pub recreate_error: Option<String>,
+    
+    /// Time from upload until the file could be read from the replication target (milliseconds)
+    /// 
+    /// Only measured with `--test-replication`, using the same monotonic
+    /// baseline as `propagation_duration_ms`. `None` if not measured or if
+    /// the file had not replicated after `max_wait`.
+    pub replication_propagation_duration_ms: Option<u64>,
+    
+    /// Time from upload until a HEAD returned the file's correct metadata (milliseconds)
+    /// 
+    /// Only measured with `--metadata-skew`, using the same monotonic
+    /// baseline as `propagation_duration_ms`. `None` if not measured or if
+    /// the metadata was still wrong after `max_wait`.
+    pub metadata_consistent_duration_ms: Option<u64>,
+    
+    /// Time from the body becoming readable until the metadata was correct (milliseconds)
+    /// 
+    /// Positive when the metadata lagged the body, negative when the body
+    /// lagged the metadata. `None` unless both were measured.
+    pub metadata_body_skew_ms: Option<i64>,
+    
+    /// Whether the uploading client could read the file right after writing it
+    /// 
+    /// Only measured with `--writer-read-your-writes`. `None` if not measured.
+    pub writer_read_your_writes: Option<bool>,
+    
+    /// Delay after upload at which a simulated organic reader read the file (milliseconds)
+    /// 
+    /// Only set for the files sampled by `--organic-read-fraction`.
+    pub organic_read_delay_ms: Option<u64>,
+    
+    /// Whether the simulated organic read returned the uploaded object
+    /// 
+    /// `Some(false)` means a real reader arriving at that moment would have
+    /// seen inconsistent data. `None` if the file was not sampled.
+    pub organic_read_consistent: Option<bool>,
+    
+    /// Number of reads that succeeded but returned the wrong body length
+    /// 
+    /// Such reads, e.g. an empty body from a gateway that has not caught up,
+    /// are not counted as consistency and polling continues.
+    pub size_mismatch_events: u32,
+    
+    /// Whether a streak of successful reads was broken by a failed read
+    /// 
+    /// Only possible with `--consecutive-reads` above 1. Shows that the
+    /// backend flapped between consistent and inconsistent states while the
+    /// file propagated.
+    pub flapping_detected: bool,
+    
+    /// Number of times the file's upload was retried after a transient failure
+    pub file_retries: u32,
+    
+    /// Number of generated keys for this file that already existed
+    /// 
+    /// Only checked with `--check-collision`; each collision replaced the key
+    /// with a newly generated one before upload.
+    pub key_collisions: u32,
+    
+    /// Whether the file became consistent within the `--sla-ms` target
+    /// 
+    /// `None` if no target was given or the upload failed.
+    pub sla_met: Option<bool>,
+    
+    /// Whether the file was uploaded with a multipart upload
+    pub was_multipart: bool,
+    
+    /// Whether the consistent read matched the payload hash in the object's metadata
+    /// 
+    /// Only checked with `--self-verify`. `None` if not checked, e.g. for
+    /// streamed or multipart files or when the file never became readable.
+    pub content_self_verified: Option<bool>,
+    
+    /// Whether the consistent read matched the checksum the backend returned with it
+    /// 
+    /// Only checked with `--checksum`. `false` also if no checksum was
+    /// returned. `None` if not checked, e.g. for streamed or multipart files
+    /// or when the file never became readable.
+    pub checksum_verified: Option<bool>,
+    
+    /// Status of each read attempt, run-length encoded, e.g. `404x3,200`
+    /// 
+    /// `!` marks a success status with placeholder content, `T` a timeout
+    /// and `E` an error without a status. Only recorded with `--expect-404-first`.
+    pub status_sequence: Option<String>,
+    
+    /// How the read statuses deviated from 404s followed by the object
+    /// 
+    /// `None` if they followed the pattern, within `--status-anomaly-grace`,
+    /// or were not checked.
+    pub status_anomaly: Option<String>,
+    
+    /// The `--capture-header` headers of the successful read, keyed by lowercased name
+    /// 
+    /// Empty if no headers were requested, none were present, or the file
+    /// never became readable.
+    pub captured_headers: HashMap<String, String>,
+    
+    /// Absolute timestamps of each phase of this file's test
+    /// 
+    /// Only recorded with `--detailed-timeline`. `None` if not recorded or
+    /// if the file was never uploaded.
+    pub timeline: Option<FileTimeline>,
+    
+    /// Whether the propagation time is a statistical outlier
+    /// 
+    /// Set after statistics are calculated for results whose propagation time
+    /// lies above the upper Tukey fence (Q3 + 1.5·IQR) of the run.
+    pub is_outlier: bool,
+    
+    /// Size of the uploaded file in bytes
+    /// 
+    /// Varies per file with `--size-distribution`. `None` for results not
+    /// produced by an upload, such as imported durations.
+    pub file_size: Option<usize>,
+    
+    /// Endpoint the file was uploaded through
+    /// 
+    /// Only recorded when the config lists several `endpoints`.
+    pub upload_endpoint: Option<String>,
+    
+    /// Endpoint the file was polled through
+    /// 
+    /// Only recorded when the config lists several `endpoints`.
+    pub read_endpoint: Option<String>,
+    
+    /// Bucket the file was uploaded to
+    /// 
+    /// Only recorded when the config lists several `buckets`.
+    pub bucket: Option<String>,
+    
+    /// Failed requests the file's test recovered from by retrying, by error category
+    /// 
+    /// Counts failed upload attempts before a successful upload, and failed
+    /// reads other than the expected 404s before the file became consistent.
+    pub recovered_errors: HashMap<String, u32>,
+    
+    /// Failed requests of an upload or polling that never succeeded, by error category
+    pub permanent_errors: HashMap<String, u32>,
+    
+    /// S3 error code of the last failed request that returned one, e.g. `SlowDown`
+    /// 
+    /// Taken from the `<Code>` element of the error response. Failed reads
+    /// take precedence over failed uploads, and the expected 404s while
+    /// polling for a file are not recorded.
+    pub last_error_code: Option<String>,
+}
+
+/// Absolute timestamps of the phases of one file's test
+/// 
+/// Recorded with `--detailed-timeline` so that a waterfall chart of every
+/// file can be rendered externally.
+#[derive(Debug, Clone, Serialize)]
+pub struct FileTimeline {
+    /// When the first upload attempt was sent
+    pub upload_start: DateTime<Utc>,
+    
+    /// When the upload completed, or `None` if it failed
+    pub upload_end: Option<DateTime<Utc>>,
+    
+    /// When each read attempt was sent, in order
+    pub polls: Vec<DateTime<Utc>>,
+    
+    /// When the first successful read returned, or `None` if none did
+    pub success: Option<DateTime<Utc>>,
+    
+    /// When deleting the file started, or `None` if it was not cleaned up
+    pub cleanup_start: Option<DateTime<Utc>>,
+    
+    /// When deleting the file finished, or `None` if it was not cleaned up
+    pub cleanup_end: Option<DateTime<Utc>>,
+}
+
+impl FileTimeline {
+    /// Creates a timeline for a file whose upload just started
+    /// 
+    /// # Arguments
+    /// 
+    /// * `upload_start` - When the first upload attempt was sent
+    /// 
+    /// # Returns
+    /// 
+    /// A `FileTimeline` with every later phase still unset
+    pub fn new(upload_start: DateTime<Utc>) -> Self {
+        Self {
+            upload_start,
+            upload_end: None,
+            polls: Vec::new(),
+            success: None,
+            cleanup_start: None,
+            cleanup_end: None,
+        }
+    }
+}
+
+impl TestResult {
+    /// Creates a new successful test result
+    /// 
+    /// # Arguments
+    /// 
+    /// * `file_key` - The S3 object key that was tested
+    /// * `upload_time` - When the upload completed
+    /// * `first_read_success_time` - When the file became readable
+    /// * `total_attempts` - Number of read attempts made
+    /// 
+    /// # Returns
+    /// 
+    /// A `TestResult` with success=true and a propagation duration derived
+    /// from the two timestamps; use `set_propagation` to replace it with a
+    /// monotonic measurement
+    pub fn success(
+        file_key: String,
+        upload_time: DateTime<Utc>,
+        first_read_success_time: DateTime<Utc>,
+        total_attempts: u32,
+    ) -> Self {
+        // A wall clock stepped backwards can put the read before the upload
+        let propagation = first_read_success_time.signed_duration_since(upload_time);
+        let propagation_duration_ms = propagation.num_milliseconds().max(0) as u64;
+        let propagation_duration_us = propagation.num_microseconds().unwrap_or(i64::MAX).max(0) as u64;
+        
+        Self {
+            file_key,
+            test_index: 0,
+            upload_time,
+            first_read_success_time: Some(first_read_success_time),
+            propagation_duration_ms: Some(propagation_duration_ms),
+            propagation_duration_us: Some(propagation_duration_us),
+            check_interval_ms: None,
+            total_attempts,
+            elapsed_ms_at_success: None,
+            cumulative_sleep_ms: None,
+            success: true,
+            error_details: None,
+            early_abort_reason: None,
+            delete_list_propagation_duration_ms: None,
+            recreate_after_delete_propagation_ms: None,
+            stale_tombstone_reads: 0,
+            recreate_error: None,
+            replication_propagation_duration_ms: None,
+            metadata_consistent_duration_ms: None,
+            metadata_body_skew_ms: None,
+            writer_read_your_writes: None,
+            organic_read_delay_ms: None,
+            organic_read_consistent: None,
+            size_mismatch_events: 0,
+            flapping_detected: false,
+            file_retries: 0,
+            key_collisions: 0,
+            sla_met: None,
+            was_multipart: false,
+            content_self_verified: None,
+            checksum_verified: None,
+            status_sequence: None,
+            status_anomaly: None,
+            captured_headers: HashMap::new(),
+            timeline: None,
+            is_outlier: false,
+            file_size: None,
+            upload_endpoint: None,
+            read_endpoint: None,
+            bucket: None,
+            recovered_errors: HashMap::new(),
+            permanent_errors: HashMap::new(),
+            last_error_code: None,
+        }
+    }
+    
+    /// Creates a new failed test result
+    /// 
+    /// # Arguments
+    /// 
+    /// * `file_key` - The S3 object key that was tested
+    /// * `upload_time` - When the upload completed (or when it was attempted)
+    /// * `error_details` - Description of what went wrong
+    /// 
+    /// # Returns
+    /// 
+    /// A `TestResult` with success=false and error details
+    pub fn failure(
+        file_key: String,
+        upload_time: DateTime<Utc>,
+        error_details: String,
+    ) -> Self {
+        Self {
+            file_key,
+            test_index: 0,
+            upload_time,
+            first_read_success_time: None,
+            propagation_duration_ms: None,
+            propagation_duration_us: None,
+            check_interval_ms: None,
+            total_attempts: 0,
+            elapsed_ms_at_success: None,
+            cumulative_sleep_ms: None,
+            success: false,
+            error_details: Some(error_details),
+            early_abort_reason: None,
+            delete_list_propagation_duration_ms: None,
+            recreate_after_delete_propagation_ms: None,
+            stale_tombstone_reads: 0,
+            recreate_error: None,
+            replication_propagation_duration_ms: None,
+            metadata_consistent_duration_ms: None,
+            metadata_body_skew_ms: None,
+            writer_read_your_writes: None,
+            organic_read_delay_ms: None,
+            organic_read_consistent: None,
+            size_mismatch_events: 0,
+            flapping_detected: false,
+            file_retries: 0,
+            key_collisions: 0,
+            sla_met: None,
+            was_multipart: false,
+            content_self_verified: None,
+            checksum_verified: None,
+            status_sequence: None,
+            status_anomaly: None,
+            captured_headers: HashMap::new(),
+            timeline: None,
+            is_outlier: false,
+            file_size: None,
+            upload_endpoint: None,
+            read_endpoint: None,
+            bucket: None,
+            recovered_errors: HashMap::new(),
+            permanent_errors: HashMap::new(),
+            last_error_code: None,
+        }
+    }
+    
+    /// Records the propagation duration measured with a monotonic clock
+    /// 
+    /// The wall-clock timestamps can jump when NTP adjusts the system clock
+    /// mid-test, which would skew or even underflow a duration computed from
+    /// them. The timestamps are kept for display only.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `propagation` - Time from upload completion to the first successful read
+    pub fn set_propagation(&mut self, propagation: std::time::Duration) {
+        self.propagation_duration_ms = Some(propagation.as_millis() as u64);
+        self.propagation_duration_us = Some(propagation.as_micros() as u64);
+    }
+}
+
+/// Statistical analysis of consistency test results
+/// 
+/// Provides comprehensive statistics about the consistency behavior
+/// observed during testing. Includes success rates and timing percentiles.
+/// 
+/// All timing values are in milliseconds for consistency.
+#[derive(Debug, Clone, Serialize)]
+pub struct ConsistencyStatistics {
+    /// Number of tests that completed successfully
+    pub successful_tests: usize,
+    
+    /// Number of tests that failed or timed out
+    pub failed_tests: usize,
+    
+    /// Success rate as a percentage (0.0 to 100.0)
+    pub success_rate: f64,
+    
+    /// Fastest propagation time observed (milliseconds)
+    /// 
+    /// `None` if no tests succeeded
+    pub min_propagation_time_ms: Option<u64>,
+    
+    /// Slowest propagation time observed (milliseconds)
+    /// 
+    /// `None` if no tests succeeded
+    pub max_propagation_time_ms: Option<u64>,
+    
+    /// Average propagation time (milliseconds)
+    /// 
+    /// `None` if no tests succeeded
+    pub avg_propagation_time_ms: Option<f64>,
+    
+    /// Median propagation time (milliseconds)
+    /// 
+    /// The middle value when all successful propagation times are sorted.
+    /// `None` if no tests succeeded.
+    pub median_propagation_time_ms: Option<u64>,
+    
+    /// 95th percentile propagation time (milliseconds)
+    /// 
+    /// 95% of successful tests completed within this time.
+    /// `None` if fewer than `MIN_SAMPLES_P95` tests succeeded.
+    pub percentile_95_ms: Option<u64>,
+    
+    /// 99th percentile propagation time (milliseconds)
+    /// 
+    /// 99% of successful tests completed within this time.
+    /// `None` if fewer than `MIN_SAMPLES_P99` tests succeeded.
+    pub percentile_99_ms: Option<u64>,
+    
+    /// Fastest propagation time observed (microseconds)
+    /// 
+    /// `None` if no tests succeeded
+    pub min_propagation_time_us: Option<u64>,
+    
+    /// Slowest propagation time observed (microseconds)
+    /// 
+    /// `None` if no tests succeeded
+    pub max_propagation_time_us: Option<u64>,
+    
+    /// Average propagation time (microseconds)
+    /// 
+    /// `None` if no tests succeeded
+    pub avg_propagation_time_us: Option<f64>,
+    
+    /// Median propagation time (microseconds)
+    /// 
+    /// `None` if no tests succeeded
+    pub median_propagation_time_us: Option<u64>,
+    
+    /// 95th percentile propagation time (microseconds)
+    /// 
+    /// `None` if fewer than `MIN_SAMPLES_P95` tests succeeded.
+    pub percentile_95_us: Option<u64>,
+    
+    /// 99th percentile propagation time (microseconds)
+    /// 
+    /// `None` if fewer than `MIN_SAMPLES_P99` tests succeeded.
+    pub percentile_99_us: Option<u64>,
+    
+    /// Width of the 95% confidence interval on the average (milliseconds)
+    /// 
+    /// The average is expected to lie within half this width of the true
+    /// mean propagation time. `None` if fewer than two tests succeeded.
+    pub mean_ci_width_ms: Option<f64>,
+    
+    /// Upper Tukey fence (Q3 + 1.5·IQR) used for outlier detection (milliseconds)
+    /// 
+    /// Propagation times above this value are flagged as outliers.
+    /// `None` if no tests succeeded.
+    pub outlier_threshold_ms: Option<u64>,
+    
+    /// Number of successful tests whose propagation time exceeded the outlier threshold
+    pub outlier_count: usize,
+    
+    /// Total number of reads across all tests that returned the wrong body length
+    pub size_mismatch_events: u64,
+    
+    /// Number of tests whose streak of `--consecutive-reads` was broken at least once
+    pub flapping_tests: usize,
+    
+    /// Number of results left out of these statistics by `--stats-min-ms` or `--stats-max-ms`
+    /// 
+    /// Excluded results count neither as successful nor as failed tests, but
+    /// remain in the report's results.
+    pub excluded_results: usize,
+    
+    /// Total number of generated keys found to already exist by `--check-collision`
+    pub key_collisions: u64,
+    
+    /// Statistics for how long deleted files kept appearing in listings
+    /// 
+    /// `None` unless `--test-delete-list-consistency` measured at least one file.
+    pub delete_list: Option<DeleteListStatistics>,
+    
+    /// Statistics for how long deleted keys took to become readable again
+    /// 
+    /// `None` unless `--recreate-after-delete` tested at least one file.
+    pub recreate_after_delete: Option<RecreateStatistics>,
+    
+    /// Statistics for how long files took to replicate to the replication target
+    /// 
+    /// `None` unless `--test-replication` measured at least one file.
+    pub replication: Option<ReplicationStatistics>,
+    
+    /// Statistics for the skew between object body and metadata
+    /// 
+    /// `None` unless `--metadata-skew` measured at least one file.
+    pub metadata_skew: Option<MetadataSkewStatistics>,
+    
+    /// Comparison of same-connection and cross-connection visibility
+    /// 
+    /// `None` unless `--writer-read-your-writes` measured at least one file.
+    pub read_your_writes: Option<ReadYourWritesStatistics>,
+    
+    /// How often simulated organic reads would have seen inconsistent data
+    /// 
+    /// `None` unless `--organic-read-fraction` sampled at least one file.
+    pub organic_reads: Option<OrganicReadStatistics>,
+    
+    /// How polling time split between sleeping and read requests
+    /// 
+    /// `None` if no file became readable.
+    pub polling_time: Option<PollingTimeStatistics>,
+    
+    /// Number of files that missed the `--sla-ms` propagation target
+    pub sla_breaches: usize,
+    
+    /// Percentage of classified files that met the `--sla-ms` target (0.0 to 100.0)
+    /// 
+    /// `None` unless `--sla-ms` was given and at least one file was classified.
+    pub sla_compliance_rate: Option<f64>,
+    
+    /// How many successful files became consistent within each attempt range
+    /// 
+    /// Ranges grow roughly exponentially (1, 2-5, 6-20, 21-100, 101+) and
+    /// only ranges with at least one file are included. Empty if no test
+    /// succeeded.
+    pub attempts_distribution: Vec<AttemptsBucket>,
+    
+    /// Propagation times of successful files grouped by attempts needed
+    /// 
+    /// Cohorts are files consistent on the first attempt, after 2-5 attempts
+    /// and after 6 or more, showing whether slow propagation goes with many
+    /// retries. Only cohorts with at least one file are included.
+    pub attempt_cohorts: Vec<AttemptCohortStatistics>,
+    
+    /// Failed requests by error category and whether retrying recovered them
+    /// 
+    /// Ordered by number of occurrences, most frequent first. Expected 404s
+    /// while polling for a file are not counted.
+    pub error_retries: Vec<ErrorRetryStatistics>,
+    
+    /// Number of files by the S3 error code of their last failed request
+    /// 
+    /// Ordered by number of files, most frequent first. Files without an
+    /// error code are not counted.
+    pub error_codes: Vec<ErrorCodeCount>,
+}
+
+/// Number of files that became consistent within a range of read attempts
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AttemptsBucket {
+    /// Smallest attempt number in the range
+    pub min_attempts: u32,
+    
+    /// Largest attempt number in the range, or `None` for the open-ended last range
+    pub max_attempts: Option<u32>,
+    
+    /// Number of successful files whose first successful read was in the range
+    pub count: usize,
+    
+    /// Percentage of successful files in the range (0.0 to 100.0)
+    pub percentage: f64,
+}
+
+/// Outcomes of the failed requests in one error category
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ErrorRetryStatistics {
+    /// HTTP status, e.g. `503`, or kind of failure, e.g. `timeout`
+    pub category: String,
+    
+    /// Number of failed requests in the category
+    pub occurrences: u64,
+    
+    /// Failed requests followed by a successful retry of the same upload or polling
+    pub recovered: u64,
+    
+    /// Failed requests of an upload or polling that never succeeded
+    pub permanent: u64,
+}
+
+/// Number of files whose last failed request returned one S3 error code
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ErrorCodeCount {
+    /// S3 error code, e.g. `SlowDown` or `InternalError`
+    pub code: String,
+    
+    /// Number of files whose last failed request returned this code
+    pub files: usize,
+}
+
+/// Propagation times of the successful files in one attempt-count cohort
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AttemptCohortStatistics {
+    /// Smallest attempt count in the cohort
+    pub min_attempts: u32,
+    
+    /// Largest attempt count in the cohort, or `None` for the open-ended last cohort
+    pub max_attempts: Option<u32>,
+    
+    /// Number of successful files in the cohort
+    pub count: usize,
+    
+    /// Average propagation time in the cohort (milliseconds)
+    pub avg_propagation_time_ms: f64,
+    
+    /// 95th percentile propagation time in the cohort (milliseconds)
+    /// 
+    /// `None` if the cohort has too few files for a meaningful p95.
+    pub percentile_95_ms: Option<u64>,
+}
+
+/// Statistics comparing what the writer and the reader saw right after upload
+#[derive(Debug, Clone, Serialize)]
+pub struct ReadYourWritesStatistics {
+    /// Number of files the writer tried to read back
+    pub measured_count: usize,
+    
+    /// Number of files the writer could read immediately after uploading
+    pub writer_immediate_count: usize,
+    
+    /// Percentage of files the writer could read immediately (0.0 to 100.0)
+    pub writer_immediate_rate: f64,
+    
+    /// Number of those files the reader could read on its first attempt
+    pub reader_first_attempt_count: usize,
+    
+    /// Percentage of files the reader could read on its first attempt (0.0 to 100.0)
+    pub reader_first_attempt_rate: f64,
+}
+
+/// Statistics for simulated organic reads
+#[derive(Debug, Clone, Serialize)]
+pub struct OrganicReadStatistics {
+    /// Number of simulated organic reads made
+    pub simulated_reads: usize,
+    
+    /// Number of those reads that did not return the uploaded object
+    pub inconsistent_reads: usize,
+    
+    /// Percentage of simulated reads that would have hit inconsistent data (0.0 to 100.0)
+    pub inconsistent_rate: f64,
+}
+
+/// Split of polling time between sleeps and read requests
+/// 
+/// Only files that became readable are included, since the split is
+/// recorded with the successful read. All timing values are in milliseconds.
+#[derive(Debug, Clone, Serialize)]
+pub struct PollingTimeStatistics {
+    /// Number of files the split was measured for
+    pub measured_files: usize,
+    
+    /// Total time spent sleeping between read attempts
+    pub sleep_ms: u64,
+    
+    /// Total time spent waiting for read requests
+    pub request_ms: u64,
+    
+    /// Percentage of polling time spent sleeping (0.0 to 100.0)
+    pub sleep_percent: f64,
+}
+
+/// Statistics for delete-to-listing propagation times
+/// 
+/// All timing values are in milliseconds.
+#[derive(Debug, Clone, Serialize)]
+pub struct DeleteListStatistics {
+    /// Number of deleted files whose listing propagation was measured
+    pub measured_count: usize,
+    
+    /// Fastest time for a deleted file to leave the listing
+    pub min_ms: u64,
+    
+    /// Slowest time for a deleted file to leave the listing
+    pub max_ms: u64,
+    
+    /// Average time for a deleted file to leave the listing
+    pub avg_ms: f64,
+    
+    /// Median time for a deleted file to leave the listing
+    pub median_ms: u64,
+    
+    /// 95th percentile time for a deleted file to leave the listing
+    /// 
+    /// `None` if too few files were measured for a meaningful percentile.
+    pub percentile_95_ms: Option<u64>,
+}
+
+/// Statistics for re-creating deleted keys
+/// 
+/// All timing values are in milliseconds.
+#[derive(Debug, Clone, Serialize)]
+pub struct RecreateStatistics {
+    /// Number of files whose re-created object became readable
+    pub measured_count: usize,
+    
+    /// Number of files whose delete or re-upload failed, or whose
+    /// re-created object was not readable after `max_wait`
+    pub failed_count: usize,
+    
+    /// Fastest time for a re-created object to become readable
+    pub min_ms: Option<u64>,
+    
+    /// Slowest time for a re-created object to become readable
+    pub max_ms: Option<u64>,
+    
+    /// Average time for a re-created object to become readable
+    pub avg_ms: Option<f64>,
+    
+    /// Median time for a re-created object to become readable
+    pub median_ms: Option<u64>,
+    
+    /// 95th percentile time for a re-created object to become readable
+    /// 
+    /// `None` if too few files were measured for a meaningful percentile.
+    pub percentile_95_ms: Option<u64>,
+    
+    /// Number of files with at least one stale-tombstone read
+    pub stale_tombstone_files: usize,
+    
+    /// Total number of stale-tombstone reads
+    pub stale_tombstone_reads: u64,
+}
+
+/// Statistics for cross-bucket replication times
+/// 
+/// All timing values are in milliseconds.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReplicationStatistics {
+    /// Number of files that were read from the replication target
+    pub measured_count: usize,
+    
+    /// Fastest time for a file to replicate
+    pub min_ms: u64,
+    
+    /// Slowest time for a file to replicate
+    pub max_ms: u64,
+    
+    /// Average time for a file to replicate
+    pub avg_ms: f64,
+    
+    /// Median time for a file to replicate
+    pub median_ms: u64,
+    
+    /// 95th percentile time for a file to replicate
+    /// 
+    /// `None` if too few files were measured for a meaningful percentile.
+    pub percentile_95_ms: Option<u64>,
+}
+
+/// Statistics for the skew between object body and metadata
+/// 
+/// A skew is the time from the body becoming readable until a HEAD
+/// returned the correct metadata, in milliseconds. Positive skews mean the
+/// metadata lagged the body, negative skews the reverse.
+#[derive(Debug, Clone, Serialize)]
+pub struct MetadataSkewStatistics {
+    /// Number of files whose body and metadata were both measured
+    pub measured_count: usize,
+    
+    /// Number of files whose metadata became correct after the body was readable
+    pub metadata_lagged: usize,
+    
+    /// Number of files whose metadata was correct before the body was readable
+    pub body_lagged: usize,
+    
+    /// Most negative skew
+    pub min_skew_ms: i64,
+    
+    /// Most positive skew
+    pub max_skew_ms: i64,
+    
+    /// Average skew
+    pub avg_skew_ms: f64,
+    
+    /// Median skew
+    pub median_skew_ms: i64,
+}
+
+/// Measurements at one concurrency level of a breakpoint search
+#[derive(Debug, Clone, Serialize)]
+pub struct BreakpointLevel {
+    /// Number of files tested concurrently
+    pub concurrency: usize,
+    
+    /// Statistics for the files tested at this level
+    pub statistics: ConsistencyStatistics,
+    
+    /// Whether this level exceeded the p95 threshold or fell below the success rate
+    pub degraded: bool,
+}
+
+/// Result of a concurrency ramp to find the consistency breaking point
+#[derive(Debug, Serialize)]
+pub struct BreakpointReport {
+    /// When the search started
+    pub test_start_time: DateTime<Utc>,
+    
+    /// When the search completed
+    pub test_end_time: DateTime<Utc>,
+    
+    /// S3 configuration used for testing
+    pub config: S3Config,
+    
+    /// Parameters used for each batch
+    pub test_parameters: TestParameters,
+    
+    /// p95 propagation time considered a breakdown (milliseconds)
+    pub p95_threshold_ms: u64,
+    
+    /// Success rate considered a breakdown (percent)
+    pub min_success_rate: f64,
+    
+    /// Measurements for each concurrency level, in increasing order
+    pub levels: Vec<BreakpointLevel>,
+    
+    /// First concurrency level at which consistency degraded
+    /// 
+    /// `None` if every level up to the maximum stayed within the thresholds.
+    pub breakpoint_concurrency: Option<usize>,
+}
+
+/// Result of running the workload against one configuration of a benchmark
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchmarkEntry {
+    /// Configuration file the run used
+    pub config_path: PathBuf,
+    
+    /// S3 endpoint that was tested
+    pub endpoint: String,
+    
+    /// Bucket that was tested
+    pub bucket: String,
+    
+    /// Position in the ranking, starting at 1
+    /// 
+    /// `None` if the run failed and produced no statistics.
+    pub rank: Option<usize>,
+    
+    /// Statistics for the files tested against this configuration
+    /// 
+    /// `None` if the run failed.
+    pub statistics: Option<ConsistencyStatistics>,
+    
+    /// Why the run failed, if it did
+    pub error_details: Option<String>,
+}
+
+/// Comparison of several configurations running the same workload
+/// 
+/// Produced by the `benchmark` subcommand.
+#[derive(Debug, Serialize)]
+pub struct BenchmarkReport {
+    /// When the benchmark started
+    pub test_start_time: DateTime<Utc>,
+    
+    /// When the benchmark completed
+    pub test_end_time: DateTime<Utc>,
+    
+    /// Parameters used for every configuration
+    pub test_parameters: TestParameters,
+    
+    /// Results for each configuration, in ranking order with failed runs last
+    pub entries: Vec<BenchmarkEntry>,
+}
+
+/// Propagation statistics for one hour of the day
+/// 
+/// Produced by `--time-of-day-report` from every run in the SQLite database.
+#[derive(Debug, Clone, Serialize)]
+pub struct HourlyStatistics {
+    /// Hour of the day the files were uploaded (0-23, UTC)
+    pub hour: u32,
+    
+    /// Number of successful tests uploaded in this hour
+    pub successful_tests: usize,
+    
+    /// Average propagation time (milliseconds)
+    pub avg_propagation_time_ms: f64,
+    
+    /// 95th percentile propagation time (milliseconds)
+    /// 
+    /// `None` if fewer than `MIN_SAMPLES_P95` tests succeeded in this hour.
+    pub percentile_95_ms: Option<u64>,
+}
+
+/// Propagation statistics for one test file size
+/// 
+/// Produced with `--size-distribution` to compare sizes within a run.
+#[derive(Debug, Clone, Serialize)]
+pub struct SizeStatistics {
+    /// File size in bytes
+    pub file_size: usize,
+    
+    /// Number of files of this size that were tested
+    pub total_tests: usize,
+    
+    /// Number of files of this size that became consistent
+    pub successful_tests: usize,
+    
+    /// Average propagation time (milliseconds)
+    /// 
+    /// `None` if no file of this size succeeded.
+    pub avg_propagation_time_ms: Option<f64>,
+    
+    /// 95th percentile propagation time (milliseconds)
+    /// 
+    /// `None` if fewer than `MIN_SAMPLES_P95` files of this size succeeded.
+    pub percentile_95_ms: Option<u64>,
+}
+
+/// Propagation statistics for one bucket of a sharded deployment
+/// 
+/// Produced when the config lists several `buckets`, to spot a single
+/// misbehaving shard.
+#[derive(Debug, Clone, Serialize)]
+pub struct BucketStatistics {
+    /// Bucket name
+    pub bucket: String,
+    
+    /// Number of files uploaded to this bucket
+    pub total_tests: usize,
+    
+    /// Number of files in this bucket that became consistent
+    pub successful_tests: usize,
+    
+    /// Average propagation time (milliseconds)
+    /// 
+    /// `None` if no file in this bucket succeeded.
+    pub avg_propagation_time_ms: Option<f64>,
+    
+    /// 95th percentile propagation time (milliseconds)
+    /// 
+    /// `None` if fewer than `MIN_SAMPLES_P95` files in this bucket succeeded.
+    pub percentile_95_ms: Option<u64>,
+    
+    /// Maximum propagation time (milliseconds)
+    /// 
+    /// `None` if no file in this bucket succeeded.
+    pub max_propagation_time_ms: Option<u64>,
 }
 
-/// Test parameters used during the consistency test
+/// A single backend availability probe
 /// 
-/// This structure captures the key parameters used during testing
-/// for inclusion in the final report. It provides context for
-/// interpreting the test results.
+/// Recorded periodically by the availability probe to build a timeline
+/// of backend health over the test window.
 #[derive(Debug, Clone, Serialize)]
-pub struct TestParameters {
-    /// Number of test files that were uploaded
-    pub test_count: usize,
+pub struct AvailabilitySample {
+    /// When the probe was started
+    pub timestamp: DateTime<Utc>,
     
-    /// Size of each test file in bytes
-    pub file_size: usize,
+    /// Whether the backend responded successfully
+    pub available: bool,
     
-    /// Maximum wait time in seconds
-    pub max_wait_seconds: u64,
+    /// Time taken by the probe request (milliseconds)
+    pub latency_ms: u64,
     
-    /// Check interval in milliseconds
-    pub check_interval_ms: u64,
+    /// Error message if the probe failed
+    pub error: Option<String>,
 }
 
-impl From<&Args> for TestParameters {
-    fn from(args: &Args) -> Self {
-        Self {
-            test_count: args.test_count,
-            file_size: args.file_size,
-            max_wait_seconds: args.max_wait,
-            check_interval_ms: args.interval,
-        }
-    }
+/// Network setup timing for a single connection to the endpoint
+/// 
+/// Recorded once per run by `--diagnose-timing`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ConnectionTiming {
+    /// Host that was connected to
+    pub host: String,
+    
+    /// Port that was connected to
+    pub port: u16,
+    
+    /// IP address the host resolved to
+    pub resolved_address: String,
+    
+    /// Time taken to resolve the host (milliseconds)
+    pub dns_resolution_ms: f64,
+    
+    /// Time taken to establish the TCP connection (milliseconds)
+    pub tcp_connect_ms: f64,
+    
+    /// Time taken by the TLS handshake (milliseconds)
+    /// 
+    /// `None` for plain HTTP endpoints.
+    pub tls_handshake_ms: Option<f64>,
 }
 
-/// Result of testing a single file for consistency
+/// Outcome of polling a bucket listing until it contains a whole batch
 /// 
-/// Contains detailed information about one consistency test,
-/// including timing data, success status, and error details.
-/// 
-/// # Fields
-/// 
-/// - `file_key`: The S3 object key that was tested
-/// - `upload_time`: When the file was successfully uploaded
-/// - `first_read_success_time`: When the file first became readable (if successful)
-/// - `propagation_duration_ms`: How long it took to become consistent in milliseconds
-/// - `total_attempts`: Number of read attempts made
-/// - `success`: Whether the consistency test succeeded
-/// - `error_details`: Error message if the test failed
+/// Produced by `--list-completeness`. The duration is measured from the end
+/// of the upload phase until a listing first returned every uploaded key.
 #[derive(Debug, Clone, Serialize)]
-pub struct TestResult {
-    /// The S3 object key that was tested
-    /// 
-    /// Generated as "consistency-test-{uuid}" to ensure uniqueness
-    pub file_key: String,
+pub struct ListCompletenessResult {
+    /// Number of uploaded keys the listing was expected to return
+    pub expected_count: usize,
     
-    /// Timestamp when the file upload completed successfully
-    /// 
-    /// This is the baseline time used to calculate propagation duration
-    pub upload_time: DateTime<Utc>,
+    /// Number of uploaded keys returned by the last listing
+    pub observed_count: usize,
     
-    /// Timestamp when the file first became readable
+    /// Time until the listing returned every uploaded key (milliseconds)
     /// 
-    /// `None` if the file never became consistently readable within the timeout
-    pub first_read_success_time: Option<DateTime<Utc>>,
+    /// `None` if the listing never became complete within `--max-wait`.
+    pub list_completeness_duration_ms: Option<u64>,
     
-    /// Time in milliseconds from upload to first successful read
-    /// 
-    /// Calculated as `first_read_success_time - upload_time`.
-    /// `None` if the consistency test failed or timed out.
-    pub propagation_duration_ms: Option<u64>,
+    /// Number of full (paginated) listings performed
+    pub total_attempts: u32,
     
-    /// Total number of read attempts made during the test
+    /// Whether the first full listing already returned every uploaded key
     /// 
-    /// Includes both failed and the final successful attempt.
-    /// Helps understand the load generated during testing.
-    pub total_attempts: u32,
+    /// `false` if the listing only became complete after retries, e.g.
+    /// because some pages were consistent before others.
+    pub list_snapshot_consistent: bool,
     
-    /// Whether the consistency test completed successfully
+    /// Time until every uploaded key had appeared on some page of some listing (milliseconds)
     /// 
-    /// `true` if the file became readable within the timeout,
-    /// `false` if it failed or timed out.
+    /// Shorter than `list_completeness_duration_ms` when keys turned up
+    /// spread across listings before a single listing returned all of them.
+    /// `None` if some key was never listed within `--max-wait`.
+    pub keys_seen_duration_ms: Option<u64>,
+    
+    /// Number of pages in the last successful listing
+    pub page_count: usize,
+    
+    /// Whether the listing became complete
     pub success: bool,
     
-    /// Error message if the test failed
-    /// 
-    /// Contains details about what went wrong, such as:
-    /// - Upload failures
-    /// - Timeout errors  
-    /// - Network errors
-    /// - Authentication failures
+    /// Last listing error, if any
     pub error_details: Option<String>,
 }
 
-impl TestResult {
-    /// Creates a new successful test result
-    /// 
-    /// # Arguments
-    /// 
-    /// * `file_key` - The S3 object key that was tested
-    /// * `upload_time` - When the upload completed
-    /// * `first_read_success_time` - When the file became readable
-    /// * `total_attempts` - Number of read attempts made
+/// Outcome of racing concurrent overwrites of one key
+/// 
+/// Produced by `--overwrite-race`. Writers are numbered from 1 in the order
+/// their uploads were started.
+#[derive(Debug, Clone, Serialize)]
+pub struct OverwriteRaceResult {
+    /// The key the writers overwrote
+    pub file_key: String,
+    
+    /// Number of writers racing
+    pub writers: usize,
+    
+    /// Number of writers whose upload succeeded
+    pub successful_writes: usize,
+    
+    /// Writer whose upload completed last, the expected winner under last-writer-wins
+    pub last_completed_writer: Option<usize>,
+    
+    /// Writer whose payload the reads converged on
     /// 
-    /// # Returns
+    /// `None` if the reads never settled on one payload within `--max-wait`.
+    pub winner: Option<usize>,
+    
+    /// Time from the last overwrite completing to the first read of the
+    /// run of matching reads that settled the race (milliseconds)
+    pub converged_value_stable_ms: Option<u64>,
+    
+    /// Whether reads returned a payload again after having moved on from it
+    pub oscillated: bool,
+    
+    /// Number of times consecutive successful reads switched payloads
+    pub value_changes: usize,
+    
+    /// Number of reads performed
+    pub total_reads: u32,
+    
+    /// Why the race could not be measured, e.g. every upload failed
+    pub error_details: Option<String>,
+}
+
+/// Minimal machine-readable outcome of a run
+/// 
+/// Written by `--status-file`. The fields form a stable contract for
+/// automation and are deliberately kept independent of the full report.
+#[derive(Debug, Clone, Serialize)]
+pub struct RunStatus {
+    /// Success rate as a percentage (0.0 to 100.0)
+    pub success_rate: f64,
+    
+    /// Number of tests that failed or timed out
+    pub failed: usize,
+    
+    /// 95th percentile propagation time (milliseconds)
     /// 
-    /// A `TestResult` with success=true and calculated propagation duration
-    pub fn success(
-        file_key: String,
-        upload_time: DateTime<Utc>,
-        first_read_success_time: DateTime<Utc>,
-        total_attempts: u32,
-    ) -> Self {
-        let propagation_duration_ms = first_read_success_time
-            .signed_duration_since(upload_time)
-            .num_milliseconds() as u64;
-        
-        Self {
-            file_key,
-            upload_time,
-            first_read_success_time: Some(first_read_success_time),
-            propagation_duration_ms: Some(propagation_duration_ms),
-            total_attempts,
-            success: true,
-            error_details: None,
-        }
-    }
+    /// `None` if too few tests succeeded.
+    pub p95_ms: Option<u64>,
     
-    /// Creates a new failed test result
+    /// Exit code the process finished with
+    pub exit_code: i32,
+}
+
+impl RunStatus {
+    /// Creates a status from run statistics
     /// 
     /// # Arguments
     /// 
-    /// * `file_key` - The S3 object key that was tested
-    /// * `upload_time` - When the upload completed (or when it was attempted)
-    /// * `error_details` - Description of what went wrong
+    /// * `statistics` - Statistics of the full run
+    /// * `exit_code` - Exit code the process will finish with
     /// 
     /// # Returns
     /// 
-    /// A `TestResult` with success=false and error details
-    pub fn failure(
-        file_key: String,
-        upload_time: DateTime<Utc>,
-        error_details: String,
-    ) -> Self {
+    /// A new `RunStatus`
+    pub fn new(statistics: &ConsistencyStatistics, exit_code: i32) -> Self {
         Self {
-            file_key,
-            upload_time,
-            first_read_success_time: None,
-            propagation_duration_ms: None,
-            total_attempts: 0,
-            success: false,
-            error_details: Some(error_details),
+            success_rate: statistics.success_rate,
+            failed: statistics.failed_tests,
+            p95_ms: statistics.percentile_95_ms,
+            exit_code,
         }
     }
 }
 
-/// Statistical analysis of consistency test results
-/// 
-/// Provides comprehensive statistics about the consistency behavior
-/// observed during testing. Includes success rates and timing percentiles.
-/// 
-/// All timing values are in milliseconds for consistency.
-#[derive(Debug, Clone, Serialize)]
-pub struct ConsistencyStatistics {
-    /// Number of tests that completed successfully
-    pub successful_tests: usize,
-    
-    /// Number of tests that failed or timed out
-    pub failed_tests: usize,
-    
-    /// Success rate as a percentage (0.0 to 100.0)
-    pub success_rate: f64,
-    
-    /// Fastest propagation time observed (milliseconds)
-    /// 
-    /// `None` if no tests succeeded
-    pub min_propagation_time_ms: Option<u64>,
-    
-    /// Slowest propagation time observed (milliseconds)
-    /// 
-    /// `None` if no tests succeeded
-    pub max_propagation_time_ms: Option<u64>,
-    
-    /// Average propagation time (milliseconds)
-    /// 
-    /// `None` if no tests succeeded
-    pub avg_propagation_time_ms: Option<f64>,
+/// Number of S3 requests made during a run, by operation
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RequestCounts {
+    /// Upload (`PUT`) requests
+    pub uploads: u64,
     
-    /// Median propagation time (milliseconds)
-    /// 
-    /// The middle value when all successful propagation times are sorted.
-    /// `None` if no tests succeeded.
-    pub median_propagation_time_ms: Option<u64>,
+    /// Consistency read (`GET`) requests, one per poll attempt
+    pub reads: u64,
     
-    /// 95th percentile propagation time (milliseconds)
-    /// 
-    /// 95% of successful tests completed within this time.
-    /// `None` if insufficient successful tests.
-    pub percentile_95_ms: Option<u64>,
+    /// Listing requests, one per page
+    pub lists: u64,
     
-    /// 99th percentile propagation time (milliseconds)
-    /// 
-    /// 99% of successful tests completed within this time.
-    /// `None` if insufficient successful tests.
-    pub percentile_99_ms: Option<u64>,
+    /// Delete requests, including cleanup retries
+    pub deletes: u64,
+}
+
+impl RequestCounts {
+    /// Gets the total number of requests across all operations
+    pub fn total(&self) -> u64 {
+        self.uploads + self.reads + self.lists + self.deletes
+    }
 }
 
 /// Complete consistency test report
@@ -303,7 +2563,7 @@ pub struct ConsistencyStatistics {
 /// Contains all information about a consistency test run, including
 /// configuration, parameters, individual results, and statistics.
 /// This structure is serialized to JSON for detailed reporting.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ConsistencyReport {
     /// When the test suite started
     pub test_start_time: DateTime<Utc>,
@@ -314,11 +2574,24 @@ pub struct ConsistencyReport {
     /// Total time taken for all tests (milliseconds)
     pub total_duration_ms: u64,
     
+    /// Version of this tool that produced the report
+    pub tool_version: String,
+    
+    /// Version of the `rust-s3` client crate the tool was built with
+    pub s3_client_version: String,
+    
+    /// Output of `rustc --version` for the compiler that built the tool
+    pub rustc_version: String,
+    
+    /// When the tool was built (RFC 3339)
+    pub build_timestamp: String,
+    
     /// S3 configuration used for testing
     /// 
     /// Includes endpoint, bucket, and other connection details.
     /// Credentials are included for debugging but should be redacted
-    /// before sharing reports.
+    /// before sharing reports. The session token, external ID, reader
+    /// secret key and proxy credentials are never included.
     pub config: S3Config,
     
     /// Parameters used during testing
@@ -326,11 +2599,72 @@ pub struct ConsistencyReport {
     
     /// Individual test results for each file
     /// 
-    /// Results are in the order the tests were performed.
+    /// Results are in the order the tests were performed. When the run used
+    /// `--failures-only`, only failed results are kept.
     pub results: Vec<TestResult>,
     
+    /// Number of results the run produced, when `results` holds only a random sample
+    /// 
+    /// `None` unless the run tested more files than `--max-results-in-memory`.
+    pub results_sampled_from: Option<usize>,
+    
     /// Statistical summary of the results
     pub statistics: ConsistencyStatistics,
+    
+    /// Backend availability timeline recorded during the test
+    /// 
+    /// `None` unless `--availability-probe` was used.
+    pub availability_timeline: Option<Vec<AvailabilitySample>>,
+    
+    /// Bucket listing completeness for the uploaded batch
+    /// 
+    /// `None` unless `--list-completeness` was used.
+    pub list_completeness: Option<ListCompletenessResult>,
+    
+    /// How reads converged after concurrent overwrites of one key
+    /// 
+    /// `None` unless `--overwrite-race` was used.
+    pub overwrite_race: Option<OverwriteRaceResult>,
+    
+    /// Highest number of files awaiting consistency at the same time
+    /// 
+    /// `None` unless `--max-in-flight` capped a burst.
+    pub peak_in_flight: Option<usize>,
+    
+    /// Network setup timing for the endpoint
+    /// 
+    /// `None` unless `--diagnose-timing` was used and the diagnostic succeeded.
+    pub connection_timing: Option<ConnectionTiming>,
+    
+    /// Propagation statistics for each file size
+    /// 
+    /// `None` unless `--size-distribution` was used.
+    pub size_breakdown: Option<Vec<SizeStatistics>>,
+    
+    /// Propagation statistics for each bucket
+    /// 
+    /// `None` unless the config lists several `buckets`.
+    pub bucket_breakdown: Option<Vec<BucketStatistics>>,
+    
+    /// Total number of S3 requests made during the run
+    pub total_requests: u64,
+    
+    /// S3 requests made during the run, by operation
+    pub requests: RequestCounts,
+    
+    /// Estimated cost of the requests made
+    /// 
+    /// `None` unless `--cost-per-1k-requests` was given.
+    pub estimated_request_cost: Option<f64>,
+    
+    /// Metadata attached to the run with `--label key=value`
+    pub labels: HashMap<String, String>,
+    
+    /// Whether the run was stopped early with Ctrl+C
+    /// 
+    /// Files in flight were finished and the rest skipped, so the results
+    /// cover fewer files than requested.
+    pub interrupted: bool,
 }
 
 impl ConsistencyReport {
@@ -347,7 +2681,9 @@ impl ConsistencyReport {
     /// 
     /// # Returns
     /// 
-    /// A new `ConsistencyReport` with calculated total duration
+    /// A new `ConsistencyReport` with calculated total duration and the
+    /// tool, S3 client and compiler versions and the build time recorded for
+    /// reproducibility
     pub fn new(
         test_start_time: DateTime<Utc>,
         test_end_time: DateTime<Utc>,
@@ -364,13 +2700,51 @@ impl ConsistencyReport {
             test_start_time,
             test_end_time,
             total_duration_ms,
+            tool_version: version::TOOL_VERSION.to_string(),
+            s3_client_version: version::S3_CLIENT_VERSION.to_string(),
+            rustc_version: version::RUSTC_VERSION.to_string(),
+            build_timestamp: version::build_timestamp(),
             config,
             test_parameters,
             results,
+            results_sampled_from: None,
             statistics,
+            availability_timeline: None,
+            list_completeness: None,
+            overwrite_race: None,
+            peak_in_flight: None,
+            connection_timing: None,
+            size_breakdown: None,
+            bucket_breakdown: None,
+            total_requests: 0,
+            requests: RequestCounts::default(),
+            estimated_request_cost: None,
+            labels: HashMap::new(),
+            interrupted: false,
         }
     }
     
+    /// Records the S3 requests made during the run
+    /// 
+    /// # Arguments
+    /// 
+    /// * `requests` - Request counts for the run
+    /// * `cost_per_1k_requests` - Optional price per 1,000 requests used to
+    ///   estimate the cost
+    pub fn set_requests(&mut self, requests: RequestCounts, cost_per_1k_requests: Option<f64>) {
+        self.total_requests = requests.total();
+        self.estimated_request_cost = cost_per_1k_requests
+            .map(|price| self.total_requests as f64 / 1000.0 * price);
+        self.requests = requests;
+    }
+    
+    /// Drops all successful results, keeping only failures
+    /// 
+    /// Statistics are left untouched so they still describe the full run.
+    pub fn retain_failures(&mut self) {
+        self.results.retain(|r| !r.success);
+    }
+    
     /// Gets a summary of the test results as a formatted string
     /// 
     /// Returns a human-readable summary including success rate,
@@ -412,4 +2786,93 @@ impl ConsistencyReport {
         
         summary
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_ms_auto_scaling() {
+        let display = DisplayOptions::default();
+        
+        assert_eq!(display.format_ms(0.25), "250.0µs");
+        assert_eq!(display.format_ms(0.0), "0.0ms");
+        assert_eq!(display.format_ms(250.0), "250.0ms");
+        assert_eq!(display.format_ms(1_850_000.0), "1850.0s");
+    }
+
+    #[test]
+    fn test_format_ms_fixed_unit_and_precision() {
+        let seconds = DisplayOptions { time_unit: TimeUnit::S, precision: 3, ..Default::default() };
+        assert_eq!(seconds.format_ms(250.0), "0.250s");
+        
+        let millis = DisplayOptions { time_unit: TimeUnit::Ms, precision: 0, ..Default::default() };
+        assert_eq!(millis.format_ms(1_850_000.0), "1850000ms");
+        
+        let micros = DisplayOptions { time_unit: TimeUnit::Us, precision: 0, ..Default::default() };
+        assert_eq!(micros.format_ms(1.5), "1500µs");
+        
+        let display = DisplayOptions::default();
+        assert_eq!(display.format_us(420.0), "420.0µs");
+    }
+    
+    #[test]
+    fn test_propagation_ignores_wall_clock_steps() {
+        let upload_time = Utc::now();
+        let mut result = TestResult::success(
+            "test-file".to_string(),
+            upload_time,
+            upload_time - chrono::Duration::seconds(2),
+            1,
+        );
+        assert_eq!(result.propagation_duration_ms, Some(0));
+        
+        result.set_propagation(std::time::Duration::from_micros(340_250));
+        assert_eq!(result.propagation_duration_ms, Some(340));
+        assert_eq!(result.propagation_duration_us, Some(340_250));
+    }
+    
+    #[test]
+    fn test_multiple_configs() {
+        let args = Args::parse_from([
+            "s3-consistency-test", "--config", "base.toml", "--config", "staging.toml",
+        ]);
+        assert_eq!(args.config, vec![PathBuf::from("base.toml"), PathBuf::from("staging.toml")]);
+    }
+    
+    #[test]
+    fn test_label_parsing() {
+        let args = Args::parse_from([
+            "s3-consistency-test", "--config", "config.toml",
+            "--label", "env=staging", "--label", "query=a=b",
+        ]);
+        assert_eq!(args.labels, vec![
+            ("env".to_string(), "staging".to_string()),
+            ("query".to_string(), "a=b".to_string()),
+        ]);
+        
+        assert!(parse_label("no-value").is_err());
+        assert!(parse_label("=value").is_err());
+    }
+    
+    #[test]
+    fn test_parse_part_size() {
+        assert_eq!(parse_part_size("5242880"), Ok(MIN_MULTIPART_PART_SIZE));
+        assert!(parse_part_size("1024").is_err());
+        assert!(parse_part_size("five").is_err());
+    }
+    
+    #[test]
+    fn test_run_status_serialization() {
+        let statistics = crate::statistics::calculate_statistics(&[]);
+        let status = serde_json::to_value(RunStatus::new(&statistics, 1)).unwrap();
+        
+        assert_eq!(status, serde_json::json!({
+            "success_rate": 0.0,
+            "failed": 0,
+            "p95_ms": null,
+            "exit_code": 1
+        }));
+    }
+}
@@ -0,0 +1,69 @@
+//! Build information for `--version-verbose` and reports
+//! 
+//! `build.rs` captures the resolved `rust-s3` version, the `rustc --version`
+//! output and the build time, so a report or bug description can name the
+//! exact build that produced it.
+
+use chrono::DateTime;
+
+/// Version of this tool
+pub const TOOL_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Version of the `rust-s3` client crate the tool was built with
+pub const S3_CLIENT_VERSION: &str = env!("S3_CLIENT_VERSION");
+
+/// Output of `rustc --version` for the compiler that built the tool
+pub const RUSTC_VERSION: &str = env!("RUSTC_VERSION");
+
+/// Returns when the tool was built
+/// 
+/// # Returns
+/// 
+/// The build time as an RFC 3339 timestamp, or `unknown` if the build
+/// script recorded no valid time
+pub fn build_timestamp() -> String {
+    env!("BUILD_TIMESTAMP")
+        .parse()
+        .ok()
+        .and_then(|secs| DateTime::from_timestamp(secs, 0))
+        .map(|time| time.to_rfc3339())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Formats the output of `--version-verbose`
+/// 
+/// # Returns
+/// 
+/// One line per component, starting with the tool name and version
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// print!("{}", verbose_version());
+/// ```
+pub fn verbose_version() -> String {
+    format!(
+        "s3-consistency-test {}\nrust-s3: {}\nrustc: {}\nbuilt: {}\n",
+        TOOL_VERSION,
+        S3_CLIENT_VERSION,
+        RUSTC_VERSION,
+        build_timestamp()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_verbose_version_lists_components() {
+        let output = verbose_version();
+        let lines: Vec<&str> = output.lines().collect();
+        
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0], format!("s3-consistency-test {}", TOOL_VERSION));
+        assert!(lines[1].starts_with("rust-s3: "));
+        assert!(lines[2].starts_with("rustc: "));
+        assert!(lines[3].starts_with("built: "));
+    }
+}
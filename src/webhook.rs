@@ -0,0 +1,270 @@
+//! Webhook notification of finished consistency test runs
+//! 
+//! When a run finishes, a JSON summary is posted to a user-supplied URL so
+//! chat tools and automation can alert on degraded consistency without a
+//! separate metrics pipeline. The payload carries the headline statistics
+//! and SLA status as fields, plus a ready-made `text` message, which Slack
+//! and compatible incoming webhooks display as-is.
+//! 
+//! # Example Payload
+//! 
+//! ```json
+//! {
+//!   "text": "⚠️ S3 consistency degraded for my-bucket (https://s3.amazonaws.com): 95.0% success (19/20), p95 1987ms, SLA <= 500ms breached by 3 files",
+//!   "status": "degraded",
+//!   "endpoint": "https://s3.amazonaws.com",
+//!   "bucket": "my-bucket",
+//!   "success_rate": 95.0,
+//!   "p95_ms": 1987,
+//!   "sla_ms": 500,
+//!   "sla_breaches": 3
+//! }
+//! ```
+
+use anyhow::{Result, anyhow};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::time::sleep;
+use tracing::{info, warn};
+
+use crate::types::ConsistencyReport;
+
+/// Delay before the first retry of a failed delivery, doubled for each later retry
+const WEBHOOK_RETRY_DELAY: Duration = Duration::from_secs(2);
+
+/// Timeout for a single webhook request
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Summary of a finished run posted to `--webhook`
+#[derive(Debug, Clone, Serialize)]
+pub struct WebhookSummary {
+    /// Human-readable one-line summary, suitable as an alert message
+    pub text: String,
+    
+    /// `ok` if every file became consistent within any SLA, `degraded` otherwise
+    pub status: &'static str,
+    
+    /// The S3 endpoint tested
+    pub endpoint: String,
+    
+    /// The bucket tested
+    pub bucket: String,
+    
+    /// The run's `--label` metadata
+    pub labels: HashMap<String, String>,
+    
+    /// When the run started
+    pub test_start_time: DateTime<Utc>,
+    
+    /// When the run finished
+    pub test_end_time: DateTime<Utc>,
+    
+    /// Whether the run was stopped early with Ctrl+C
+    pub interrupted: bool,
+    
+    /// Success rate as a percentage (0.0 to 100.0)
+    pub success_rate: f64,
+    
+    /// Number of tests that completed successfully
+    pub successful_tests: usize,
+    
+    /// Number of tests that failed or timed out
+    pub failed_tests: usize,
+    
+    /// Median propagation time (milliseconds)
+    pub median_ms: Option<u64>,
+    
+    /// 95th percentile propagation time (milliseconds)
+    /// 
+    /// `None` if too few tests succeeded.
+    pub p95_ms: Option<u64>,
+    
+    /// 99th percentile propagation time (milliseconds)
+    /// 
+    /// `None` if too few tests succeeded.
+    pub p99_ms: Option<u64>,
+    
+    /// Slowest propagation time (milliseconds)
+    pub max_ms: Option<u64>,
+    
+    /// The `--sla-ms` propagation target, if one was given
+    pub sla_ms: Option<u64>,
+    
+    /// Percentage of classified files that met the SLA (0.0 to 100.0)
+    pub sla_compliance_rate: Option<f64>,
+    
+    /// Number of files that missed the SLA
+    pub sla_breaches: usize,
+}
+
+impl WebhookSummary {
+    /// Summarizes a completed report
+    /// 
+    /// # Arguments
+    /// 
+    /// * `report` - The completed consistency report
+    /// 
+    /// # Returns
+    /// 
+    /// The summary to post, with its alert text filled in
+    pub fn new(report: &ConsistencyReport) -> Self {
+        let stats = &report.statistics;
+        let sla_ms = report.test_parameters.sla_ms;
+        let degraded = stats.failed_tests > 0 || (sla_ms.is_some() && stats.sla_breaches > 0);
+        
+        let mut text = format!(
+            "{} S3 consistency {} for {} ({}): {:.1}% success ({}/{}), p95 {}",
+            if degraded { "⚠️" } else { "✅" },
+            if degraded { "degraded" } else { "ok" },
+            report.config.bucket,
+            report.config.endpoint,
+            stats.success_rate,
+            stats.successful_tests,
+            stats.successful_tests + stats.failed_tests,
+            stats.percentile_95_ms.map_or("n/a".to_string(), |p95| format!("{}ms", p95)),
+        );
+        if let Some(target) = sla_ms {
+            if stats.sla_breaches > 0 {
+                text.push_str(&format!(", SLA <= {}ms breached by {} files", target, stats.sla_breaches));
+            } else {
+                text.push_str(&format!(", SLA <= {}ms met", target));
+            }
+        }
+        if report.interrupted {
+            text.push_str(" (stopped early)");
+        }
+        
+        Self {
+            text,
+            status: if degraded { "degraded" } else { "ok" },
+            endpoint: report.config.endpoint.clone(),
+            bucket: report.config.bucket.clone(),
+            labels: report.labels.clone(),
+            test_start_time: report.test_start_time,
+            test_end_time: report.test_end_time,
+            interrupted: report.interrupted,
+            success_rate: stats.success_rate,
+            successful_tests: stats.successful_tests,
+            failed_tests: stats.failed_tests,
+            median_ms: stats.median_propagation_time_ms,
+            p95_ms: stats.percentile_95_ms,
+            p99_ms: stats.percentile_99_ms,
+            max_ms: stats.max_propagation_time_ms,
+            sla_ms,
+            sla_compliance_rate: stats.sla_compliance_rate,
+            sla_breaches: stats.sla_breaches,
+        }
+    }
+}
+
+/// Posts a run summary to a webhook, retrying failed deliveries
+/// 
+/// A delivery fails if the request cannot be sent, times out, or is
+/// answered with a non-success status. Retries wait 2s, then 4s, 8s and so on.
+/// 
+/// # Arguments
+/// 
+/// * `report` - The completed consistency report
+/// * `url` - The `http(s)://` URL to post the summary to
+/// * `retries` - Number of times a failed delivery is retried
+/// 
+/// # Returns
+/// 
+/// - `Ok(())` if the webhook accepted the summary
+/// - `Err(anyhow::Error)` if every attempt failed, with the last failure
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// send_webhook(&report, "https://hooks.slack.com/services/T000/B000/XXXX", 3).await?;
+/// ```
+pub async fn send_webhook(report: &ConsistencyReport, url: &str, retries: u32) -> Result<()> {
+    let body = serde_json::to_string(&WebhookSummary::new(report))?;
+    let client = reqwest::Client::builder()
+        .timeout(WEBHOOK_TIMEOUT)
+        .build()?;
+    
+    let mut delay = WEBHOOK_RETRY_DELAY;
+    for attempt in 0..=retries {
+        if attempt > 0 {
+            sleep(delay).await;
+            delay *= 2;
+        }
+        
+        let response = client.post(url)
+            .header("content-type", "application/json")
+            .body(body.clone())
+            .send()
+            .await;
+        
+        let failure = match response {
+            Ok(response) if response.status().is_success() => {
+                info!("📣 Run summary sent to webhook: {}", url);
+                return Ok(());
+            }
+            Ok(response) => {
+                let status = response.status().as_u16();
+                let text = response.text().await.unwrap_or_default();
+                anyhow!("Webhook rejected the summary with HTTP {}: {}", status, text.trim())
+            }
+            Err(e) => anyhow!("Failed to send summary to webhook at {}: {}", url, e),
+        };
+        
+        if attempt == retries {
+            return Err(failure);
+        }
+        warn!("📣 {}, retrying ({}/{})...", failure, attempt + 1, retries);
+    }
+    
+    unreachable!("the last attempt always returns")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::S3Config;
+    use crate::statistics::calculate_statistics;
+    use crate::types::{Args, TestParameters, TestResult};
+    use chrono::{Duration, TimeZone};
+    use clap::Parser;
+    
+    fn create_test_report(argv: &[&str]) -> ConsistencyReport {
+        let start = Utc.with_ymd_and_hms(2025, 1, 23, 14, 30, 0).unwrap();
+        let mut results = vec![
+            TestResult::success("a".to_string(), start, start + Duration::milliseconds(120), 2),
+            TestResult::success("b".to_string(), start, start + Duration::milliseconds(800), 5),
+        ];
+        for result in &mut results {
+            result.sla_met = Some(result.propagation_duration_ms.is_some_and(|duration| duration <= 500));
+        }
+        let statistics = calculate_statistics(&results);
+        let config = S3Config {
+            endpoint: "http://localhost:9000".to_string(),
+            bucket: "test-bucket".to_string(),
+            ..Default::default()
+        };
+        let parameters = TestParameters::from(&Args::parse_from(argv));
+        
+        ConsistencyReport::new(start, start + Duration::seconds(1), config, parameters, results, statistics)
+    }
+    
+    #[test]
+    fn test_summary_reports_sla_breaches() {
+        let report = create_test_report(&["s3-consistency-test", "--config", "config.toml", "--sla-ms", "500"]);
+        let summary = WebhookSummary::new(&report);
+        
+        assert_eq!(summary.status, "degraded");
+        assert_eq!(summary.sla_breaches, 1);
+        assert_eq!(
+            summary.text,
+            "⚠️ S3 consistency degraded for test-bucket (http://localhost:9000): 100.0% success (2/2), p95 n/a, SLA <= 500ms breached by 1 files"
+        );
+        
+        let report = create_test_report(&["s3-consistency-test", "--config", "config.toml"]);
+        let summary = WebhookSummary::new(&report);
+        assert_eq!(summary.status, "ok");
+        assert!(summary.text.starts_with("✅ S3 consistency ok for test-bucket"));
+    }
+}
@@ -0,0 +1,213 @@
+//! Workload profiles for reproducible test definitions
+//! 
+//! A workload profile is a TOML file, separate from the S3 configuration,
+//! that captures what a test run does: how many files of which sizes, how
+//! long to wait and how often to poll, and the thresholds to judge the
+//! result against. Teams can check profiles such as `nightly.toml` into
+//! version control and run them with `--workload nightly.toml`.
+//! 
+//! Every setting is optional and named after its command-line flag. Flags
+//! given on the command line take precedence over the profile.
+
+use anyhow::{Context, Result};
+use clap::ArgMatches;
+use clap::parser::ValueSource;
+use serde::Deserialize;
+use std::path::Path;
+
+use crate::sizes::SizeDistribution;
+use crate::types::Args;
+
+/// Test settings loaded from a workload profile
+/// 
+/// # Examples
+/// 
+/// ```toml
+/// # nightly.toml
+/// test_count = 200
+/// size_distribution = "80%:10KB,15%:1MB,5%:100MB"
+/// max_wait = 120
+/// interval = 50
+/// burst = true
+/// sla_ms = 500
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WorkloadProfile {
+    /// Number of test files (`--test-count`)
+    pub test_count: Option<usize>,
+    
+    /// Size of each test file in bytes (`--file-size`)
+    pub file_size: Option<usize>,
+    
+    /// Weighted file sizes, e.g. `80%:10KB,20%:1MB` (`--size-distribution`)
+    pub size_distribution: Option<String>,
+    
+    /// Maximum wait time per file in seconds (`--max-wait`)
+    pub max_wait: Option<u64>,
+    
+    /// Check interval in milliseconds (`--interval`)
+    pub interval: Option<u64>,
+    
+    /// Whether to tune the check interval per file (`--auto-interval`)
+    pub auto_interval: Option<bool>,
+    
+    /// Whether to upload all files before polling (`--burst`)
+    pub burst: Option<bool>,
+    
+    /// Maximum random delay before each upload in milliseconds (`--upload-jitter`)
+    pub upload_jitter: Option<u64>,
+    
+    /// Consecutive matching reads required per file (`--consecutive-reads`)
+    pub consecutive_reads: Option<u32>,
+    
+    /// Maximum upload retries per file (`--file-retries`)
+    pub file_retries: Option<u32>,
+    
+    /// Propagation time target in milliseconds (`--sla-ms`)
+    pub sla_ms: Option<u64>,
+    
+    /// Whether to ramp concurrency to find the breaking point (`--find-breakpoint`)
+    pub find_breakpoint: Option<bool>,
+    
+    /// Highest concurrency tried by the breakpoint search (`--max-concurrency`)
+    pub max_concurrency: Option<usize>,
+    
+    /// p95 threshold in milliseconds for the breakpoint search (`--breakpoint-p95-ms`)
+    pub breakpoint_p95_ms: Option<u64>,
+    
+    /// Minimum success rate for the breakpoint search (`--breakpoint-min-success-rate`)
+    pub breakpoint_min_success_rate: Option<f64>,
+}
+
+impl WorkloadProfile {
+    /// Loads a workload profile from a TOML file
+    /// 
+    /// # Arguments
+    /// 
+    /// * `path` - Path to the profile
+    /// 
+    /// # Returns
+    /// 
+    /// - `Ok(WorkloadProfile)` if the file was read and parsed
+    /// - `Err(anyhow::Error)` if the file cannot be read, is not valid TOML,
+    ///   or contains an unknown setting
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read workload profile: {}", path.display()))?;
+        
+        toml::from_str(&content)
+            .with_context(|| format!("Failed to parse workload profile: {}", path.display()))
+    }
+    
+    /// Applies the profile to parsed command-line arguments
+    /// 
+    /// Each setting in the profile replaces the corresponding argument unless
+    /// that argument was given explicitly on the command line.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `args` - The arguments to update
+    /// * `matches` - The matches `args` was parsed from, used to tell
+    ///   explicit flags apart from defaults
+    /// 
+    /// # Returns
+    /// 
+    /// - `Ok(())` once the profile has been applied
+    /// - `Err(anyhow::Error)` if a setting in the profile is invalid
+    pub fn apply(&self, args: &mut Args, matches: &ArgMatches) -> Result<()> {
+        if self.consecutive_reads == Some(0) {
+            return Err(anyhow::anyhow!("consecutive_reads in workload profile must be at least 1"));
+        }
+        
+        let from_command_line = |id: &str| matches.value_source(id) == Some(ValueSource::CommandLine);
+        
+        macro_rules! apply {
+            ($($field:ident),* $(,)?) => {
+                $(
+                    if let Some(value) = self.$field
+                        && !from_command_line(stringify!($field))
+                    {
+                        args.$field = value;
+                    }
+                )*
+            };
+        }
+        
+        apply!(
+            test_count,
+            file_size,
+            max_wait,
+            interval,
+            auto_interval,
+            burst,
+            upload_jitter,
+            consecutive_reads,
+            file_retries,
+            find_breakpoint,
+            max_concurrency,
+            breakpoint_p95_ms,
+            breakpoint_min_success_rate,
+        );
+        
+        if let Some(sla_ms) = self.sla_ms
+            && !from_command_line("sla_ms")
+        {
+            args.sla_ms = Some(sla_ms);
+        }
+        
+        if let Some(distribution) = &self.size_distribution
+            && !from_command_line("size_distribution")
+        {
+            args.size_distribution = Some(SizeDistribution::parse(distribution)
+                .context("Invalid size_distribution in workload profile")?);
+        }
+        
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::{CommandFactory, FromArgMatches};
+    
+    fn parse_args(argv: &[&str]) -> (Args, ArgMatches) {
+        let matches = Args::command().get_matches_from(argv);
+        let args = Args::from_arg_matches(&matches).unwrap();
+        (args, matches)
+    }
+    
+    #[test]
+    fn test_profile_applies_unless_given_on_command_line() {
+        let profile: WorkloadProfile = toml::from_str(
+            r#"
+            test_count = 200
+            interval = 50
+            burst = true
+            sla_ms = 500
+            size_distribution = "90%:1KB,10%:1MB"
+            "#,
+        ).unwrap();
+        let (mut args, matches) = parse_args(&[
+            "s3-consistency-test", "--config", "config.toml", "--test-count", "5",
+        ]);
+        
+        profile.apply(&mut args, &matches).unwrap();
+        
+        assert_eq!(args.test_count, 5);
+        assert_eq!(args.interval, 50);
+        assert!(args.burst);
+        assert_eq!(args.sla_ms, Some(500));
+        assert_eq!(args.max_file_size(), 1024 * 1024);
+    }
+    
+    #[test]
+    fn test_profile_rejects_unknown_settings() {
+        assert!(toml::from_str::<WorkloadProfile>("test_cuont = 10").is_err());
+        
+        let profile: WorkloadProfile = toml::from_str("size_distribution = \"10KB\"").unwrap();
+        let (mut args, matches) = parse_args(&["s3-consistency-test", "--config", "config.toml"]);
+        assert!(profile.apply(&mut args, &matches).is_err());
+    }
+}